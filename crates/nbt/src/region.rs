@@ -14,8 +14,8 @@ use minedmap_types::*;
 
 /// Data block size of region data files
 ///
-/// After one header block, the region file consists of one or more consecutive blocks
-/// of data for each populated chunk.
+/// After two header blocks (chunk locations and timestamps), the region file
+/// consists of one or more consecutive blocks of data for each populated chunk.
 const BLOCKSIZE: usize = 4096;
 
 /// Chunk descriptor extracted from region file header
@@ -56,6 +56,13 @@ fn parse_header(header: &ChunkArray<u32>) -> Vec<ChunkDesc> {
 	chunks
 }
 
+/// Reads one of the tables of a region data file header
+fn read_table<R: Read>(reader: &mut R) -> Result<ChunkArray<u32>> {
+	let mut table = ChunkArray::<u32>::default();
+	reader.read_exact(bytemuck::cast_mut::<_, [u8; BLOCKSIZE]>(&mut table.0))?;
+	Ok(table)
+}
+
 /// Decompresses chunk data and deserializes to a given data structure
 fn decode_chunk<T>(buf: &[u8]) -> Result<T>
 where
@@ -83,23 +90,59 @@ pub struct Region<R: Read + Seek> {
 }
 
 impl<R: Read + Seek> Region<R> {
+	/// Returns the timestamps of the last modification of each chunk
+	///
+	/// Timestamps are given in seconds since the Unix epoch. Chunks that do
+	/// not exist in the region data have no timestamp.
+	pub fn timestamps(&mut self) -> Result<ChunkArray<Option<u32>>> {
+		self.reader
+			.seek(SeekFrom::Start(0))
+			.context("Failed to seek region header")?;
+		let locations = read_table(&mut self.reader).context("Failed to read region header")?;
+		let timestamps =
+			read_table(&mut self.reader).context("Failed to read region timestamps")?;
+
+		let chunks = parse_header(&locations);
+		let mut ret = ChunkArray::<Option<u32>>::default();
+		for chunk in chunks {
+			ret[chunk.coords] = Some(u32::from_be(timestamps[chunk.coords]));
+		}
+
+		Ok(ret)
+	}
+
 	/// Iterates over the chunks of the region data
 	///
 	/// The order of iteration is based on the order the chunks appear in the
 	/// data file.
-	pub fn foreach_chunk<T, F>(self, mut f: F) -> Result<()>
+	pub fn foreach_chunk<T, F>(self, f: F) -> Result<()>
+	where
+		R: Read + Seek,
+		T: DeserializeOwned,
+		F: FnMut(ChunkCoords, T) -> Result<()>,
+	{
+		self.foreach_chunk_filtered(|_| true, f)
+	}
+
+	/// Iterates over the chunks of the region data accepted by a filter
+	///
+	/// Chunks for which *filter* returns false are skipped without decoding
+	/// their data. The order of iteration is based on the order the chunks
+	/// appear in the data file.
+	pub fn foreach_chunk_filtered<T, P, F>(self, mut filter: P, mut f: F) -> Result<()>
 	where
 		R: Read + Seek,
 		T: DeserializeOwned,
+		P: FnMut(ChunkCoords) -> bool,
 		F: FnMut(ChunkCoords, T) -> Result<()>,
 	{
 		let Region { mut reader } = self;
 
 		let chunks = {
-			let mut header = ChunkArray::<u32>::default();
 			reader
-				.read_exact(bytemuck::cast_mut::<_, [u8; BLOCKSIZE]>(&mut header.0))
-				.context("Failed to read region header")?;
+				.seek(SeekFrom::Start(0))
+				.context("Failed to seek region header")?;
+			let header = read_table(&mut reader).context("Failed to read region header")?;
 
 			parse_header(&header)
 		};
@@ -117,6 +160,10 @@ impl<R: Read + Seek> Region<R> {
 			}
 			seen[coords] = true;
 
+			if !filter(coords) {
+				continue;
+			}
+
 			reader
 				.seek(SeekFrom::Start(offset as u64 * BLOCKSIZE as u64))
 				.context("Failed to seek chunk data")?;
@@ -160,3 +207,43 @@ where
 	let file = File::open(path).context("Failed to open file")?;
 	Ok(from_reader(file))
 }
+
+#[cfg(test)]
+mod test {
+	use std::io::Cursor;
+
+	use super::*;
+
+	#[test]
+	fn test_timestamps() {
+		let mut header = vec![0u8; 2 * BLOCKSIZE];
+		let mut set = |table: usize, x: usize, z: usize, value: u32| {
+			let offset = table * BLOCKSIZE + 4 * (z * CHUNKS_PER_REGION + x);
+			header[offset..offset + 4].copy_from_slice(&value.to_be_bytes());
+		};
+		// Chunk at offset 2 with a length of 1 block
+		set(0, 1, 2, 2 << 8 | 1);
+		set(1, 1, 2, 1700000000);
+		// Chunk at offset 3 without timestamp
+		set(0, 31, 31, 3 << 8 | 1);
+		// Timestamp of a chunk that does not exist
+		set(1, 5, 5, 1700000000);
+
+		let timestamps = from_reader(Cursor::new(header)).timestamps().unwrap();
+
+		for (coords, &timestamp) in timestamps.iter() {
+			let expected = match (coords.x.0, coords.z.0) {
+				(1, 2) => Some(1700000000),
+				(31, 31) => Some(0),
+				_ => None,
+			};
+			assert_eq!(timestamp, expected, "chunk {:?}", coords);
+		}
+	}
+
+	#[test]
+	fn test_timestamps_truncated() {
+		let header = vec![0u8; BLOCKSIZE + 100];
+		assert!(from_reader(Cursor::new(header)).timestamps().is_err());
+	}
+}
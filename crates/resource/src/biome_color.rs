@@ -0,0 +1,117 @@
+//! Colors used to visualize biome types on the biome map layer
+
+use super::Color;
+
+/// Biome layer colors of the standard Minecraft biomes
+///
+/// Related biomes are given similar hues, so the biome layer remains
+/// readable at lower zoom levels.
+const BIOME_COLORS: &[(&str, [u8; 3])] = &[
+	("badlands", [217, 69, 21]),
+	("bamboo_jungle", [118, 142, 20]),
+	("beach", [250, 222, 85]),
+	("birch_forest", [48, 116, 68]),
+	("cherry_grove", [242, 174, 214]),
+	("cold_ocean", [32, 32, 112]),
+	("dark_forest", [64, 81, 26]),
+	("deep_cold_ocean", [32, 32, 56]),
+	("deep_dark", [14, 40, 46]),
+	("deep_frozen_ocean", [64, 64, 144]),
+	("deep_lukewarm_ocean", [0, 0, 64]),
+	("deep_ocean", [0, 0, 48]),
+	("desert", [250, 148, 24]),
+	("dripstone_caves", [140, 110, 80]),
+	("eroded_badlands", [255, 109, 61]),
+	("flower_forest", [45, 142, 73]),
+	("forest", [5, 102, 33]),
+	("frozen_ocean", [112, 112, 214]),
+	("frozen_peaks", [160, 160, 255]),
+	("frozen_river", [160, 160, 255]),
+	("grove", [128, 180, 160]),
+	("ice_spikes", [180, 220, 220]),
+	("jagged_peaks", [220, 220, 200]),
+	("jungle", [83, 123, 9]),
+	("lukewarm_ocean", [0, 0, 144]),
+	("lush_caves", [40, 130, 40]),
+	("mangrove_swamp", [44, 204, 142]),
+	("meadow", [96, 164, 69]),
+	("mushroom_fields", [255, 0, 255]),
+	("ocean", [0, 0, 112]),
+	("old_growth_birch_forest", [88, 156, 108]),
+	("old_growth_pine_taiga", [89, 102, 81]),
+	("old_growth_spruce_taiga", [129, 142, 121]),
+	("plains", [141, 179, 96]),
+	("river", [0, 0, 255]),
+	("savanna", [189, 178, 95]),
+	("savanna_plateau", [167, 157, 100]),
+	("snowy_beach", [250, 240, 192]),
+	("snowy_plains", [255, 255, 255]),
+	("snowy_slopes", [196, 196, 196]),
+	("snowy_taiga", [49, 85, 74]),
+	("sparse_jungle", [98, 139, 23]),
+	("stony_peaks", [123, 143, 116]),
+	("stony_shore", [162, 162, 132]),
+	("sunflower_plains", [181, 219, 136]),
+	("swamp", [7, 249, 178]),
+	("taiga", [11, 102, 89]),
+	("the_void", [0, 0, 0]),
+	("warm_ocean", [0, 0, 172]),
+	("windswept_forest", [80, 112, 80]),
+	("windswept_gravelly_hills", [136, 136, 136]),
+	("windswept_hills", [96, 96, 96]),
+	("windswept_savanna", [229, 218, 135]),
+	("wooded_badlands", [176, 151, 101]),
+	("basalt_deltas", [64, 54, 54]),
+	("crimson_forest", [221, 8, 8]),
+	("nether_wastes", [191, 59, 59]),
+	("soul_sand_valley", [94, 56, 48]),
+	("warped_forest", [73, 144, 123]),
+	("end_barrens", [128, 128, 255]),
+	("end_highlands", [144, 144, 255]),
+	("end_midlands", [112, 112, 255]),
+	("small_end_islands", [96, 96, 224]),
+	("the_end", [128, 128, 255]),
+];
+
+/// Derives a color from a biome ID
+///
+/// Used for biomes without predefined color. The FNV-1a hash of the ID
+/// selects a hue, so the color is stable across runs and platforms.
+fn hashed_color(id: &str) -> Color {
+	let hash = id.bytes().fold(0x811c9dc5_u32, |hash, b| {
+		(hash ^ u32::from(b)).wrapping_mul(0x01000193)
+	});
+
+	let hue = (hash % 360) as f32 / 60.0;
+	let saturation = 0.5 + ((hash >> 16) % 4) as f32 * 0.1;
+	let value = 0.6 + ((hash >> 24) % 4) as f32 * 0.1;
+
+	let chroma = value * saturation;
+	let x = chroma * (1.0 - (hue % 2.0 - 1.0).abs());
+	let (r, g, b) = match hue as u32 {
+		0 => (chroma, x, 0.0),
+		1 => (x, chroma, 0.0),
+		2 => (0.0, chroma, x),
+		3 => (0.0, x, chroma),
+		4 => (x, 0.0, chroma),
+		_ => (chroma, 0.0, x),
+	};
+	let m = value - chroma;
+
+	Color([r, g, b].map(|c| (255.0 * (c + m)) as u8))
+}
+
+/// Returns the color used to display a biome on the biome map layer
+///
+/// *id* is the namespaced ID of the biome. Standard Minecraft biomes use
+/// predefined colors; other biomes get a color derived from their ID.
+pub fn biome_color(id: &str) -> Color {
+	id.strip_prefix("minecraft:")
+		.and_then(|suffix| {
+			BIOME_COLORS
+				.iter()
+				.find(|(biome, _)| *biome == suffix)
+				.map(|&(_, color)| Color(color))
+		})
+		.unwrap_or_else(|| hashed_color(id))
+}
@@ -59,6 +59,24 @@ impl Biome {
 		}
 	}
 
+	/// Constructs a new Biome from raw temperature and downfall values
+	///
+	/// The values are rounded to the precision of the storage format.
+	pub fn from_climate(temp: f32, downfall: f32) -> Biome {
+		/// Helper to encode temperature and downfall values
+		fn encode(v: f32) -> i8 {
+			(v * 20.0).round().clamp(i8::MIN.into(), i8::MAX.into()) as i8
+		}
+		Biome {
+			temp: encode(temp),
+			downfall: encode(downfall),
+			grass_color_modifier: None,
+			water_color: None,
+			foliage_color: None,
+			grass_color: None,
+		}
+	}
+
 	/// Builder function to override the biome water color
 	const fn water(self, water_color: [u8; 3]) -> Biome {
 		Biome {
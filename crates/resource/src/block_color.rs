@@ -1,5 +1,7 @@
 //! Functions for computations of block colors
 
+use serde::{Deserialize, Serialize};
+
 use super::{Biome, BlockColor, Color, Colorf};
 
 /// Converts an u8 RGB color to a float vector
@@ -12,12 +14,124 @@ fn color_vec(color: Color) -> Colorf {
 	color_vec_unscaled(color) / 255.0
 }
 
+/// Finds the control points surrounding a height
+///
+/// Returns the indices of the two points and the interpolation factor
+/// between them. Heights outside of the range of the points are clamped.
+/// Returns [None] if the list of points is empty.
+fn curve_segment<T>(points: &[(f32, T)], height: f32) -> Option<(usize, usize, f32)> {
+	let upper = points.iter().position(|&(stop, _)| stop >= height);
+	let Some(upper) = upper.filter(|&upper| upper > 0) else {
+		let index = upper.unwrap_or(points.len().checked_sub(1)?);
+		return Some((index, index, 0.0));
+	};
+
+	let (h0, _) = points[upper - 1];
+	let (h1, _) = points[upper];
+	Some((upper - 1, upper, (height - h0) / (h1 - h0)))
+}
+
+/// A piecewise linear function of the block height
+///
+/// The curve is defined by a list of (height, value) control points sorted
+/// by height. Between the control points, the value is interpolated
+/// linearly; outside of their range, the value of the nearest point is used.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HeightCurve(pub Vec<(f32, f32)>);
+
+impl HeightCurve {
+	/// Returns the value of the curve at a given height
+	///
+	/// Curves without control points have the value 1.0 everywhere.
+	pub fn value(&self, height: f32) -> f32 {
+		let Some((i0, i1, t)) = curve_segment(&self.0, height) else {
+			return 1.0;
+		};
+		let v0 = self.0[i0].1;
+		let v1 = self.0[i1].1;
+		v0 + (v1 - v0) * t
+	}
+}
+
+/// A color ramp defined by control points at different block heights
+///
+/// Colors are interpolated in the same way as the values of a [HeightCurve].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct HeightRamp(pub Vec<(f32, Color)>);
+
+impl HeightRamp {
+	/// Returns the color of the ramp at a given height
+	///
+	/// Returns [None] for ramps without control points.
+	pub fn color(&self, height: f32) -> Option<Colorf> {
+		let (i0, i1, t) = curve_segment(&self.0, height)?;
+		let c0 = color_vec_unscaled(self.0[i0].1);
+		let c1 = color_vec_unscaled(self.0[i1].1);
+		Some(c0.lerp(c1, t))
+	}
+}
+
+/// Height-dependent adjustments of block colors
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ColorStyle {
+	/// Brightness factor of blocks by height
+	pub brightness: HeightCurve,
+	/// Brightness factor of water by height
+	///
+	/// Unless translucent blocks are blended, the height passed for water
+	/// is the height of the ground below the water, so the curve describes
+	/// the shading of water by depth.
+	pub water_brightness: HeightCurve,
+	/// Color ramp for hypsometric tinting of blocks other than water
+	pub tint: HeightRamp,
+	/// Strength of the hypsometric tint, from 0.0 (none) to 1.0 (replaces the block color)
+	pub tint_strength: f32,
+	/// Reduce the biome temperature used for grass and foliage colors above Y=64
+	///
+	/// Minecraft's grass and foliage colors get less saturated at high
+	/// altitudes.
+	pub height_temperature: bool,
+}
+
+impl Default for ColorStyle {
+	/// Returns the standard MinedMap color style
+	///
+	/// The brightness increases linearly with the height. Heights are limited
+	/// to -2032..=2031 by Minecraft, so two control points suffice to cover
+	/// all possible heights.
+	fn default() -> Self {
+		let brightness = |height: f32| 0.5 + 0.005 * height;
+		let curve = HeightCurve(vec![
+			(-2048.0, brightness(-2048.0)),
+			(2048.0, brightness(2048.0)),
+		]);
+
+		ColorStyle {
+			brightness: curve.clone(),
+			water_brightness: curve,
+			tint: HeightRamp::default(),
+			tint_strength: 0.5,
+			height_temperature: true,
+		}
+	}
+}
+
+impl ColorStyle {
+	/// Returns the amount the biome temperature is reduced by at a given height
+	fn temperature_offset(&self, depth: f32) -> f32 {
+		if !self.height_temperature {
+			return 0.0;
+		}
+		f32::max((depth - 64.0) / 600.0, 0.0)
+	}
+}
+
 /// Helper for grass and foliage colors
 ///
-/// Biome temperature and downfall are modified based on the depth value
-/// before using them to compute the final color
-fn color_from_params(colors: &[Colorf; 3], biome: &Biome, depth: f32) -> Colorf {
-	let temp = (biome.temp() - f32::max((depth - 64.0) / 600.0, 0.0)).clamp(0.0, 1.0);
+/// Biome temperature and downfall are modified based on the temperature
+/// offset before using them to compute the final color
+fn color_from_params(colors: &[Colorf; 3], biome: &Biome, temp_offset: f32) -> Colorf {
+	let temp = (biome.temp() - temp_offset).clamp(0.0, 1.0);
 	let downfall = biome.downfall().clamp(0.0, 1.0) * temp;
 
 	colors[0] + temp * colors[1] + downfall * colors[2]
@@ -25,16 +139,16 @@ fn color_from_params(colors: &[Colorf; 3], biome: &Biome, depth: f32) -> Colorf
 
 /// Extension trait with helpers for computing biome-specific block colors
 trait BiomeExt {
-	/// Returns the grass color of the biome at a given depth
-	fn grass_color(&self, depth: f32) -> Colorf;
-	/// Returns the foliage color of the biome at a given depth
-	fn foliage_color(&self, depth: f32) -> Colorf;
+	/// Returns the grass color of the biome with a given temperature offset
+	fn grass_color(&self, temp_offset: f32) -> Colorf;
+	/// Returns the foliage color of the biome with a given temperature offset
+	fn foliage_color(&self, temp_offset: f32) -> Colorf;
 	/// Returns the water color of the biome
 	fn water_color(&self) -> Colorf;
 }
 
 impl BiomeExt for Biome {
-	fn grass_color(&self, depth: f32) -> Colorf {
+	fn grass_color(&self, temp_offset: f32) -> Colorf {
 		use super::BiomeGrassColorModifier::*;
 
 		/// Color matrix extracted from grass color texture
@@ -51,7 +165,7 @@ impl BiomeExt for Biome {
 		let regular_color = || {
 			self.grass_color
 				.map(color_vec)
-				.unwrap_or_else(|| color_from_params(&GRASS_COLORS, self, depth))
+				.unwrap_or_else(|| color_from_params(&GRASS_COLORS, self, temp_offset))
 		};
 
 		match self.grass_color_modifier {
@@ -61,7 +175,7 @@ impl BiomeExt for Biome {
 		}
 	}
 
-	fn foliage_color(&self, depth: f32) -> Colorf {
+	fn foliage_color(&self, temp_offset: f32) -> Colorf {
 		/// Color matrix extracted from foliage color texture
 		const FOLIAGE_COLORS: [Colorf; 3] = [
 			Colorf::new(0.376, 0.631, 0.482),   // lower right
@@ -71,7 +185,7 @@ impl BiomeExt for Biome {
 
 		self.foliage_color
 			.map(color_vec)
-			.unwrap_or_else(|| color_from_params(&FOLIAGE_COLORS, self, depth))
+			.unwrap_or_else(|| color_from_params(&FOLIAGE_COLORS, self, temp_offset))
 	}
 
 	fn water_color(&self) -> Colorf {
@@ -100,9 +214,17 @@ pub fn needs_biome(block: BlockColor) -> bool {
 
 /// Determined the block color to display for a given [BlockColor]
 ///
+/// The height-dependent adjustments of the color are configured by the
+/// passed [ColorStyle].
+///
 /// [needs_biome] must be used to determine whether passing a [Biome] is necessary.
 /// Will panic if a [Biome] is necessary, but none is passed.
-pub fn block_color(block: BlockColor, biome: Option<&Biome>, depth: f32) -> Colorf {
+pub fn block_color(
+	block: BlockColor,
+	biome: Option<&Biome>,
+	depth: f32,
+	style: &ColorStyle,
+) -> Colorf {
 	use super::BlockFlag::*;
 
 	let get_biome = || biome.expect("needs biome to determine block color");
@@ -110,10 +232,10 @@ pub fn block_color(block: BlockColor, biome: Option<&Biome>, depth: f32) -> Colo
 	let mut color = color_vec_unscaled(block.color);
 
 	if block.is(Grass) {
-		color *= get_biome().grass_color(depth);
+		color *= get_biome().grass_color(style.temperature_offset(depth));
 	}
 	if block.is(Foliage) {
-		color *= get_biome().foliage_color(depth);
+		color *= get_biome().foliage_color(style.temperature_offset(depth));
 	}
 	if block.is(Birch) {
 		color *= BIRCH_COLOR;
@@ -123,7 +245,12 @@ pub fn block_color(block: BlockColor, biome: Option<&Biome>, depth: f32) -> Colo
 	}
 	if block.is(Water) {
 		color *= get_biome().water_color();
+		return color * style.water_brightness.value(depth);
+	}
+
+	if let Some(tint) = style.tint.color(depth) {
+		color = color.lerp(tint, style.tint_strength);
 	}
 
-	color * (0.5 + 0.005 * depth)
+	color * style.brightness.value(depth)
 }
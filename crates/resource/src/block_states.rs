@@ -0,0 +1,220 @@
+//! Block state-dependent variants of block types
+
+use std::collections::BTreeMap;
+
+use enumflags2::make_bitflags;
+
+use super::{BlockFlag, BlockType, Color};
+
+/// Approximate average color of young crops
+const SPROUT_COLOR: [u8; 3] = [60, 130, 35];
+
+/// Approximate average color of lit redstone lamps
+const LIT_REDSTONE_LAMP_COLOR: [u8; 3] = [180, 122, 70];
+
+/// Color of snowy grass blocks, podzol and mycelium
+const SNOWY_COLOR: [u8; 3] = [249, 254, 254];
+
+/// Modification of a block type for matching block states
+#[derive(Debug, Clone, Copy)]
+enum Modifier {
+	/// Sets the light level emitted by the block
+	Light(u8),
+	/// Replaces the block color, removing biome-dependent coloring
+	Color([u8; 3]),
+	/// Multiplies the block color with a tint, removing biome-dependent coloring
+	Tint([u8; 3]),
+	/// Blends the block color with another color
+	///
+	/// A factor of 0 keeps the block color, 1 replaces it with the other color.
+	Blend([u8; 3], f32),
+	/// Scales the brightness of the block color
+	Scale(f32),
+}
+
+impl Modifier {
+	/// Applies the modification to a block type
+	fn apply(self, block_type: &mut BlockType) {
+		let block_color = &mut block_type.block_color;
+		let [r, g, b] = block_color.color.0.map(f32::from);
+
+		let color = match self {
+			Modifier::Light(level) => {
+				block_type.light_emission = level;
+				return;
+			}
+			Modifier::Color(color) => color.map(f32::from),
+			Modifier::Tint(tint) => {
+				let [tr, tg, tb] = tint.map(|v| f32::from(v) / 255.0);
+				[r * tr, g * tg, b * tb]
+			}
+			Modifier::Blend(other, factor) => {
+				let [or, og, ob] = other.map(f32::from);
+				[
+					r + (or - r) * factor,
+					g + (og - g) * factor,
+					b + (ob - b) * factor,
+				]
+			}
+			Modifier::Scale(factor) => [r * factor, g * factor, b * factor],
+		};
+
+		if matches!(self, Modifier::Color(_) | Modifier::Tint(_)) {
+			block_color
+				.flags
+				.remove(make_bitflags!(BlockFlag::{Grass | Foliage | Birch | Spruce}));
+		}
+		block_color.color = Color(color.map(|v| v.round().clamp(0.0, 255.0) as u8));
+	}
+}
+
+/// Variant of a block type used for block states with specific property values
+#[derive(Debug, Clone)]
+pub struct StateVariant {
+	/// Property values a block state must have to use the variant
+	properties: Vec<(&'static str, String)>,
+	/// Modified block type
+	pub block_type: BlockType,
+}
+
+impl StateVariant {
+	/// Checks whether a block state has all property values of the variant
+	pub fn matches(&self, properties: &BTreeMap<String, String>) -> bool {
+		self.properties
+			.iter()
+			.all(|(key, value)| properties.get(*key) == Some(value))
+	}
+}
+
+/// Block state-dependent modifications of a block type
+///
+/// Each entry lists the property values a block state must have and the
+/// modifications applied to the block type. The first matching entry is used.
+type Rules = Vec<(Vec<(&'static str, String)>, Vec<Modifier>)>;
+
+/// Adds rules for the growth stages of a crop
+///
+/// The color of the fully grown crop is blended with [SPROUT_COLOR] for
+/// younger stages.
+fn crop(rules: &mut Rules, max_age: u8) {
+	for age in 0..max_age {
+		let factor = 1.0 - f32::from(age) / f32::from(max_age);
+		rules.push((
+			vec![("age", age.to_string())],
+			vec![Modifier::Blend(SPROUT_COLOR, factor)],
+		));
+	}
+}
+
+/// Adds a rule setting the light emission of a block when a property has a given value
+fn light(rules: &mut Rules, property: &'static str, value: &str, level: u8) {
+	rules.push((
+		vec![(property, value.to_string())],
+		vec![Modifier::Light(level)],
+	));
+}
+
+/// Returns the block state-dependent modifications of a standard Minecraft block type
+///
+/// *id* is the block ID without namespace.
+fn rules(id: &str) -> Rules {
+	let mut rules = Rules::new();
+
+	match id.strip_prefix("waxed_").unwrap_or(id) {
+		"wheat" | "carrots" | "potatoes" => crop(&mut rules, 7),
+		"beetroots" => crop(&mut rules, 3),
+		"melon_stem" | "pumpkin_stem" => {
+			// Stems are tinted depending on their age like in Minecraft
+			for age in 0..=7 {
+				rules.push((
+					vec![("age", age.to_string())],
+					vec![Modifier::Tint([age * 32, 255 - age * 8, age * 4])],
+				));
+			}
+		}
+		"redstone_wire" => {
+			// Brightness of the red component of the Minecraft redstone color,
+			// relative to full power
+			for power in 0u8..15 {
+				let factor = f32::from(power) / 15.0 * 0.6 + if power > 0 { 0.4 } else { 0.3 };
+				rules.push((
+					vec![("power", power.to_string())],
+					vec![Modifier::Scale(factor)],
+				));
+			}
+		}
+		"grass_block" | "podzol" | "mycelium" => rules.push((
+			vec![("snowy", "true".to_string())],
+			vec![Modifier::Color(SNOWY_COLOR)],
+		)),
+		"furnace" | "blast_furnace" | "smoker" => light(&mut rules, "lit", "true", 13),
+		"redstone_lamp" => rules.push((
+			vec![("lit", "true".to_string())],
+			vec![
+				Modifier::Light(15),
+				Modifier::Color(LIT_REDSTONE_LAMP_COLOR),
+			],
+		)),
+		"redstone_ore" | "deepslate_redstone_ore" => light(&mut rules, "lit", "true", 9),
+		"campfire" | "soul_campfire" => light(&mut rules, "lit", "false", 0),
+		"cave_vines" | "cave_vines_plant" => light(&mut rules, "berries", "true", 14),
+		"copper_bulb" => light(&mut rules, "lit", "true", 15),
+		"exposed_copper_bulb" => light(&mut rules, "lit", "true", 12),
+		"weathered_copper_bulb" => light(&mut rules, "lit", "true", 8),
+		"oxidized_copper_bulb" => light(&mut rules, "lit", "true", 4),
+		"light" => {
+			for level in 0..=15 {
+				light(&mut rules, "level", &level.to_string(), level);
+			}
+		}
+		"respawn_anchor" => {
+			for (charges, level) in [(1, 3), (2, 7), (3, 11), (4, 15)] {
+				light(&mut rules, "charges", &charges.to_string(), level);
+			}
+		}
+		"sea_pickle" => {
+			light(&mut rules, "waterlogged", "false", 0);
+			for pickles in 1..=4 {
+				light(&mut rules, "pickles", &pickles.to_string(), 3 + 3 * pickles);
+			}
+		}
+		id if id.ends_with("candle_cake") => light(&mut rules, "lit", "true", 3),
+		id if id.ends_with("candle") => {
+			for candles in 1..=4 {
+				rules.push((
+					vec![
+						("lit", "true".to_string()),
+						("candles", candles.to_string()),
+					],
+					vec![Modifier::Light(3 * candles)],
+				));
+			}
+		}
+		_ => {}
+	}
+
+	rules
+}
+
+/// Returns the block state-dependent variants of a block type
+///
+/// Only standard Minecraft block types have variants.
+pub fn variants(block_type: &BlockType) -> Vec<StateVariant> {
+	let Some(id) = block_type.id.strip_prefix("minecraft:") else {
+		return Vec::new();
+	};
+
+	rules(id)
+		.into_iter()
+		.map(|(properties, modifiers)| {
+			let mut variant = block_type.clone();
+			for modifier in modifiers {
+				modifier.apply(&mut variant);
+			}
+			StateVariant {
+				properties,
+				block_type: variant,
+			}
+		})
+		.collect()
+}
@@ -9,6 +9,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -19,6 +20,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([167, 95, 60]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -29,6 +31,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([168, 90, 50]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -39,6 +42,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([168, 90, 50]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -49,6 +53,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: Some("acacia"),
 		},
@@ -59,6 +64,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque|Foliage}),
 				color: Color([149, 148, 148]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -69,6 +75,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([150, 88, 55]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -79,6 +86,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([168, 90, 50]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -89,6 +97,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([168, 90, 50]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -99,6 +108,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([118, 117, 23]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -109,6 +119,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: Some("acacia"),
 		},
@@ -119,6 +130,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([168, 90, 50]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -129,6 +141,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([168, 90, 50]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -139,6 +152,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([156, 87, 51]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -149,6 +163,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{WallSign}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: Some("acacia"),
 		},
@@ -159,6 +174,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{WallSign}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: Some("acacia"),
 		},
@@ -169,6 +185,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([103, 96, 86]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -179,6 +196,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([115, 87, 74]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -189,6 +207,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -199,6 +218,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -209,6 +229,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([133, 97, 191]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -219,6 +240,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([163, 126, 207]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -229,6 +251,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([94, 66, 58]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -239,6 +262,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([136, 136, 136]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -249,6 +273,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([136, 136, 136]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -259,6 +284,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([136, 136, 136]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -269,6 +295,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([136, 136, 136]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -279,6 +306,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([72, 72, 72]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -289,6 +317,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque|Grass}),
 				color: Color([141, 142, 141]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -299,6 +328,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque|Grass}),
 				color: Color([139, 139, 139]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -309,6 +339,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([101, 124, 47]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -319,6 +350,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([90, 114, 44]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -329,6 +361,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -339,6 +372,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([93, 144, 19]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -349,6 +383,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([139, 141, 62]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -359,6 +394,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -369,6 +405,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([191, 171, 81]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -379,6 +416,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([193, 173, 80]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -389,6 +427,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([193, 173, 80]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -399,6 +438,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: Some("bamboo"),
 		},
@@ -409,6 +449,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([190, 170, 78]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -419,6 +460,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([190, 170, 78]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -429,6 +471,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([190, 170, 78]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -439,6 +482,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([193, 173, 80]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -449,6 +493,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([193, 173, 80]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -459,6 +504,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -469,6 +515,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: Some("bamboo"),
 		},
@@ -479,6 +526,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([193, 173, 80]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -489,6 +537,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([193, 173, 80]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -499,6 +548,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([198, 179, 85]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -509,6 +559,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{WallSign}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: Some("bamboo"),
 		},
@@ -519,6 +570,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{WallSign}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: Some("bamboo"),
 		},
@@ -529,6 +581,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([134, 100, 58]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -539,6 +592,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -549,6 +603,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([80, 81, 86]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -559,6 +614,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([117, 220, 215]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -569,6 +625,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([85, 85, 85]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -579,6 +636,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([202, 160, 74]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -589,6 +647,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([180, 146, 90]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -599,6 +658,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([93, 91, 30]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -609,6 +669,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([253, 235, 110]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -619,6 +680,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([111, 141, 51]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -629,6 +691,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -639,6 +702,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -649,6 +713,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([220, 209, 176]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -659,6 +724,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([192, 175, 121]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -669,6 +735,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([192, 175, 121]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -679,6 +746,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: Some("birch"),
 		},
@@ -689,6 +757,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque|Birch}),
 				color: Color([130, 129, 130]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -699,6 +768,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([193, 179, 135]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -709,6 +779,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([192, 175, 121]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -719,6 +790,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([192, 175, 121]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -729,6 +801,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([127, 160, 79]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -739,6 +812,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: Some("birch"),
 		},
@@ -749,6 +823,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([192, 175, 121]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -759,6 +834,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([192, 175, 121]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -769,6 +845,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([207, 194, 157]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -779,6 +856,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{WallSign}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: Some("birch"),
 		},
@@ -789,6 +867,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{WallSign}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: Some("birch"),
 		},
@@ -799,6 +878,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([216, 215, 210]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -809,6 +889,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -819,6 +900,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -829,6 +911,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -839,6 +922,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([248, 222, 214]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -849,6 +933,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([20, 21, 25]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -859,6 +944,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([8, 10, 15]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -869,6 +955,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([25, 26, 31]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -879,6 +966,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([67, 30, 32]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -889,6 +977,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([25, 25, 29]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -897,8 +986,9 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 		"black_stained_glass",
 		ConstBlockType {
 			block_color: BlockColor {
-				flags: make_bitflags!(BlockFlag::{Opaque}),
+				flags: make_bitflags!(BlockFlag::{Opaque|Translucent}),
 				color: Color([25, 25, 25]),
+				alpha: 128,
 			},
 			sign_material: None,
 		},
@@ -907,8 +997,9 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 		"black_stained_glass_pane",
 		ConstBlockType {
 			block_color: BlockColor {
-				flags: make_bitflags!(BlockFlag::{Opaque}),
+				flags: make_bitflags!(BlockFlag::{Opaque|Translucent}),
 				color: Color([24, 24, 24]),
+				alpha: 128,
 			},
 			sign_material: None,
 		},
@@ -919,6 +1010,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([37, 22, 16]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -929,6 +1021,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -939,6 +1032,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([20, 21, 25]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -949,6 +1043,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([42, 36, 41]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -959,6 +1054,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([42, 36, 41]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -969,6 +1065,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([42, 36, 41]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -979,6 +1076,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([42, 36, 41]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -989,6 +1087,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([80, 80, 81]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -999,6 +1098,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1009,6 +1109,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1019,6 +1120,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1029,6 +1131,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([248, 222, 214]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1039,6 +1142,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([53, 57, 157]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1049,6 +1153,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([44, 46, 143]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1059,6 +1164,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([70, 73, 166]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1069,6 +1175,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([47, 64, 139]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1079,6 +1186,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([116, 167, 253]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1089,6 +1197,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1099,6 +1208,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([43, 45, 140]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1107,8 +1217,9 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 		"blue_stained_glass",
 		ConstBlockType {
 			block_color: BlockColor {
-				flags: make_bitflags!(BlockFlag::{Opaque}),
+				flags: make_bitflags!(BlockFlag::{Opaque|Translucent}),
 				color: Color([51, 76, 178]),
+				alpha: 128,
 			},
 			sign_material: None,
 		},
@@ -1117,8 +1228,9 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 		"blue_stained_glass_pane",
 		ConstBlockType {
 			block_color: BlockColor {
-				flags: make_bitflags!(BlockFlag::{Opaque}),
+				flags: make_bitflags!(BlockFlag::{Opaque|Translucent}),
 				color: Color([48, 73, 171]),
+				alpha: 128,
 			},
 			sign_material: None,
 		},
@@ -1129,6 +1241,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([74, 59, 91]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1139,6 +1252,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1149,6 +1263,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([53, 57, 157]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1159,6 +1274,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([209, 206, 179]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1169,6 +1285,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([162, 130, 78]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1179,6 +1296,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1189,6 +1307,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([207, 91, 159]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1199,6 +1318,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1209,6 +1329,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1219,6 +1340,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([122, 100, 80]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1229,6 +1351,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([150, 97, 83]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1239,6 +1362,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([150, 97, 83]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1249,6 +1373,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([150, 97, 83]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1259,6 +1384,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([150, 97, 83]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1269,6 +1395,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1279,6 +1406,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1289,6 +1417,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1299,6 +1428,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([248, 222, 214]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1309,6 +1439,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([114, 71, 40]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1319,6 +1450,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([96, 59, 31]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1329,6 +1461,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([125, 84, 53]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1339,6 +1472,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([119, 106, 85]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1349,6 +1483,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1359,6 +1494,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([149, 111, 81]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1369,6 +1505,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([106, 66, 35]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1377,8 +1514,9 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 		"brown_stained_glass",
 		ConstBlockType {
 			block_color: BlockColor {
-				flags: make_bitflags!(BlockFlag::{Opaque}),
+				flags: make_bitflags!(BlockFlag::{Opaque|Translucent}),
 				color: Color([102, 76, 51]),
+				alpha: 128,
 			},
 			sign_material: None,
 		},
@@ -1387,8 +1525,9 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 		"brown_stained_glass_pane",
 		ConstBlockType {
 			block_color: BlockColor {
-				flags: make_bitflags!(BlockFlag::{Opaque}),
+				flags: make_bitflags!(BlockFlag::{Opaque|Translucent}),
 				color: Color([97, 73, 48]),
+				alpha: 128,
 			},
 			sign_material: None,
 		},
@@ -1399,6 +1538,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([77, 51, 35]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1409,6 +1549,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1419,6 +1560,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([114, 71, 40]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1427,8 +1569,9 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 		"bubble_column",
 		ConstBlockType {
 			block_color: BlockColor {
-				flags: make_bitflags!(BlockFlag::{Opaque|Water}),
+				flags: make_bitflags!(BlockFlag::{Opaque|Water|Translucent}),
 				color: Color([177, 177, 177]),
+				alpha: 128,
 			},
 			sign_material: None,
 		},
@@ -1439,6 +1582,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1449,6 +1593,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([165, 26, 162]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1459,6 +1604,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1469,6 +1615,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1479,6 +1626,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([132, 96, 186]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1489,6 +1637,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([85, 127, 43]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1499,6 +1648,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([248, 222, 214]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1509,6 +1659,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([223, 224, 220]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1519,6 +1670,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([27, 79, 100]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1529,6 +1681,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([110, 88, 54]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1539,6 +1692,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1549,6 +1703,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([248, 222, 214]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1559,6 +1714,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([81, 124, 37]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1569,6 +1725,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([103, 87, 67]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1579,6 +1736,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([198, 118, 24]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1589,6 +1747,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([73, 72, 74]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1599,6 +1758,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1609,6 +1769,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([90, 109, 40]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1619,6 +1780,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([88, 101, 38]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1629,6 +1791,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1639,6 +1802,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([131, 161, 147]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1649,6 +1813,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1659,6 +1824,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([223, 170, 164]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1669,6 +1835,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([226, 178, 172]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1679,6 +1846,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([226, 178, 172]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1689,6 +1857,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: Some("cherry"),
 		},
@@ -1699,6 +1868,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([229, 172, 194]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1709,6 +1879,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([185, 141, 137]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1719,6 +1890,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([226, 178, 172]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1729,6 +1901,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([226, 178, 172]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1739,6 +1912,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1749,6 +1923,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: Some("cherry"),
 		},
@@ -1759,6 +1934,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([226, 178, 172]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1769,6 +1945,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([226, 178, 172]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1779,6 +1956,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([226, 178, 172]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1789,6 +1967,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{WallSign}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: Some("cherry"),
 		},
@@ -1799,6 +1978,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{WallSign}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: Some("cherry"),
 		},
@@ -1809,6 +1989,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([54, 33, 44]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1819,6 +2000,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([162, 130, 78]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1829,6 +2011,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([72, 72, 72]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1839,6 +2022,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([178, 144, 88]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1849,6 +2033,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([184, 100, 73]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1859,6 +2044,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([54, 54, 54]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1869,6 +2055,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([47, 23, 28]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1879,6 +2066,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([53, 48, 56]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1889,6 +2077,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([231, 226, 218]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1899,6 +2088,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([181, 97, 31]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1909,6 +2099,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([223, 214, 170]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1919,6 +2110,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([119, 118, 119]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1929,6 +2121,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([89, 94, 86]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1939,6 +2132,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([110, 113, 106]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1949,6 +2143,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([151, 120, 151]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1959,6 +2154,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([93, 57, 93]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1969,6 +2165,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([160, 166, 179]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1979,6 +2176,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([16, 15, 15]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1989,6 +2187,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([105, 105, 105]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -1999,6 +2198,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([119, 85, 59]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2009,6 +2209,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([77, 77, 80]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2019,6 +2220,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([77, 77, 80]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2029,6 +2231,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([77, 77, 80]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2039,6 +2242,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([77, 77, 80]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2049,6 +2253,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([127, 127, 127]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2059,6 +2264,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([127, 127, 127]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2069,6 +2275,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([127, 127, 127]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2079,6 +2286,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([127, 127, 127]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2089,6 +2297,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([228, 233, 234]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2099,6 +2308,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([154, 91, 40]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2109,6 +2319,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([181, 136, 108]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2119,6 +2330,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([166, 161, 159]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2129,6 +2341,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([88, 61, 23]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2139,6 +2352,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([159, 139, 113]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2149,6 +2363,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([192, 107, 79]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2159,6 +2374,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([156, 86, 57]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2169,6 +2385,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([192, 109, 82]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2179,6 +2396,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([191, 107, 79]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2189,6 +2407,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([124, 125, 120]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2199,6 +2418,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([190, 106, 79]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2209,6 +2429,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2219,6 +2440,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([64, 64, 65]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2229,6 +2451,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([52, 52, 52]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2239,6 +2462,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([40, 20, 23]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2249,6 +2473,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([44, 37, 43]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2259,6 +2484,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([118, 117, 118]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2269,6 +2495,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([112, 98, 99]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2279,6 +2506,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([119, 73, 42]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2289,6 +2517,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2299,6 +2528,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2309,6 +2539,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2319,6 +2550,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([114, 54, 79]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2329,6 +2561,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([101, 48, 70]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2339,6 +2572,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([101, 48, 70]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2349,6 +2583,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2359,6 +2594,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: Some("crimson"),
 		},
@@ -2369,6 +2605,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([92, 25, 29]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2379,6 +2616,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([130, 31, 31]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2389,6 +2627,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([101, 48, 70]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2399,6 +2638,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([101, 48, 70]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2409,6 +2649,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([126, 8, 41]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2419,6 +2660,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: Some("crimson"),
 		},
@@ -2429,6 +2671,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([101, 48, 70]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2439,6 +2682,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([101, 48, 70]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2449,6 +2693,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([112, 49, 70]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2459,6 +2704,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([103, 50, 72]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2469,6 +2715,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{WallSign}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: Some("crimson"),
 		},
@@ -2479,6 +2726,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{WallSign}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: Some("crimson"),
 		},
@@ -2489,6 +2737,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([32, 10, 60]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2499,6 +2748,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([191, 106, 80]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2509,6 +2759,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([191, 106, 80]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2519,6 +2770,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([191, 106, 80]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2529,6 +2781,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([181, 97, 31]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2539,6 +2792,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([181, 97, 31]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2549,6 +2803,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([223, 214, 170]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2559,6 +2814,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([223, 214, 170]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2569,6 +2825,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2579,6 +2836,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2589,6 +2847,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2599,6 +2858,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([248, 222, 214]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2609,6 +2869,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([21, 137, 145]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2619,6 +2880,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([21, 119, 136]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2629,6 +2891,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([36, 147, 157]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2639,6 +2902,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([52, 118, 125]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2649,6 +2913,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([20, 121, 135]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2657,8 +2922,9 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 		"cyan_stained_glass",
 		ConstBlockType {
 			block_color: BlockColor {
-				flags: make_bitflags!(BlockFlag::{Opaque}),
+				flags: make_bitflags!(BlockFlag::{Opaque|Translucent}),
 				color: Color([76, 127, 153]),
+				alpha: 128,
 			},
 			sign_material: None,
 		},
@@ -2667,8 +2933,9 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 		"cyan_stained_glass_pane",
 		ConstBlockType {
 			block_color: BlockColor {
-				flags: make_bitflags!(BlockFlag::{Opaque}),
+				flags: make_bitflags!(BlockFlag::{Opaque|Translucent}),
 				color: Color([73, 122, 147]),
+				alpha: 128,
 			},
 			sign_material: None,
 		},
@@ -2679,6 +2946,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([86, 91, 91]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2689,6 +2957,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2699,6 +2968,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([21, 137, 145]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2709,6 +2979,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([72, 72, 72]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2719,6 +2990,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2729,6 +3001,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2739,6 +3012,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([76, 51, 25]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2749,6 +3023,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([66, 43, 20]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2759,6 +3034,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([66, 43, 20]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2769,6 +3045,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: Some("dark_oak"),
 		},
@@ -2779,6 +3056,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque|Foliage}),
 				color: Color([150, 150, 150]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2789,6 +3067,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([67, 45, 22]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2799,6 +3078,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([66, 43, 20]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2809,6 +3089,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([66, 43, 20]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2819,6 +3100,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([61, 90, 30]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2829,6 +3111,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: Some("dark_oak"),
 		},
@@ -2839,6 +3122,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([66, 43, 20]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2849,6 +3133,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([66, 43, 20]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2859,6 +3144,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([75, 49, 23]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2869,6 +3155,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{WallSign}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: Some("dark_oak"),
 		},
@@ -2879,6 +3166,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{WallSign}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: Some("dark_oak"),
 		},
@@ -2889,6 +3177,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([60, 46, 26]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2899,6 +3188,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([51, 91, 75]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2909,6 +3199,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([51, 91, 75]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2919,6 +3210,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([51, 91, 75]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2929,6 +3221,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([130, 116, 94]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2939,6 +3232,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2949,6 +3243,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([124, 117, 114]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2959,6 +3254,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2969,6 +3265,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2979,6 +3276,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2989,6 +3287,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([131, 123, 119]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -2999,6 +3298,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3009,6 +3309,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3019,6 +3320,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([107, 78, 40]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3029,6 +3331,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3039,6 +3342,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([131, 123, 119]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3049,6 +3353,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3059,6 +3364,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3069,6 +3375,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3079,6 +3386,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([133, 126, 122]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3089,6 +3397,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3099,6 +3408,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3109,6 +3419,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3119,6 +3430,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([130, 123, 119]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3129,6 +3441,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3139,6 +3452,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3149,6 +3463,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([124, 68, 53]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3159,6 +3474,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([80, 80, 82]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3169,6 +3485,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([70, 70, 71]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3179,6 +3496,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([70, 70, 71]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3189,6 +3507,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([70, 70, 71]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3199,6 +3518,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([70, 70, 71]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3209,6 +3529,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([74, 74, 76]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3219,6 +3540,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([92, 93, 89]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3229,6 +3551,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([83, 106, 106]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3239,6 +3562,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([78, 104, 87]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3249,6 +3573,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([115, 102, 78]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3259,6 +3584,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([106, 99, 94]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3269,6 +3595,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([79, 90, 115]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3279,6 +3606,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([104, 73, 74]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3289,6 +3617,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([54, 54, 55]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3299,6 +3628,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([54, 54, 55]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3309,6 +3639,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([54, 54, 55]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3319,6 +3650,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([54, 54, 55]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3329,6 +3661,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([123, 104, 90]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3339,6 +3672,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([98, 237, 228]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3349,6 +3683,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([121, 141, 140]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3359,6 +3694,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([188, 188, 188]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3369,6 +3705,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([188, 188, 188]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3379,6 +3716,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([188, 188, 188]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3389,6 +3727,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([188, 188, 188]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3399,6 +3738,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([134, 96, 67]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3409,6 +3749,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([148, 121, 65]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3419,6 +3760,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([110, 109, 109]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3429,6 +3771,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([12, 9, 15]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3439,6 +3782,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3449,6 +3793,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3459,6 +3804,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([50, 58, 38]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3469,6 +3815,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([134, 107, 92]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3479,6 +3826,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([110, 109, 109]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3489,6 +3837,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([42, 203, 87]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3499,6 +3848,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([108, 136, 115]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3509,6 +3859,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([128, 75, 85]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3519,6 +3870,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([15, 10, 24]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3529,6 +3881,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([15, 10, 24]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3539,6 +3892,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([91, 120, 97]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3549,6 +3903,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3559,6 +3914,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([219, 222, 158]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3569,6 +3925,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([218, 224, 162]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3579,6 +3936,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([218, 224, 162]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3589,6 +3947,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([218, 224, 162]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3599,6 +3958,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([218, 224, 162]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3609,6 +3969,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([15, 10, 24]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3619,6 +3980,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([154, 119, 100]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3629,6 +3991,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([161, 125, 103]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3639,6 +4002,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([135, 107, 89]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3649,6 +4013,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([164, 123, 106]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3659,6 +4024,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([161, 125, 104]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3669,6 +4035,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([161, 125, 104]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3679,6 +4046,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([154, 121, 101]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3689,6 +4057,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([154, 121, 101]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3699,6 +4068,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([154, 121, 101]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3709,6 +4079,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([81, 44, 15]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3719,6 +4090,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3729,6 +4101,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([211, 140, 53]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3739,6 +4112,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3749,6 +4123,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([163, 35, 46]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3759,6 +4134,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3769,6 +4145,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3779,6 +4156,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([197, 180, 133]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3789,6 +4167,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([124, 68, 53]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3799,6 +4178,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([112, 121, 64]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3809,6 +4189,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([99, 111, 60]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3819,6 +4200,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([105, 90, 82]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3827,8 +4209,9 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 		"frosted_ice",
 		ConstBlockType {
 			block_color: BlockColor {
-				flags: make_bitflags!(BlockFlag::{Opaque}),
+				flags: make_bitflags!(BlockFlag::{Opaque|Translucent}),
 				color: Color([140, 181, 252]),
+				alpha: 128,
 			},
 			sign_material: None,
 		},
@@ -3839,6 +4222,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([110, 109, 109]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3849,6 +4233,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([55, 42, 38]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3859,6 +4244,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([175, 213, 219]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3869,6 +4255,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([170, 210, 217]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3879,6 +4266,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3889,6 +4277,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3899,6 +4288,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([171, 131, 84]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3909,6 +4299,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([246, 208, 61]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3919,6 +4310,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([145, 133, 106]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3929,6 +4321,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([149, 103, 85]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3939,6 +4332,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([149, 103, 85]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3949,6 +4343,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([149, 103, 85]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3959,6 +4354,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([149, 103, 85]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3969,6 +4365,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3979,6 +4376,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque|Grass}),
 				color: Color([147, 147, 147]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3989,6 +4387,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([148, 121, 65]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -3999,6 +4398,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([131, 127, 126]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4009,6 +4409,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4019,6 +4420,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4029,6 +4431,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4039,6 +4442,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([248, 222, 214]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4049,6 +4453,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([62, 68, 71]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4059,6 +4464,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([54, 57, 61]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4069,6 +4475,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([76, 81, 84]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4079,6 +4486,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([83, 90, 93]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4089,6 +4497,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([55, 58, 62]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4097,8 +4506,9 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 		"gray_stained_glass",
 		ConstBlockType {
 			block_color: BlockColor {
-				flags: make_bitflags!(BlockFlag::{Opaque}),
+				flags: make_bitflags!(BlockFlag::{Opaque|Translucent}),
 				color: Color([76, 76, 76]),
+				alpha: 128,
 			},
 			sign_material: None,
 		},
@@ -4107,8 +4517,9 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 		"gray_stained_glass_pane",
 		ConstBlockType {
 			block_color: BlockColor {
-				flags: make_bitflags!(BlockFlag::{Opaque}),
+				flags: make_bitflags!(BlockFlag::{Opaque|Translucent}),
 				color: Color([73, 73, 73]),
+				alpha: 128,
 			},
 			sign_material: None,
 		},
@@ -4119,6 +4530,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([57, 42, 35]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4129,6 +4541,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4139,6 +4552,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([62, 68, 71]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4149,6 +4563,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4159,6 +4574,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4169,6 +4585,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4179,6 +4596,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([248, 222, 214]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4189,6 +4607,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([84, 109, 27]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4199,6 +4618,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([73, 91, 36]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4209,6 +4629,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([97, 119, 44]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4219,6 +4640,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([117, 142, 67]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4229,6 +4651,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([79, 100, 31]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4237,8 +4660,9 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 		"green_stained_glass",
 		ConstBlockType {
 			block_color: BlockColor {
-				flags: make_bitflags!(BlockFlag::{Opaque}),
+				flags: make_bitflags!(BlockFlag::{Opaque|Translucent}),
 				color: Color([102, 127, 51]),
+				alpha: 128,
 			},
 			sign_material: None,
 		},
@@ -4247,8 +4671,9 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 		"green_stained_glass_pane",
 		ConstBlockType {
 			block_color: BlockColor {
-				flags: make_bitflags!(BlockFlag::{Opaque}),
+				flags: make_bitflags!(BlockFlag::{Opaque|Translucent}),
 				color: Color([97, 122, 48]),
+				alpha: 128,
 			},
 			sign_material: None,
 		},
@@ -4259,6 +4684,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([76, 83, 42]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4269,6 +4695,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4279,6 +4706,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([84, 109, 27]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4289,6 +4717,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([142, 142, 142]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4299,6 +4728,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([161, 115, 91]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4309,6 +4739,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([165, 139, 12]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4319,6 +4750,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([82, 86, 94]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4329,6 +4761,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([220, 220, 220]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4337,8 +4770,9 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 		"honey_block",
 		ConstBlockType {
 			block_color: BlockColor {
-				flags: make_bitflags!(BlockFlag::{Opaque}),
+				flags: make_bitflags!(BlockFlag::{Opaque|Translucent}),
 				color: Color([251, 185, 52]),
+				alpha: 128,
 			},
 			sign_material: None,
 		},
@@ -4349,6 +4783,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([229, 148, 29]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4359,6 +4794,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([75, 74, 75]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4369,6 +4805,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4379,6 +4816,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([216, 199, 66]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4389,6 +4827,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4399,6 +4838,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4407,8 +4847,9 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 		"ice",
 		ConstBlockType {
 			block_color: BlockColor {
-				flags: make_bitflags!(BlockFlag::{Opaque}),
+				flags: make_bitflags!(BlockFlag::{Opaque|Translucent}),
 				color: Color([145, 183, 253]),
+				alpha: 128,
 			},
 			sign_material: None,
 		},
@@ -4419,6 +4860,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([119, 118, 119]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4429,6 +4871,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([127, 127, 127]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4439,6 +4882,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([118, 117, 118]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4449,6 +4893,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([80, 80, 82]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4459,6 +4904,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([115, 121, 105]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4469,6 +4915,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([125, 125, 125]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4479,6 +4926,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([122, 121, 122]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4489,6 +4937,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([136, 139, 135]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4499,6 +4948,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([220, 220, 220]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4509,6 +4959,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([194, 193, 193]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4519,6 +4970,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([136, 129, 122]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4529,6 +4981,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([202, 202, 202]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4539,6 +4992,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4549,6 +5003,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([214, 152, 52]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4559,6 +5014,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([80, 69, 81]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4569,6 +5025,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([93, 64, 47]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4579,6 +5036,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4589,6 +5047,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([163, 119, 84]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4599,6 +5058,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([160, 115, 80]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4609,6 +5069,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([160, 115, 80]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4619,6 +5080,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: Some("jungle"),
 		},
@@ -4629,6 +5091,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque|Foliage}),
 				color: Color([156, 154, 143]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4639,6 +5102,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([149, 109, 70]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4649,6 +5113,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([160, 115, 80]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4659,6 +5124,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([160, 115, 80]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4669,6 +5135,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([47, 81, 16]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4679,6 +5146,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: Some("jungle"),
 		},
@@ -4689,6 +5157,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([160, 115, 80]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4699,6 +5168,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([160, 115, 80]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4709,6 +5179,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([152, 110, 77]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4719,6 +5190,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{WallSign}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: Some("jungle"),
 		},
@@ -4729,6 +5201,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{WallSign}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: Some("jungle"),
 		},
@@ -4739,6 +5212,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([85, 67, 25]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4749,6 +5223,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4759,6 +5234,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([86, 130, 42]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4769,6 +5245,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4779,6 +5256,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([106, 91, 83]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4789,6 +5267,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([30, 67, 140]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4799,6 +5278,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([107, 117, 141]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4809,6 +5289,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4819,6 +5300,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque|Grass}),
 				color: Color([125, 125, 125]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4829,6 +5311,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([212, 90, 18]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4839,6 +5322,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([73, 72, 74]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4849,6 +5333,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([173, 137, 83]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4859,6 +5344,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4869,6 +5355,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4879,6 +5366,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4889,6 +5377,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4899,6 +5388,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4909,6 +5399,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([248, 222, 214]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4919,6 +5410,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([58, 175, 217]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4929,6 +5421,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([35, 137, 198]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4939,6 +5432,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([74, 180, 213]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4949,6 +5443,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([94, 164, 208]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4959,6 +5454,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([49, 163, 212]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4967,8 +5463,9 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 		"light_blue_stained_glass",
 		ConstBlockType {
 			block_color: BlockColor {
-				flags: make_bitflags!(BlockFlag::{Opaque}),
+				flags: make_bitflags!(BlockFlag::{Opaque|Translucent}),
 				color: Color([102, 153, 216]),
+				alpha: 128,
 			},
 			sign_material: None,
 		},
@@ -4977,8 +5474,9 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 		"light_blue_stained_glass_pane",
 		ConstBlockType {
 			block_color: BlockColor {
-				flags: make_bitflags!(BlockFlag::{Opaque}),
+				flags: make_bitflags!(BlockFlag::{Opaque|Translucent}),
 				color: Color([97, 147, 208]),
+				alpha: 128,
 			},
 			sign_material: None,
 		},
@@ -4989,6 +5487,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([113, 108, 137]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -4999,6 +5498,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5009,6 +5509,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([58, 175, 217]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5019,6 +5520,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5029,6 +5531,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5039,6 +5542,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5049,6 +5553,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([248, 222, 214]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5059,6 +5564,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([142, 142, 134]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5069,6 +5575,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([125, 125, 115]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5079,6 +5586,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([154, 154, 148]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5089,6 +5597,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([144, 166, 167]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5099,6 +5608,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([124, 124, 115]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5107,8 +5617,9 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 		"light_gray_stained_glass",
 		ConstBlockType {
 			block_color: BlockColor {
-				flags: make_bitflags!(BlockFlag::{Opaque}),
+				flags: make_bitflags!(BlockFlag::{Opaque|Translucent}),
 				color: Color([153, 153, 153]),
+				alpha: 128,
 			},
 			sign_material: None,
 		},
@@ -5117,8 +5628,9 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 		"light_gray_stained_glass_pane",
 		ConstBlockType {
 			block_color: BlockColor {
-				flags: make_bitflags!(BlockFlag::{Opaque}),
+				flags: make_bitflags!(BlockFlag::{Opaque|Translucent}),
 				color: Color([147, 147, 147]),
+				alpha: 128,
 			},
 			sign_material: None,
 		},
@@ -5129,6 +5641,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([135, 106, 97]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5139,6 +5652,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5149,6 +5663,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([142, 142, 134]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5159,6 +5674,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([246, 208, 61]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5169,6 +5685,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5179,6 +5696,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([154, 125, 147]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5189,6 +5707,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5199,6 +5718,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque|Grass}),
 				color: Color([133, 133, 133]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5209,6 +5729,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5219,6 +5740,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5229,6 +5751,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5239,6 +5762,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([248, 222, 214]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5249,6 +5773,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([112, 185, 25]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5259,6 +5784,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([94, 168, 24]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5269,6 +5795,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([125, 189, 41]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5279,6 +5806,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([162, 197, 55]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5289,6 +5817,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([99, 172, 23]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5297,8 +5826,9 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 		"lime_stained_glass",
 		ConstBlockType {
 			block_color: BlockColor {
-				flags: make_bitflags!(BlockFlag::{Opaque}),
+				flags: make_bitflags!(BlockFlag::{Opaque|Translucent}),
 				color: Color([127, 204, 25]),
+				alpha: 128,
 			},
 			sign_material: None,
 		},
@@ -5307,8 +5837,9 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 		"lime_stained_glass_pane",
 		ConstBlockType {
 			block_color: BlockColor {
-				flags: make_bitflags!(BlockFlag::{Opaque}),
+				flags: make_bitflags!(BlockFlag::{Opaque|Translucent}),
 				color: Color([122, 196, 24]),
+				alpha: 128,
 			},
 			sign_material: None,
 		},
@@ -5319,6 +5850,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([103, 117, 52]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5329,6 +5861,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5339,6 +5872,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([112, 185, 25]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5349,6 +5883,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([147, 149, 152]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5359,6 +5894,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([142, 119, 91]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5369,6 +5905,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5379,6 +5916,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5389,6 +5927,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5399,6 +5938,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([248, 222, 214]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5409,6 +5949,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([189, 68, 179]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5419,6 +5960,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([169, 48, 159]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5429,6 +5971,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([192, 83, 184]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5439,6 +5982,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([208, 100, 191]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5449,6 +5993,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([173, 54, 163]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5457,8 +6002,9 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 		"magenta_stained_glass",
 		ConstBlockType {
 			block_color: BlockColor {
-				flags: make_bitflags!(BlockFlag::{Opaque}),
+				flags: make_bitflags!(BlockFlag::{Opaque|Translucent}),
 				color: Color([178, 76, 216]),
+				alpha: 128,
 			},
 			sign_material: None,
 		},
@@ -5467,8 +6013,9 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 		"magenta_stained_glass_pane",
 		ConstBlockType {
 			block_color: BlockColor {
-				flags: make_bitflags!(BlockFlag::{Opaque}),
+				flags: make_bitflags!(BlockFlag::{Opaque|Translucent}),
 				color: Color([171, 73, 208]),
+				alpha: 128,
 			},
 			sign_material: None,
 		},
@@ -5479,6 +6026,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([149, 88, 108]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5489,6 +6037,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5499,6 +6048,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([189, 68, 179]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5509,6 +6059,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([142, 63, 31]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5519,6 +6070,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5529,6 +6081,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([112, 48, 46]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5539,6 +6092,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([117, 54, 48]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5549,6 +6103,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([117, 54, 48]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5559,6 +6114,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: Some("mangrove"),
 		},
@@ -5569,6 +6125,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque|Foliage}),
 				color: Color([129, 128, 128]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5579,6 +6136,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([102, 48, 42]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5589,6 +6147,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([117, 54, 48]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5599,6 +6158,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([117, 54, 48]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5609,6 +6169,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([96, 174, 83]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5619,6 +6180,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([74, 59, 38]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5629,6 +6191,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: Some("mangrove"),
 		},
@@ -5639,6 +6202,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([117, 54, 48]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5649,6 +6213,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([117, 54, 48]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5659,6 +6224,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([110, 46, 42]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5669,6 +6235,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{WallSign}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: Some("mangrove"),
 		},
@@ -5679,6 +6246,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{WallSign}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: Some("mangrove"),
 		},
@@ -5689,6 +6257,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([83, 66, 41]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5699,6 +6268,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5709,6 +6279,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([111, 144, 30]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5719,6 +6290,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque|Grass}),
 				color: Color([153, 153, 153]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5729,6 +6301,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([89, 109, 45]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5739,6 +6312,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([89, 109, 45]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5749,6 +6323,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([110, 118, 94]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5759,6 +6334,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([110, 118, 94]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5769,6 +6345,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([110, 118, 94]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5779,6 +6356,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([110, 118, 94]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5789,6 +6367,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([115, 121, 105]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5799,6 +6378,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([115, 121, 105]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5809,6 +6389,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([115, 121, 105]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5819,6 +6400,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([115, 121, 105]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5829,6 +6411,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5839,6 +6422,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([60, 57, 60]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5849,6 +6433,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([137, 103, 79]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5859,6 +6444,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([137, 103, 79]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5869,6 +6455,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([137, 103, 79]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5879,6 +6466,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([137, 103, 79]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5889,6 +6477,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([70, 58, 45]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5899,6 +6488,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([203, 196, 185]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5909,6 +6499,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([111, 98, 101]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5919,6 +6510,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([44, 21, 26]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5929,6 +6521,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([44, 21, 26]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5939,6 +6532,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([44, 21, 26]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5949,6 +6543,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([44, 21, 26]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5959,6 +6554,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([44, 21, 26]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5969,6 +6565,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([115, 54, 42]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5977,8 +6574,9 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 		"nether_portal",
 		ConstBlockType {
 			block_color: BlockColor {
-				flags: make_bitflags!(BlockFlag::{Opaque}),
+				flags: make_bitflags!(BlockFlag::{Opaque|Translucent}),
 				color: Color([89, 11, 192]),
+				alpha: 128,
 			},
 			sign_material: None,
 		},
@@ -5989,6 +6587,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([117, 65, 62]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -5999,6 +6598,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([19, 151, 133]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6009,6 +6609,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([111, 18, 19]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6019,6 +6620,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([114, 2, 2]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6029,6 +6631,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([66, 61, 63]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6039,6 +6642,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([97, 38, 38]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6049,6 +6653,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([88, 58, 40]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6059,6 +6664,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6069,6 +6675,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([140, 110, 66]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6079,6 +6686,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([162, 130, 78]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6089,6 +6697,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([162, 130, 78]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6099,6 +6708,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: Some("oak"),
 		},
@@ -6109,6 +6719,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque|Foliage}),
 				color: Color([144, 144, 144]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6119,6 +6730,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([151, 121, 73]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6129,6 +6741,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([162, 130, 78]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6139,6 +6752,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([162, 130, 78]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6149,6 +6763,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([77, 106, 40]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6159,6 +6774,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: Some("oak"),
 		},
@@ -6169,6 +6785,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([162, 130, 78]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6179,6 +6796,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([162, 130, 78]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6189,6 +6807,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([124, 99, 56]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6199,6 +6818,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{WallSign}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: Some("oak"),
 		},
@@ -6209,6 +6829,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{WallSign}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: Some("oak"),
 		},
@@ -6219,6 +6840,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([109, 85, 50]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6229,6 +6851,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([98, 98, 98]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6239,6 +6862,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([15, 10, 24]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6249,6 +6873,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([250, 245, 206]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6259,6 +6884,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6269,6 +6895,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6279,6 +6906,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6289,6 +6917,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([248, 222, 214]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6299,6 +6928,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([240, 118, 19]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6309,6 +6939,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([224, 97, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6319,6 +6950,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([227, 131, 31]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6329,6 +6961,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([154, 147, 91]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6339,6 +6972,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([234, 106, 8]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6347,8 +6981,9 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 		"orange_stained_glass",
 		ConstBlockType {
 			block_color: BlockColor {
-				flags: make_bitflags!(BlockFlag::{Opaque}),
+				flags: make_bitflags!(BlockFlag::{Opaque|Translucent}),
 				color: Color([216, 127, 51]),
+				alpha: 128,
 			},
 			sign_material: None,
 		},
@@ -6357,8 +6992,9 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 		"orange_stained_glass_pane",
 		ConstBlockType {
 			block_color: BlockColor {
-				flags: make_bitflags!(BlockFlag::{Opaque}),
+				flags: make_bitflags!(BlockFlag::{Opaque|Translucent}),
 				color: Color([208, 122, 48]),
+				alpha: 128,
 			},
 			sign_material: None,
 		},
@@ -6369,6 +7005,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([161, 83, 37]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6379,6 +7016,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6389,6 +7027,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6399,6 +7038,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([240, 118, 19]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6409,6 +7049,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6419,6 +7060,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([83, 161, 132]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6429,6 +7071,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([82, 162, 132]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6439,6 +7082,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([70, 132, 109]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6449,6 +7093,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([82, 160, 132]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6459,6 +7104,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([82, 161, 131]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6469,6 +7115,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([83, 161, 132]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6479,6 +7126,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([79, 153, 126]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6489,6 +7137,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([79, 153, 126]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6499,6 +7148,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([79, 153, 126]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6509,6 +7159,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([141, 180, 250]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6519,6 +7170,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([142, 106, 79]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6529,6 +7181,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([245, 240, 239]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6539,6 +7192,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([129, 126, 139]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6549,6 +7203,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([162, 130, 78]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6559,6 +7214,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6569,6 +7225,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6579,6 +7236,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6589,6 +7247,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6599,6 +7258,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6609,6 +7269,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([248, 222, 214]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6619,6 +7280,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([237, 141, 172]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6629,6 +7291,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([213, 101, 142]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6639,6 +7302,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([228, 153, 181]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6649,6 +7313,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([235, 154, 181]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6659,6 +7324,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6669,6 +7335,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([230, 121, 157]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6677,8 +7344,9 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 		"pink_stained_glass",
 		ConstBlockType {
 			block_color: BlockColor {
-				flags: make_bitflags!(BlockFlag::{Opaque}),
+				flags: make_bitflags!(BlockFlag::{Opaque|Translucent}),
 				color: Color([242, 127, 165]),
+				alpha: 128,
 			},
 			sign_material: None,
 		},
@@ -6687,8 +7355,9 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 		"pink_stained_glass_pane",
 		ConstBlockType {
 			block_color: BlockColor {
-				flags: make_bitflags!(BlockFlag::{Opaque}),
+				flags: make_bitflags!(BlockFlag::{Opaque|Translucent}),
 				color: Color([233, 122, 159]),
+				alpha: 128,
 			},
 			sign_material: None,
 		},
@@ -6699,6 +7368,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([161, 78, 78]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6709,6 +7379,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6719,6 +7390,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6729,6 +7401,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([237, 141, 172]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6739,6 +7412,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([109, 104, 96]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6749,6 +7423,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([153, 127, 85]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6759,6 +7434,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([193, 165, 103]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6769,6 +7445,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([122, 144, 189]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6779,6 +7456,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6789,6 +7467,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6799,6 +7478,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([91, 63, 24]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6809,6 +7489,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([129, 102, 89]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6819,6 +7500,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([132, 134, 133]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6829,6 +7511,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([132, 134, 133]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6839,6 +7522,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([132, 134, 133]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6849,6 +7533,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([99, 98, 100]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6859,6 +7544,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([53, 48, 56]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6869,6 +7555,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([48, 42, 49]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6879,6 +7566,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([48, 42, 49]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6889,6 +7577,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([48, 42, 49]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6899,6 +7588,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([48, 42, 49]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6909,6 +7599,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6919,6 +7610,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([53, 48, 56]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6929,6 +7621,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([53, 48, 56]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6939,6 +7632,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([53, 48, 56]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6949,6 +7643,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([53, 48, 56]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6959,6 +7654,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([72, 72, 73]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6969,6 +7665,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([72, 72, 73]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6979,6 +7676,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([72, 72, 73]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6989,6 +7687,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([72, 72, 73]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -6999,6 +7698,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([192, 193, 194]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7009,6 +7709,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([192, 193, 194]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7019,6 +7720,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([192, 193, 194]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7029,6 +7731,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([154, 106, 89]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7039,6 +7742,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([154, 106, 89]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7049,6 +7753,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([154, 106, 89]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7059,6 +7764,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([97, 104, 99]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7069,6 +7775,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([97, 104, 99]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7079,6 +7786,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([97, 104, 99]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7089,6 +7797,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([97, 104, 99]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7099,6 +7808,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7109,6 +7819,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([84, 135, 47]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7119,6 +7830,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([118, 117, 23]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7129,6 +7841,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([158, 137, 183]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7139,6 +7852,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([101, 124, 47]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7149,6 +7863,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([169, 204, 127]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7159,6 +7874,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([93, 144, 19]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7169,6 +7885,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([127, 160, 79]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7179,6 +7896,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([47, 162, 168]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7189,6 +7907,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([153, 116, 92]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7199,6 +7918,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([85, 127, 43]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7209,6 +7929,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([164, 117, 143]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7219,6 +7940,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([79, 121, 146]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7229,6 +7951,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([141, 44, 29]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7239,6 +7962,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([127, 8, 41]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7249,6 +7973,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([147, 172, 43]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7259,6 +7984,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([61, 90, 30]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7269,6 +7995,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([107, 78, 40]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7279,6 +8006,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque|Grass}),
 				color: Color([124, 124, 124]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7289,6 +8017,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([112, 121, 64]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7299,6 +8028,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([47, 81, 16]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7309,6 +8039,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([123, 174, 95]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7319,6 +8050,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([96, 174, 83]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7329,6 +8061,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([77, 106, 40]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7339,6 +8072,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([93, 142, 30]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7349,6 +8083,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([179, 202, 143]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7359,6 +8094,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([99, 157, 78]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7369,6 +8105,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([128, 64, 37]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7379,6 +8116,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([216, 75, 67]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7389,6 +8127,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([89, 128, 32]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7399,6 +8138,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([44, 60, 36]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7409,6 +8149,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([100, 101, 77]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7419,6 +8160,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([74, 109, 87]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7429,6 +8171,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([20, 136, 123]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7439,6 +8182,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([93, 164, 71]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7449,6 +8193,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([41, 44, 23]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7459,6 +8204,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([248, 253, 253]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7469,6 +8215,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([73, 72, 74]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7479,6 +8226,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([137, 109, 74]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7489,6 +8237,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([99, 156, 151]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7499,6 +8248,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([99, 171, 158]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7509,6 +8259,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([99, 171, 158]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7519,6 +8270,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([99, 171, 158]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7529,6 +8281,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([99, 156, 151]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7539,6 +8292,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([99, 156, 151]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7549,6 +8303,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([99, 156, 151]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7559,6 +8314,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([198, 118, 24]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7569,6 +8325,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque|Grass}),
 				color: Color([154, 154, 154]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7579,6 +8336,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7589,6 +8347,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7599,6 +8358,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7609,6 +8369,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([248, 222, 214]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7619,6 +8380,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([121, 42, 172]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7629,6 +8391,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([100, 31, 156]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7639,6 +8402,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([131, 55, 177]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7649,6 +8413,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([109, 47, 152]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7659,6 +8424,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([103, 32, 156]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7667,8 +8433,9 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 		"purple_stained_glass",
 		ConstBlockType {
 			block_color: BlockColor {
-				flags: make_bitflags!(BlockFlag::{Opaque}),
+				flags: make_bitflags!(BlockFlag::{Opaque|Translucent}),
 				color: Color([127, 63, 178]),
+				alpha: 128,
 			},
 			sign_material: None,
 		},
@@ -7677,8 +8444,9 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 		"purple_stained_glass_pane",
 		ConstBlockType {
 			block_color: BlockColor {
-				flags: make_bitflags!(BlockFlag::{Opaque}),
+				flags: make_bitflags!(BlockFlag::{Opaque|Translucent}),
 				color: Color([122, 61, 171]),
+				alpha: 128,
 			},
 			sign_material: None,
 		},
@@ -7689,6 +8457,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([118, 70, 86]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7699,6 +8468,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7709,6 +8479,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([121, 42, 172]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7719,6 +8490,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([169, 125, 169]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7729,6 +8501,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([171, 129, 171]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7739,6 +8512,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([169, 125, 169]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7749,6 +8523,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([169, 125, 169]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7759,6 +8534,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([235, 229, 222]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7769,6 +8545,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([234, 229, 221]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7779,6 +8556,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([235, 230, 224]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7789,6 +8567,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([235, 229, 222]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7799,6 +8578,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([235, 229, 222]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7809,6 +8589,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([125, 111, 88]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7819,6 +8600,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([154, 105, 79]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7829,6 +8611,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([221, 169, 46]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7839,6 +8622,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([166, 135, 107]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7849,6 +8633,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7859,6 +8644,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7869,6 +8655,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7879,6 +8666,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([248, 222, 214]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7889,6 +8677,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([160, 39, 34]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7899,6 +8688,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([142, 32, 32]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7909,6 +8699,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([168, 54, 50]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7919,6 +8710,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([181, 59, 53]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7929,6 +8721,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7939,6 +8732,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([200, 46, 45]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7949,6 +8743,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([69, 7, 9]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7959,6 +8754,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([69, 7, 9]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7969,6 +8765,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([69, 7, 9]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7979,6 +8776,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([69, 7, 9]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7989,6 +8787,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([190, 102, 33]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -7999,6 +8798,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([181, 97, 31]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8009,6 +8809,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([181, 97, 31]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8019,6 +8820,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([181, 97, 31]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8029,6 +8831,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([181, 97, 31]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8039,6 +8842,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([140, 31, 30]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8047,8 +8851,9 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 		"red_stained_glass",
 		ConstBlockType {
 			block_color: BlockColor {
-				flags: make_bitflags!(BlockFlag::{Opaque}),
+				flags: make_bitflags!(BlockFlag::{Opaque|Translucent}),
 				color: Color([153, 51, 51]),
+				alpha: 128,
 			},
 			sign_material: None,
 		},
@@ -8057,8 +8862,9 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 		"red_stained_glass_pane",
 		ConstBlockType {
 			block_color: BlockColor {
-				flags: make_bitflags!(BlockFlag::{Opaque}),
+				flags: make_bitflags!(BlockFlag::{Opaque|Translucent}),
 				color: Color([147, 48, 48]),
+				alpha: 128,
 			},
 			sign_material: None,
 		},
@@ -8069,6 +8875,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([143, 61, 46]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8079,6 +8886,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8089,6 +8897,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8099,6 +8908,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([160, 39, 34]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8109,6 +8919,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([175, 24, 5]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8119,6 +8930,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([95, 54, 30]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8129,6 +8941,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([140, 109, 109]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8139,6 +8952,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8149,6 +8963,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8159,6 +8974,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([175, 24, 5]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8169,6 +8985,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([80, 82, 78]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8179,6 +8996,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([160, 157, 156]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8189,6 +9007,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([129, 111, 176]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8199,6 +9018,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([75, 26, 144]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8209,6 +9029,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([144, 103, 76]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8219,6 +9040,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([131, 66, 37]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8229,6 +9051,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([219, 207, 163]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8239,6 +9062,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([223, 214, 170]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8249,6 +9073,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([223, 214, 170]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8259,6 +9084,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([223, 214, 170]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8269,6 +9095,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([223, 214, 170]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8279,6 +9106,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([170, 131, 73]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8289,6 +9117,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([12, 29, 36]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8299,6 +9128,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([15, 31, 38]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8309,6 +9139,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([7, 70, 84]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8319,6 +9150,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([198, 205, 169]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8329,6 +9161,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([7, 48, 57]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8339,6 +9172,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([172, 199, 190]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8349,6 +9183,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([90, 97, 39]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8359,6 +9194,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8369,6 +9205,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8379,6 +9216,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([240, 146, 70]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8389,6 +9227,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([139, 96, 139]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8399,6 +9238,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: Some("oak"),
 		},
@@ -8409,6 +9249,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8419,6 +9260,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8427,8 +9269,9 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 		"slime_block",
 		ConstBlockType {
 			block_color: BlockColor {
-				flags: make_bitflags!(BlockFlag::{Opaque}),
+				flags: make_bitflags!(BlockFlag::{Opaque|Translucent}),
 				color: Color([111, 192, 91]),
+				alpha: 128,
 			},
 			sign_material: None,
 		},
@@ -8439,6 +9282,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8449,6 +9293,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8459,6 +9304,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([57, 58, 70]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8469,6 +9315,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([85, 83, 81]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8479,6 +9326,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([72, 72, 78]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8489,6 +9337,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([235, 229, 222]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8499,6 +9348,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([235, 229, 222]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8509,6 +9359,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([235, 229, 222]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8519,6 +9370,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([181, 97, 31]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8529,6 +9381,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([181, 97, 31]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8539,6 +9392,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([181, 97, 31]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8549,6 +9403,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([223, 214, 170]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8559,6 +9414,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([223, 214, 170]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8569,6 +9425,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([223, 214, 170]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8579,6 +9436,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([158, 158, 158]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8589,6 +9447,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([158, 158, 158]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8599,6 +9458,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([135, 105, 67]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8609,6 +9469,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([249, 254, 254]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8619,6 +9480,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([249, 254, 254]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8629,6 +9491,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([80, 204, 208]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8639,6 +9502,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([51, 192, 197]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8649,6 +9513,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([71, 99, 114]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8659,6 +9524,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([81, 62, 50]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8669,6 +9535,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([75, 57, 46]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8679,6 +9546,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8689,6 +9557,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8699,6 +9568,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([36, 46, 62]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8709,6 +9579,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([195, 192, 74]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8719,6 +9590,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([206, 96, 158]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8729,6 +9601,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8739,6 +9612,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([106, 80, 48]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8749,6 +9623,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([114, 84, 48]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8759,6 +9634,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([114, 84, 48]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8769,6 +9645,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: Some("spruce"),
 		},
@@ -8779,6 +9656,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque|Spruce}),
 				color: Color([126, 126, 126]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8789,6 +9667,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([108, 80, 46]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8799,6 +9678,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([114, 84, 48]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8809,6 +9689,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([114, 84, 48]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8819,6 +9700,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([44, 60, 36]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8829,6 +9711,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: Some("spruce"),
 		},
@@ -8839,6 +9722,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([114, 84, 48]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8849,6 +9733,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([114, 84, 48]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8859,6 +9744,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([103, 79, 47]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8869,6 +9755,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{WallSign}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: Some("spruce"),
 		},
@@ -8879,6 +9766,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{WallSign}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: Some("spruce"),
 		},
@@ -8889,6 +9777,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([58, 37, 16]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8899,6 +9788,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([109, 104, 96]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8909,6 +9799,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([125, 125, 125]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8919,6 +9810,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([122, 121, 122]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8929,6 +9821,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([122, 121, 122]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8939,6 +9832,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([122, 121, 122]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8949,6 +9843,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([122, 121, 122]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8959,6 +9854,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8969,6 +9865,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([125, 125, 125]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8979,6 +9876,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([125, 125, 125]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8989,6 +9887,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([125, 125, 125]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -8999,6 +9898,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([123, 118, 111]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9009,6 +9909,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([166, 91, 51]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9019,6 +9920,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([174, 92, 59]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9029,6 +9931,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([178, 158, 72]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9039,6 +9942,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([191, 171, 116]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9049,6 +9953,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([196, 176, 118]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9059,6 +9964,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([221, 164, 157]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9069,6 +9975,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([215, 145, 148]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9079,6 +9986,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([137, 57, 90]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9089,6 +9997,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([121, 56, 82]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9099,6 +10008,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([65, 44, 22]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9109,6 +10019,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([72, 56, 36]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9119,6 +10030,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([165, 122, 81]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9129,6 +10041,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([171, 132, 84]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9139,6 +10052,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([109, 43, 43]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9149,6 +10063,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([119, 54, 47]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9159,6 +10074,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([160, 129, 77]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9169,6 +10085,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([177, 144, 86]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9179,6 +10096,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([105, 80, 46]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9189,6 +10107,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([115, 89, 52]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9199,6 +10118,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([57, 150, 147]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9209,6 +10129,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([52, 128, 124]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9219,6 +10140,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([88, 74, 90]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9229,6 +10151,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9239,6 +10162,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([148, 192, 101]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9249,6 +10173,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([246, 196, 54]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9259,6 +10184,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([129, 125, 124]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9269,6 +10195,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([217, 204, 159]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9279,6 +10206,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([68, 77, 50]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9289,6 +10217,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque|Grass}),
 				color: Color([151, 149, 151]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9299,6 +10228,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([59, 139, 14]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9309,6 +10239,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([226, 170, 157]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9319,6 +10250,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([152, 94, 67]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9327,8 +10259,9 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 		"tinted_glass",
 		ConstBlockType {
 			block_color: BlockColor {
-				flags: make_bitflags!(BlockFlag::{Opaque}),
+				flags: make_bitflags!(BlockFlag::{Opaque|Translucent}),
 				color: Color([44, 38, 46]),
+				alpha: 128,
 			},
 			sign_material: None,
 		},
@@ -9339,6 +10272,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([142, 62, 53]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9349,6 +10283,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9359,6 +10294,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9369,6 +10305,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9379,6 +10316,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([162, 130, 78]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9389,6 +10327,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([56, 82, 98]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9399,6 +10338,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9409,6 +10349,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9419,6 +10360,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9429,6 +10371,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([49, 87, 206]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9439,6 +10382,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9449,6 +10393,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9459,6 +10404,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([108, 109, 102]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9469,6 +10415,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([98, 102, 95]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9479,6 +10426,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([98, 102, 95]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9489,6 +10437,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([98, 102, 95]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9499,6 +10448,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([98, 102, 95]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9509,6 +10459,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([108, 109, 102]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9519,6 +10470,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([108, 109, 102]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9529,6 +10481,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([108, 109, 102]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9539,6 +10492,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([228, 226, 191]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9549,6 +10503,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([20, 143, 124]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9559,6 +10514,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([20, 135, 122]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9569,6 +10525,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([54, 69, 79]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9579,6 +10536,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([229, 244, 228]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9589,6 +10547,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque|Grass}),
 				color: Color([116, 116, 116]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9599,6 +10558,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9609,6 +10569,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{WallSign}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: Some("oak"),
 		},
@@ -9619,6 +10580,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9629,6 +10591,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9639,6 +10602,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([44, 126, 120]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9649,6 +10613,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([43, 104, 99]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9659,6 +10624,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([43, 104, 99]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9669,6 +10635,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9679,6 +10646,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: Some("warped"),
 		},
@@ -9689,6 +10657,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([58, 58, 77]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9699,6 +10668,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([43, 114, 101]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9709,6 +10679,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([43, 104, 99]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9719,6 +10690,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([43, 104, 99]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9729,6 +10701,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([20, 138, 124]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9739,6 +10712,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: Some("warped"),
 		},
@@ -9749,6 +10723,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([43, 104, 99]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9759,6 +10734,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([43, 104, 99]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9769,6 +10745,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([53, 109, 110]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9779,6 +10756,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([47, 119, 111]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9789,6 +10767,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{WallSign}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: Some("warped"),
 		},
@@ -9799,6 +10778,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{WallSign}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: Some("warped"),
 		},
@@ -9809,6 +10789,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([22, 119, 121]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9817,8 +10798,9 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 		"water",
 		ConstBlockType {
 			block_color: BlockColor {
-				flags: make_bitflags!(BlockFlag::{Opaque|Water}),
+				flags: make_bitflags!(BlockFlag::{Opaque|Water|Translucent}),
 				color: Color([177, 177, 177]),
+				alpha: 128,
 			},
 			sign_material: None,
 		},
@@ -9829,6 +10811,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([73, 72, 74]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9839,6 +10822,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([184, 100, 73]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9849,6 +10833,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([192, 107, 79]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9859,6 +10844,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([156, 86, 57]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9869,6 +10855,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([192, 109, 82]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9879,6 +10866,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([191, 107, 79]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9889,6 +10877,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([190, 106, 79]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9899,6 +10888,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([191, 106, 80]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9909,6 +10899,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([191, 106, 80]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9919,6 +10910,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([191, 106, 80]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9929,6 +10921,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([154, 119, 100]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9939,6 +10932,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([161, 125, 103]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9949,6 +10943,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([135, 107, 89]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9959,6 +10954,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([164, 123, 106]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9969,6 +10965,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([161, 125, 104]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9979,6 +10976,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([161, 125, 104]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9989,6 +10987,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([154, 121, 101]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -9999,6 +10998,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([154, 121, 101]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10009,6 +11009,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([154, 121, 101]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10019,6 +11020,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([83, 161, 132]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10029,6 +11031,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([82, 162, 132]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10039,6 +11042,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([70, 132, 109]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10049,6 +11053,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([82, 160, 132]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10059,6 +11064,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([82, 161, 131]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10069,6 +11075,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([83, 161, 132]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10079,6 +11086,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([79, 153, 126]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10089,6 +11097,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([79, 153, 126]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10099,6 +11108,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([79, 153, 126]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10109,6 +11119,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([104, 150, 111]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10119,6 +11130,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([108, 153, 110]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10129,6 +11141,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([92, 126, 99]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10139,6 +11152,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([110, 150, 109]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10149,6 +11163,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([106, 152, 110]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10159,6 +11174,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([108, 153, 110]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10169,6 +11185,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([109, 145, 107]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10179,6 +11196,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([109, 145, 107]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10189,6 +11207,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([109, 145, 107]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10199,6 +11218,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([104, 150, 111]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10209,6 +11229,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([108, 153, 110]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10219,6 +11240,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([92, 126, 99]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10229,6 +11251,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([110, 150, 109]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10239,6 +11262,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([106, 152, 110]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10249,6 +11273,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([108, 153, 110]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10259,6 +11284,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([109, 145, 107]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10269,6 +11295,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([109, 145, 107]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10279,6 +11306,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([109, 145, 107]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10289,6 +11317,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([104, 1, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10299,6 +11328,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([132, 16, 12]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10309,6 +11339,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([171, 181, 70]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10319,6 +11350,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([166, 151, 73]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10329,6 +11361,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10339,6 +11372,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10349,6 +11383,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10359,6 +11394,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([248, 222, 214]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10369,6 +11405,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([233, 236, 236]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10379,6 +11416,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([207, 213, 214]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10389,6 +11427,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([225, 227, 227]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10399,6 +11438,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([188, 212, 202]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10409,6 +11449,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([215, 220, 221]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10417,8 +11458,9 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 		"white_stained_glass",
 		ConstBlockType {
 			block_color: BlockColor {
-				flags: make_bitflags!(BlockFlag::{Opaque}),
+				flags: make_bitflags!(BlockFlag::{Opaque|Translucent}),
 				color: Color([255, 255, 255]),
+				alpha: 128,
 			},
 			sign_material: None,
 		},
@@ -10427,8 +11469,9 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 		"white_stained_glass_pane",
 		ConstBlockType {
 			block_color: BlockColor {
-				flags: make_bitflags!(BlockFlag::{Opaque}),
+				flags: make_bitflags!(BlockFlag::{Opaque|Translucent}),
 				color: Color([246, 246, 246]),
+				alpha: 128,
 			},
 			sign_material: None,
 		},
@@ -10439,6 +11482,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([209, 178, 161]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10449,6 +11493,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10459,6 +11504,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10469,6 +11515,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([233, 236, 236]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10479,6 +11526,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10489,6 +11537,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10499,6 +11548,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10509,6 +11559,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10519,6 +11570,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10529,6 +11581,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10539,6 +11592,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([248, 222, 214]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10549,6 +11603,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([248, 197, 39]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10559,6 +11614,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([240, 175, 21]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10569,6 +11625,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([232, 199, 54]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10579,6 +11636,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([234, 192, 88]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10589,6 +11647,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([248, 188, 29]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10597,8 +11656,9 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 		"yellow_stained_glass",
 		ConstBlockType {
 			block_color: BlockColor {
-				flags: make_bitflags!(BlockFlag::{Opaque}),
+				flags: make_bitflags!(BlockFlag::{Opaque|Translucent}),
 				color: Color([229, 229, 51]),
+				alpha: 128,
 			},
 			sign_material: None,
 		},
@@ -10607,8 +11667,9 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 		"yellow_stained_glass_pane",
 		ConstBlockType {
 			block_color: BlockColor {
-				flags: make_bitflags!(BlockFlag::{Opaque}),
+				flags: make_bitflags!(BlockFlag::{Opaque|Translucent}),
 				color: Color([221, 221, 48]),
+				alpha: 128,
 			},
 			sign_material: None,
 		},
@@ -10619,6 +11680,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([186, 133, 35]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10629,6 +11691,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10639,6 +11702,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{Opaque}),
 				color: Color([248, 197, 39]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10649,6 +11713,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -10659,6 +11724,7 @@ pub const BLOCK_TYPES: &[(&str, ConstBlockType)] = &[
 			block_color: BlockColor {
 				flags: make_bitflags!(BlockFlag::{}),
 				color: Color([0, 0, 0]),
+				alpha: 255,
 			},
 			sign_material: None,
 		},
@@ -0,0 +1,146 @@
+//! Name-based fallbacks for unknown block types
+
+use super::map_color::DYE_COLORS;
+
+/// Keywords of block IDs and the standard block types used for them
+///
+/// Each entry consists of a keyword, the block type used by default and
+/// the block type suffix used together with a dye color prefix found in
+/// the block ID.
+const KEYWORDS: &[(&str, &str, Option<&str>)] = &[
+	("wool", "white_wool", Some("wool")),
+	("carpet", "white_carpet", Some("carpet")),
+	("concrete", "white_concrete", Some("concrete")),
+	("terracotta", "terracotta", Some("terracotta")),
+	("glass", "glass", Some("stained_glass")),
+	("planks", "oak_planks", None),
+	("log", "oak_log", None),
+	("wood", "oak_wood", None),
+	("leaves", "oak_leaves", None),
+	("sapling", "oak_sapling", None),
+	("ore", "iron_ore", None),
+	("bricks", "bricks", None),
+	("brick", "bricks", None),
+	("cobblestone", "cobblestone", None),
+	("sandstone", "sandstone", None),
+	("stone", "stone", None),
+	("sand", "sand", None),
+	("gravel", "gravel", None),
+	("dirt", "dirt", None),
+	("grass", "grass_block", None),
+	("moss", "moss_block", None),
+	("ice", "ice", None),
+	("snow", "snow_block", None),
+];
+
+/// Block types that are never used as fallbacks
+///
+/// Fluids and fire are not solid, so block types with names ending in their
+/// IDs (like `hot_water` or `blue_fire`) are unlikely to look like them.
+const EXCLUDED: &[&str] = &["bubble_column", "fire", "lava", "soul_fire", "water"];
+
+/// Determines a standard block type to use for an unknown block type
+///
+/// *known* checks whether a namespaced ID refers to a known visible block
+/// type; invisible block types like air are never used as fallbacks.
+///
+/// The block ID without its namespace is looked up in the `minecraft:`
+/// namespace first, followed by shorter and shorter suffixes of the ID
+/// (so `pale_oak_planks` falls back to `oak_planks`). If no such block type
+/// exists, the words of the ID are matched against a list of keywords,
+/// taking dye color prefixes into account. Fluids and fire are excluded.
+pub fn fallback(id: &str, known: impl Fn(&str) -> bool) -> Option<String> {
+	let name = id.split_once(':').map_or(id, |(_, name)| name);
+
+	let mut suffix = name;
+	loop {
+		let candidate = format!("minecraft:{}", suffix);
+		if !EXCLUDED.contains(&suffix) && known(&candidate) {
+			return Some(candidate);
+		}
+		let Some((_, rest)) = suffix.split_once('_') else {
+			break;
+		};
+		suffix = rest;
+	}
+
+	let words: Vec<_> = name.split('_').collect();
+	let padded = format!("_{}_", name);
+	let color = DYE_COLORS
+		.iter()
+		.filter(|color| padded.contains(&format!("_{}_", color)))
+		.max_by_key(|color| color.len());
+
+	for &(keyword, default, colored) in KEYWORDS {
+		if !words.contains(&keyword) {
+			continue;
+		}
+		if let (Some(color), Some(colored)) = (color, colored) {
+			let candidate = format!("minecraft:{}_{}", color, colored);
+			if known(&candidate) {
+				return Some(candidate);
+			}
+		}
+		let candidate = format!("minecraft:{}", default);
+		if known(&candidate) {
+			return Some(candidate);
+		}
+	}
+
+	None
+}
+
+#[cfg(test)]
+mod test {
+	use crate::BlockTypes;
+
+	#[test]
+	fn test_fallback() {
+		let block_types = BlockTypes::default();
+
+		for (id, expected) in [
+			// Suffixes of the ID
+			("minecraft:pale_oak_planks", Some("minecraft:oak_planks")),
+			("mymod:pale_oak_planks", Some("minecraft:oak_planks")),
+			("mymod:stone", Some("minecraft:stone")),
+			("mymod:light_blue_wool", Some("minecraft:light_blue_wool")),
+			(
+				"mymod:shiny_light_blue_wool",
+				Some("minecraft:light_blue_wool"),
+			),
+			// Keywords with dye color prefixes
+			("mymod:red_wool_slab", Some("minecraft:red_wool")),
+			(
+				"mymod:light_gray_concrete_stairs",
+				Some("minecraft:light_gray_concrete"),
+			),
+			(
+				"mymod:blue_glass_wall",
+				Some("minecraft:blue_stained_glass"),
+			),
+			("mymod:wool_stairs", Some("minecraft:white_wool")),
+			// Keywords without dye colors
+			("mymod:tin_ore", Some("minecraft:iron_ore")),
+			("mymod:deepslate_ruby_ore", Some("minecraft:iron_ore")),
+			("mymod:marble_bricks_wall", Some("minecraft:bricks")),
+			// Invisible, fluid and fire block types are not used
+			("mymod:hot_air", None),
+			("mymod:cave_air", None),
+			("mymod:foo_water", None),
+			("mymod:liquid_lava", None),
+			("mymod:blue_fire", None),
+			("mymod:barrier", None),
+			// No similar block type
+			("mymod:machine_frame", None),
+		] {
+			assert_eq!(
+				block_types
+					.get_fallback(id)
+					.map(|block_type| block_type.id.as_str()),
+				expected,
+				"fallback for {}",
+				id,
+			);
+		}
+	}
+}
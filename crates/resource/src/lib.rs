@@ -2,14 +2,22 @@
 #![warn(missing_docs)]
 #![warn(clippy::missing_docs_in_private_items)]
 
+mod biome_color;
 mod biomes;
 mod block_color;
+mod block_states;
+mod fallback;
 mod legacy_block_types;
+mod light_emission;
+mod map_color;
 
 #[allow(clippy::missing_docs_in_private_items)] // Generated module
 mod block_types;
 
-use std::collections::HashMap;
+use std::{
+	collections::{BTreeMap, HashMap},
+	sync::Mutex,
+};
 
 use enumflags2::{bitflags, BitFlags};
 use serde::{Deserialize, Serialize};
@@ -36,6 +44,12 @@ pub enum BlockFlag {
 	/// The WallSign flag is used to distinguish wall signs from
 	/// freestanding or -hanging signs.
 	WallSign,
+	/// The block type is translucent
+	///
+	/// Translucent blocks like water, ice or stained glass are rendered
+	/// blended with the blocks below them when enabled. The opacity is
+	/// given by the [BlockColor] alpha value.
+	Translucent,
 }
 
 /// An RGB color with u8 components
@@ -46,12 +60,14 @@ pub struct Color(pub [u8; 3]);
 pub type Colorf = glam::Vec3;
 
 /// A block type specification
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct BlockColor {
 	/// Bit set of [BlockFlag]s describing special properties of the block type
 	pub flags: BitFlags<BlockFlag>,
 	/// Base color of the block type
 	pub color: Color,
+	/// Opacity of [BlockFlag::Translucent] block types (255 for other types)
+	pub alpha: u8,
 }
 
 impl BlockColor {
@@ -74,48 +90,90 @@ struct ConstBlockType {
 /// A block type specification
 #[derive(Debug, Clone)]
 pub struct BlockType {
+	/// Namespaced ID of the block type (like `minecraft:stone`)
+	pub id: String,
 	/// Determines the rendered color of the block type
 	pub block_color: BlockColor,
 	/// Material of a sign block
 	pub sign_material: Option<String>,
+	/// Light level emitted by the block type
+	pub light_emission: u8,
+	/// Color of the block type on Minecraft map items
+	pub map_color: MapColor,
 }
 
-impl From<&ConstBlockType> for BlockType {
-	fn from(value: &ConstBlockType) -> Self {
+impl BlockType {
+	/// Constructs a [BlockType] from a namespaced block ID
+	///
+	/// The light emission and map color are derived from the block ID.
+	pub fn new(id: &str, block_color: BlockColor, sign_material: Option<String>) -> Self {
+		let suffix = id.strip_prefix("minecraft:").unwrap_or(id);
+		BlockType {
+			id: id.to_string(),
+			block_color,
+			sign_material,
+			light_emission: light_emission::light_emission(suffix),
+			map_color: map_color::map_color(suffix),
+		}
+	}
+
+	/// Constructs a [BlockType] from a constant specification and a block ID without namespace
+	fn from_const(id: &str, value: &ConstBlockType) -> Self {
 		BlockType {
+			id: format!("minecraft:{}", id),
 			block_color: value.block_color,
 			sign_material: value.sign_material.map(String::from),
+			light_emission: light_emission::light_emission(id),
+			map_color: map_color::map_color(id),
 		}
 	}
 }
 
 /// Used to look up standard Minecraft block types
+///
+/// Additional block types (including block types of other namespaces) can be
+/// added at runtime.
 #[derive(Debug)]
 pub struct BlockTypes {
-	/// Map of string IDs to block types
+	/// Map of namespaced string IDs to block types
 	block_type_map: HashMap<String, BlockType>,
+	/// Map of namespaced string IDs to block state-dependent variants
+	state_variants: HashMap<String, Vec<block_states::StateVariant>>,
 	/// Array used to look up old numeric block type and subtype values
 	legacy_block_types: Box<[[BlockType; 16]; 256]>,
+	/// Fallback block type IDs determined for unknown block types
+	fallbacks: Mutex<BTreeMap<String, Option<String>>>,
 }
 
 impl Default for BlockTypes {
 	fn default() -> Self {
 		let block_type_map: HashMap<_, _> = block_types::BLOCK_TYPES
 			.iter()
-			.map(|(k, v)| (String::from(*k), BlockType::from(v)))
+			.map(|(k, v)| {
+				let block_type = BlockType::from_const(k, v);
+				(block_type.id.clone(), block_type)
+			})
 			.collect();
 		let legacy_block_types = Box::new(legacy_block_types::LEGACY_BLOCK_TYPES.map(|inner| {
 			inner.map(|id| {
 				block_type_map
-					.get(id)
+					.get(&format!("minecraft:{}", id))
 					.expect("Unknown legacy block type")
 					.clone()
 			})
 		}));
 
+		let state_variants = block_type_map
+			.values()
+			.map(|block_type| (block_type.id.clone(), block_states::variants(block_type)))
+			.filter(|(_, variants)| !variants.is_empty())
+			.collect();
+
 		BlockTypes {
 			block_type_map,
+			state_variants,
 			legacy_block_types,
+			fallbacks: Default::default(),
 		}
 	}
 }
@@ -124,8 +182,72 @@ impl BlockTypes {
 	/// Resolves a Minecraft 1.13+ string block type ID
 	#[inline]
 	pub fn get(&self, id: &str) -> Option<&BlockType> {
-		let suffix = id.strip_prefix("minecraft:")?;
-		self.block_type_map.get(suffix)
+		self.block_type_map.get(id)
+	}
+
+	/// Resolves a Minecraft 1.13+ string block type ID and block state properties
+	///
+	/// Some block types are rendered differently depending on their block
+	/// state, like growing crops, redstone wire or lit furnaces.
+	pub fn get_state(&self, id: &str, properties: &BTreeMap<String, String>) -> Option<&BlockType> {
+		let variant = self
+			.state_variants
+			.get(id)
+			.and_then(|variants| variants.iter().find(|variant| variant.matches(properties)));
+		match variant {
+			Some(variant) => Some(&variant.block_type),
+			None => self.get(id),
+		}
+	}
+
+	/// Resolves an unknown block type ID to a standard block type with a
+	/// similar name
+	///
+	/// Used to render block types unknown to MinedMap (for example from
+	/// newer Minecraft versions or mods) with plausible colors. The
+	/// fallbacks used so far can be retrieved using [BlockTypes::fallbacks].
+	pub fn get_fallback(&self, id: &str) -> Option<&BlockType> {
+		let mut fallbacks = self.fallbacks.lock().unwrap();
+		let fallback = fallbacks.entry(id.to_string()).or_insert_with(|| {
+			fallback::fallback(id, |candidate| {
+				self.block_type_map
+					.get(candidate)
+					.is_some_and(|block_type| block_type.block_color.is(BlockFlag::Opaque))
+			})
+		});
+		self.block_type_map.get(fallback.as_deref()?)
+	}
+
+	/// Returns the unknown block type IDs that were resolved using
+	/// [BlockTypes::get_fallback], with the IDs of the fallback block types
+	pub fn fallbacks(&self) -> BTreeMap<String, String> {
+		self.fallbacks
+			.lock()
+			.unwrap()
+			.iter()
+			.filter_map(|(id, fallback)| Some((id.clone(), fallback.clone()?)))
+			.collect()
+	}
+
+	/// Adds a block type, replacing any existing block type with the same ID
+	///
+	/// Replaced block types are also replaced in the lookup table for old
+	/// numeric block types. Block state-dependent variants are derived from
+	/// the new block type.
+	pub fn insert(&mut self, block_type: BlockType) {
+		for legacy in self.legacy_block_types.iter_mut().flatten() {
+			if legacy.id == block_type.id {
+				*legacy = block_type.clone();
+			}
+		}
+		let variants = block_states::variants(&block_type);
+		if variants.is_empty() {
+			self.state_variants.remove(&block_type.id);
+		} else {
+			self.state_variants.insert(block_type.id.clone(), variants);
+		}
+		self.block_type_map
+			.insert(block_type.id.clone(), block_type);
 	}
 
 	/// Resolves a Minecraft pre-1.13 numeric block type ID
@@ -133,39 +255,63 @@ impl BlockTypes {
 	pub fn get_legacy(&self, id: u8, data: u8) -> Option<&BlockType> {
 		Some(&self.legacy_block_types[id as usize][data as usize])
 	}
+
+	/// Iterates over all known block types
+	pub fn iter(&self) -> impl Iterator<Item = &BlockType> {
+		self.block_type_map.values()
+	}
 }
 
+pub use biome_color::biome_color;
 pub use biomes::{Biome, BiomeGrassColorModifier};
-pub use block_color::{block_color, needs_biome};
+pub use block_color::{block_color, needs_biome, ColorStyle, HeightCurve, HeightRamp};
+pub use map_color::{MapBrightness, MapColor};
+
+/// A biome type specification
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct BiomeType {
+	/// Namespaced ID of the biome type (like `minecraft:plains`)
+	pub id: String,
+	/// Biome parameters used for block color computation
+	pub biome: Biome,
+}
 
 /// Used to look up standard Minecraft biome types
+///
+/// Additional biome types (including biome types of other namespaces) can be
+/// added at runtime.
 #[derive(Debug)]
 pub struct BiomeTypes {
-	/// Map of string IDs to biome types
-	biome_map: HashMap<String, &'static Biome>,
+	/// Map of namespaced string IDs to biome types
+	biome_map: HashMap<String, BiomeType>,
 	/// Array used to look up old numeric biome IDs
-	legacy_biomes: Box<[&'static Biome; 256]>,
+	legacy_biomes: Box<[BiomeType; 256]>,
 }
 
 impl Default for BiomeTypes {
 	fn default() -> Self {
 		let mut biome_map: HashMap<_, _> = biomes::BIOMES
 			.iter()
-			.map(|(k, v)| (String::from(*k), v))
+			.map(|(k, v)| {
+				let id = format!("minecraft:{}", k);
+				(id.clone(), BiomeType { id, biome: *v })
+			})
 			.collect();
 
 		for &(old, new) in biomes::BIOME_ALIASES.iter().rev() {
 			let biome = biome_map
-				.get(new)
-				.copied()
+				.get(&format!("minecraft:{}", new))
+				.cloned()
 				.expect("Biome alias for unknown biome");
-			assert!(biome_map.insert(String::from(old), biome).is_none());
+			assert!(biome_map
+				.insert(format!("minecraft:{}", old), biome)
+				.is_none());
 		}
 
 		let legacy_biomes = (0..=255)
 			.map(|index| {
-				let id = biomes::legacy_biome(index);
-				*biome_map.get(id).expect("Unknown legacy biome")
+				let id = format!("minecraft:{}", biomes::legacy_biome(index));
+				biome_map.get(&id).expect("Unknown legacy biome").clone()
 			})
 			.collect::<Box<[_]>>()
 			.try_into()
@@ -181,14 +327,156 @@ impl Default for BiomeTypes {
 impl BiomeTypes {
 	/// Resolves a Minecraft 1.18+ string biome type ID
 	#[inline]
-	pub fn get(&self, id: &str) -> Option<&Biome> {
-		let suffix = id.strip_prefix("minecraft:")?;
-		self.biome_map.get(suffix).copied()
+	pub fn get(&self, id: &str) -> Option<&BiomeType> {
+		self.biome_map.get(id)
+	}
+
+	/// Adds a biome type, replacing any existing biome type with the same ID
+	///
+	/// Replaced biome types are also replaced in the lookup table for old
+	/// numeric biome IDs.
+	pub fn insert(&mut self, biome_type: BiomeType) {
+		for legacy in self.legacy_biomes.iter_mut() {
+			if legacy.id == biome_type.id {
+				*legacy = biome_type.clone();
+			}
+		}
+		self.biome_map.insert(biome_type.id.clone(), biome_type);
 	}
 
 	/// Resolves a Minecraft pre-1.18 numeric biome type ID
 	#[inline]
-	pub fn get_legacy(&self, id: u8) -> Option<&Biome> {
-		Some(self.legacy_biomes[id as usize])
+	pub fn get_legacy(&self, id: u8) -> Option<&BiomeType> {
+		Some(&self.legacy_biomes[id as usize])
+	}
+
+	/// Iterates over all known biome types, excluding aliases
+	pub fn iter(&self) -> impl Iterator<Item = &BiomeType> {
+		self.biome_map
+			.iter()
+			.filter(|(k, v)| v.id == **k)
+			.map(|(_, v)| v)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	/// Resolves a block type with the given block state properties
+	fn get_state<'a>(
+		block_types: &'a BlockTypes,
+		id: &str,
+		properties: &[(&str, &str)],
+	) -> &'a BlockType {
+		let properties = properties
+			.iter()
+			.map(|&(key, value)| (key.to_string(), value.to_string()))
+			.collect();
+		block_types.get_state(id, &properties).unwrap()
+	}
+
+	#[test]
+	fn test_crop_age() {
+		let block_types = BlockTypes::default();
+		let wheat = block_types.get("minecraft:wheat").unwrap();
+
+		let sprout = get_state(&block_types, "minecraft:wheat", &[("age", "0")]);
+		assert_eq!(sprout.block_color.color, Color([60, 130, 35]));
+		let growing = get_state(&block_types, "minecraft:wheat", &[("age", "4")]);
+		assert_ne!(growing.block_color.color, sprout.block_color.color);
+		assert_ne!(growing.block_color.color, wheat.block_color.color);
+		let grown = get_state(&block_types, "minecraft:wheat", &[("age", "7")]);
+		assert_eq!(grown.block_color.color, wheat.block_color.color);
+	}
+
+	#[test]
+	fn test_redstone_power() {
+		let block_types = BlockTypes::default();
+		let red = |power: &str| {
+			get_state(&block_types, "minecraft:redstone_wire", &[("power", power)])
+				.block_color
+				.color
+				.0[0]
+		};
+
+		assert!(red("0") < red("1"));
+		assert!(red("1") < red("8"));
+		assert!(red("8") < red("15"));
+		assert_eq!(
+			red("15"),
+			block_types
+				.get("minecraft:redstone_wire")
+				.unwrap()
+				.block_color
+				.color
+				.0[0]
+		);
+	}
+
+	#[test]
+	fn test_candles() {
+		let block_types = BlockTypes::default();
+		let light = |id: &str, properties: &[(&str, &str)]| {
+			get_state(&block_types, id, properties).light_emission
+		};
+
+		assert_eq!(
+			light("minecraft:candle", &[("candles", "1"), ("lit", "true")]),
+			3
+		);
+		assert_eq!(
+			light(
+				"minecraft:white_candle",
+				&[("candles", "4"), ("lit", "true")]
+			),
+			12
+		);
+		assert_eq!(
+			light(
+				"minecraft:white_candle",
+				&[("candles", "4"), ("lit", "false")]
+			),
+			0
+		);
+		assert_eq!(light("minecraft:candle_cake", &[("lit", "true")]), 3);
+		assert_eq!(light("minecraft:candle_cake", &[("lit", "false")]), 0);
+	}
+
+	#[test]
+	fn test_sea_pickle() {
+		let block_types = BlockTypes::default();
+		let light = |properties: &[(&str, &str)]| {
+			get_state(&block_types, "minecraft:sea_pickle", properties).light_emission
+		};
+
+		assert_eq!(light(&[("pickles", "1"), ("waterlogged", "true")]), 6);
+		assert_eq!(light(&[("pickles", "4"), ("waterlogged", "true")]), 15);
+		assert_eq!(light(&[("pickles", "4"), ("waterlogged", "false")]), 0);
+	}
+
+	#[test]
+	fn test_unknown_properties() {
+		let block_types = BlockTypes::default();
+		let base = |id: &str| block_types.get(id).unwrap();
+
+		for (id, properties) in [
+			("minecraft:stone", &[("foo", "bar")][..]),
+			("minecraft:wheat", &[("foo", "bar")]),
+			("minecraft:wheat", &[("age", "99")]),
+			("minecraft:redstone_wire", &[]),
+			(
+				"minecraft:sea_pickle",
+				&[("pickles", "5"), ("waterlogged", "true")],
+			),
+		] {
+			let block_type = get_state(&block_types, id, properties);
+			assert_eq!(block_type.block_color.color, base(id).block_color.color);
+			assert_eq!(block_type.light_emission, base(id).light_emission);
+		}
+
+		assert!(block_types
+			.get_state("examplemod:stone", &BTreeMap::new())
+			.is_none());
 	}
 }
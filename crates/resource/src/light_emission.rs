@@ -0,0 +1,85 @@
+//! Light emission levels of block types
+
+/// Light levels emitted by standard Minecraft block types
+///
+/// Block types not listed here do not emit light. Blocks emitting light
+/// depending on their block state (like lit furnaces, redstone lamps or
+/// candles) are handled by the block state-dependent variants, with the
+/// exception of campfires, which are lit by default.
+///
+/// The list is sorted by block ID.
+const LIGHT_EMISSION: &[(&str, u8)] = &[
+	("amethyst_cluster", 5),
+	("beacon", 15),
+	("brewing_stand", 1),
+	("brown_mushroom", 1),
+	("calibrated_sculk_sensor", 1),
+	("campfire", 15),
+	("conduit", 15),
+	("crying_obsidian", 10),
+	("dragon_egg", 1),
+	("enchanting_table", 7),
+	("end_gateway", 15),
+	("end_portal", 15),
+	("end_portal_frame", 1),
+	("end_rod", 14),
+	("ender_chest", 7),
+	("fire", 15),
+	("glow_lichen", 7),
+	("glowstone", 15),
+	("jack_o_lantern", 15),
+	("lantern", 15),
+	("large_amethyst_bud", 4),
+	("lava", 15),
+	("lava_cauldron", 15),
+	("magma_block", 3),
+	("medium_amethyst_bud", 2),
+	("nether_portal", 11),
+	("ochre_froglight", 15),
+	("pearlescent_froglight", 15),
+	("redstone_torch", 7),
+	("redstone_wall_torch", 7),
+	("sculk_catalyst", 6),
+	("sculk_sensor", 1),
+	("sea_lantern", 15),
+	("shroomlight", 15),
+	("small_amethyst_bud", 1),
+	("soul_campfire", 10),
+	("soul_fire", 10),
+	("soul_lantern", 10),
+	("soul_torch", 10),
+	("soul_wall_torch", 10),
+	("torch", 14),
+	("verdant_froglight", 15),
+	("wall_torch", 14),
+];
+
+/// Returns the light level emitted by a standard Minecraft block type
+///
+/// *id* is the block ID without namespace.
+pub fn light_emission(id: &str) -> u8 {
+	LIGHT_EMISSION
+		.binary_search_by_key(&id, |&(block, _)| block)
+		.map_or(0, |index| LIGHT_EMISSION[index].1)
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_sorted() {
+		// light_emission() relies on binary search
+		for pair in LIGHT_EMISSION.windows(2) {
+			assert!(pair[0].0 < pair[1].0, "{} >= {}", pair[0].0, pair[1].0);
+		}
+	}
+
+	#[test]
+	fn test_light_emission() {
+		assert_eq!(light_emission("amethyst_cluster"), 5);
+		assert_eq!(light_emission("glowstone"), 15);
+		assert_eq!(light_emission("wall_torch"), 14);
+		assert_eq!(light_emission("stone"), 0);
+	}
+}
@@ -0,0 +1,573 @@
+//! Map colors of block types, as used by Minecraft map items
+
+use super::Color;
+
+/// Base colors of the Minecraft map color IDs
+///
+/// Map color 0 is unused; blocks with this color are not drawn on maps.
+const BASE_COLORS: [[u8; 3]; 62] = [
+	[0, 0, 0],
+	[127, 178, 56],
+	[247, 233, 163],
+	[199, 199, 199],
+	[255, 0, 0],
+	[160, 160, 255],
+	[167, 167, 167],
+	[0, 124, 0],
+	[255, 255, 255],
+	[164, 168, 184],
+	[151, 109, 77],
+	[112, 112, 112],
+	[64, 64, 255],
+	[143, 119, 72],
+	[255, 252, 245],
+	[216, 127, 51],
+	[178, 76, 216],
+	[102, 153, 216],
+	[229, 229, 51],
+	[127, 204, 25],
+	[242, 127, 165],
+	[76, 76, 76],
+	[153, 153, 153],
+	[76, 127, 153],
+	[127, 63, 178],
+	[51, 76, 178],
+	[102, 76, 51],
+	[102, 127, 51],
+	[153, 51, 51],
+	[25, 25, 25],
+	[250, 238, 77],
+	[92, 219, 213],
+	[74, 128, 255],
+	[0, 217, 58],
+	[129, 86, 49],
+	[112, 2, 0],
+	[209, 177, 161],
+	[159, 82, 36],
+	[149, 87, 108],
+	[112, 108, 138],
+	[186, 133, 36],
+	[103, 117, 53],
+	[160, 77, 78],
+	[57, 41, 35],
+	[135, 107, 98],
+	[87, 92, 92],
+	[122, 73, 88],
+	[76, 62, 92],
+	[76, 50, 35],
+	[76, 82, 42],
+	[142, 60, 46],
+	[37, 22, 16],
+	[189, 48, 49],
+	[148, 63, 97],
+	[92, 25, 29],
+	[22, 126, 134],
+	[58, 142, 140],
+	[86, 44, 62],
+	[20, 180, 133],
+	[100, 100, 100],
+	[216, 175, 147],
+	[127, 167, 150],
+];
+
+/// Shade of a map color
+///
+/// Minecraft maps draw each map color in one of four shades. Only the first
+/// three are used for blocks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MapBrightness {
+	/// Darker shade
+	Low,
+	/// Regular shade
+	Normal,
+	/// Brighter shade (the unmodified base color)
+	High,
+	/// Darkest shade
+	Lowest,
+}
+
+impl MapBrightness {
+	/// Returns the factor the base color is multiplied with (out of 255)
+	fn modifier(self) -> u16 {
+		match self {
+			MapBrightness::Low => 180,
+			MapBrightness::Normal => 220,
+			MapBrightness::High => 255,
+			MapBrightness::Lowest => 135,
+		}
+	}
+}
+
+/// A Minecraft map color ID
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct MapColor(pub u8);
+
+impl MapColor {
+	/// Map color of blocks that are not drawn on maps
+	pub const NONE: MapColor = MapColor(0);
+
+	/// Map color of water
+	pub const WATER: MapColor = MapColor(12);
+
+	/// Returns the color of a shade of the map color
+	///
+	/// [None] is returned for [MapColor::NONE] and unknown IDs.
+	pub fn color(self, brightness: MapBrightness) -> Option<Color> {
+		if self == MapColor::NONE {
+			return None;
+		}
+		let base = BASE_COLORS.get(usize::from(self.0))?;
+		let modifier = brightness.modifier();
+		Some(Color(base.map(|c| (u16::from(c) * modifier / 255) as u8)))
+	}
+}
+
+/// Named map color IDs used by the block mapping
+mod id {
+	#![allow(missing_docs, clippy::missing_docs_in_private_items)]
+
+	pub const NONE: u8 = 0;
+	pub const GRASS: u8 = 1;
+	pub const SAND: u8 = 2;
+	pub const WOOL: u8 = 3;
+	pub const FIRE: u8 = 4;
+	pub const ICE: u8 = 5;
+	pub const METAL: u8 = 6;
+	pub const PLANT: u8 = 7;
+	pub const SNOW: u8 = 8;
+	pub const CLAY: u8 = 9;
+	pub const DIRT: u8 = 10;
+	pub const STONE: u8 = 11;
+	pub const WATER: u8 = 12;
+	pub const WOOD: u8 = 13;
+	pub const QUARTZ: u8 = 14;
+	pub const COLOR_ORANGE: u8 = 15;
+	pub const COLOR_MAGENTA: u8 = 16;
+	pub const COLOR_LIGHT_BLUE: u8 = 17;
+	pub const COLOR_YELLOW: u8 = 18;
+	pub const COLOR_LIGHT_GREEN: u8 = 19;
+	pub const COLOR_PINK: u8 = 20;
+	pub const COLOR_GRAY: u8 = 21;
+	pub const COLOR_LIGHT_GRAY: u8 = 22;
+	pub const COLOR_CYAN: u8 = 23;
+	pub const COLOR_PURPLE: u8 = 24;
+	pub const COLOR_BLUE: u8 = 25;
+	pub const COLOR_BROWN: u8 = 26;
+	pub const COLOR_GREEN: u8 = 27;
+	pub const COLOR_RED: u8 = 28;
+	pub const COLOR_BLACK: u8 = 29;
+	pub const GOLD: u8 = 30;
+	pub const DIAMOND: u8 = 31;
+	pub const LAPIS: u8 = 32;
+	pub const EMERALD: u8 = 33;
+	pub const PODZOL: u8 = 34;
+	pub const NETHER: u8 = 35;
+	pub const TERRACOTTA_WHITE: u8 = 36;
+	pub const TERRACOTTA_GRAY: u8 = 43;
+	pub const TERRACOTTA_LIGHT_GRAY: u8 = 44;
+	pub const TERRACOTTA_CYAN: u8 = 45;
+	pub const TERRACOTTA_BROWN: u8 = 48;
+	pub const TERRACOTTA_RED: u8 = 50;
+	pub const CRIMSON_NYLIUM: u8 = 52;
+	pub const CRIMSON_STEM: u8 = 53;
+	pub const CRIMSON_HYPHAE: u8 = 54;
+	pub const WARPED_NYLIUM: u8 = 55;
+	pub const WARPED_STEM: u8 = 56;
+	pub const WARPED_HYPHAE: u8 = 57;
+	pub const WARPED_WART_BLOCK: u8 = 58;
+	pub const DEEPSLATE: u8 = 59;
+	pub const RAW_IRON: u8 = 60;
+	pub const GLOW_LICHEN: u8 = 61;
+}
+
+use id::*;
+
+/// Dye colors in the order of their map color IDs
+///
+/// The dye map colors start at [COLOR_ORANGE] (with white using [SNOW]), and
+/// the terracotta map colors start at [TERRACOTTA_WHITE] in the same order.
+pub const DYE_COLORS: &[&str] = &[
+	"white",
+	"orange",
+	"magenta",
+	"light_blue",
+	"yellow",
+	"lime",
+	"pink",
+	"gray",
+	"light_gray",
+	"cyan",
+	"purple",
+	"blue",
+	"brown",
+	"green",
+	"red",
+	"black",
+];
+
+/// Wood types with the map colors of their planks and their bark
+const WOOD_TYPES: &[(&str, u8, u8)] = &[
+	("oak", WOOD, PODZOL),
+	("spruce", PODZOL, COLOR_BROWN),
+	("birch", SAND, QUARTZ),
+	("jungle", DIRT, PODZOL),
+	("acacia", COLOR_ORANGE, STONE),
+	("dark_oak", COLOR_BROWN, COLOR_BROWN),
+	("mangrove", COLOR_RED, PODZOL),
+	("cherry", TERRACOTTA_WHITE, TERRACOTTA_GRAY),
+	("bamboo", COLOR_YELLOW, COLOR_YELLOW),
+	("crimson", CRIMSON_STEM, CRIMSON_HYPHAE),
+	("warped", WARPED_STEM, WARPED_HYPHAE),
+];
+
+/// Map colors of block types that are not covered by the rules of
+/// [map_color_id]
+///
+/// The list is sorted by block ID.
+const MAP_COLORS: &[(&str, u8)] = &[
+	("amethyst_block", COLOR_PURPLE),
+	("amethyst_cluster", COLOR_PURPLE),
+	("ancient_debris", COLOR_BLACK),
+	("anvil", METAL),
+	("azalea", PLANT),
+	("bamboo", PLANT),
+	("bamboo_mosaic", COLOR_YELLOW),
+	("bamboo_mosaic_slab", COLOR_YELLOW),
+	("bamboo_mosaic_stairs", COLOR_YELLOW),
+	("beacon", DIAMOND),
+	("bedrock", STONE),
+	("bee_nest", COLOR_YELLOW),
+	("beehive", WOOD),
+	("bell", GOLD),
+	("big_dripleaf", PLANT),
+	("blue_ice", ICE),
+	("bone_block", SAND),
+	("brain_coral_block", COLOR_PINK),
+	("brewing_stand", METAL),
+	("brown_mushroom_block", DIRT),
+	("bubble_column", WATER),
+	("bubble_coral_block", COLOR_PURPLE),
+	("budding_amethyst", COLOR_PURPLE),
+	("cactus", PLANT),
+	("calcite", TERRACOTTA_WHITE),
+	("calibrated_sculk_sensor", COLOR_CYAN),
+	("campfire", PODZOL),
+	("carved_pumpkin", COLOR_ORANGE),
+	("cauldron", STONE),
+	("chain_command_block", COLOR_GREEN),
+	("chipped_anvil", METAL),
+	("chorus_flower", COLOR_PURPLE),
+	("chorus_plant", COLOR_PURPLE),
+	("clay", CLAY),
+	("coal_block", COLOR_BLACK),
+	("coarse_dirt", DIRT),
+	("cobweb", WOOL),
+	("cocoa", PLANT),
+	("command_block", COLOR_BROWN),
+	("conduit", DIAMOND),
+	("crafter", STONE),
+	("crimson_nylium", CRIMSON_NYLIUM),
+	("crimson_roots", NETHER),
+	("crying_obsidian", COLOR_BLACK),
+	("damaged_anvil", METAL),
+	("dead_bush", WOOD),
+	("decorated_pot", TERRACOTTA_RED),
+	("diamond_block", DIAMOND),
+	("dirt", DIRT),
+	("dirt_path", DIRT),
+	("dragon_egg", COLOR_BLACK),
+	("dried_kelp_block", COLOR_GREEN),
+	("dripstone_block", TERRACOTTA_BROWN),
+	("emerald_block", EMERALD),
+	("enchanting_table", COLOR_RED),
+	("end_gateway", COLOR_BLACK),
+	("end_portal", COLOR_BLACK),
+	("end_portal_frame", COLOR_GREEN),
+	("ender_chest", STONE),
+	("farmland", DIRT),
+	("fire", FIRE),
+	("fire_coral_block", COLOR_RED),
+	("frogspawn", WATER),
+	("frosted_ice", ICE),
+	("glowstone", SAND),
+	("gold_block", GOLD),
+	("grass_block", GRASS),
+	("grass_path", DIRT),
+	("gravel", STONE),
+	("hanging_roots", DIRT),
+	("hay_block", COLOR_YELLOW),
+	("heavy_core", METAL),
+	("heavy_weighted_pressure_plate", METAL),
+	("honey_block", COLOR_ORANGE),
+	("honeycomb_block", COLOR_ORANGE),
+	("hopper", STONE),
+	("horn_coral_block", COLOR_YELLOW),
+	("ice", ICE),
+	("iron_bars", NONE),
+	("iron_block", METAL),
+	("iron_door", METAL),
+	("iron_trapdoor", METAL),
+	("jack_o_lantern", COLOR_ORANGE),
+	("jigsaw", COLOR_LIGHT_GRAY),
+	("kelp_plant", WATER),
+	("lantern", METAL),
+	("lapis_block", LAPIS),
+	("lava", FIRE),
+	("lava_cauldron", STONE),
+	("lectern", WOOD),
+	("light_weighted_pressure_plate", GOLD),
+	("lily_pad", PLANT),
+	("lodestone", METAL),
+	("magma_block", NETHER),
+	("mangrove_roots", PODZOL),
+	("melon", COLOR_LIGHT_GREEN),
+	("moss_block", COLOR_GREEN),
+	("moss_carpet", COLOR_GREEN),
+	("mud", TERRACOTTA_CYAN),
+	("muddy_mangrove_roots", PODZOL),
+	("mushroom_stem", WOOL),
+	("mycelium", COLOR_PURPLE),
+	("nether_portal", NONE),
+	("nether_sprouts", COLOR_CYAN),
+	("nether_wart", COLOR_RED),
+	("nether_wart_block", COLOR_RED),
+	("netherite_block", COLOR_BLACK),
+	("netherrack", NETHER),
+	("obsidian", COLOR_BLACK),
+	("ochre_froglight", SAND),
+	("packed_ice", ICE),
+	("packed_mud", DIRT),
+	("pearlescent_froglight", COLOR_PINK),
+	("petrified_oak_slab", WOOD),
+	("piston", STONE),
+	("piston_head", STONE),
+	("pitcher_crop", PLANT),
+	("pitcher_plant", PLANT),
+	("podzol", PODZOL),
+	("pointed_dripstone", TERRACOTTA_BROWN),
+	("powder_snow", SNOW),
+	("powder_snow_cauldron", STONE),
+	("pumpkin", COLOR_ORANGE),
+	("raw_copper_block", COLOR_ORANGE),
+	("raw_gold_block", GOLD),
+	("raw_iron_block", RAW_IRON),
+	("red_mushroom_block", COLOR_RED),
+	("red_sand", COLOR_ORANGE),
+	("redstone_block", FIRE),
+	("redstone_lamp", NONE),
+	("redstone_wire", NONE),
+	("reinforced_deepslate", DEEPSLATE),
+	("repeating_command_block", COLOR_PURPLE),
+	("respawn_anchor", COLOR_BLACK),
+	("rooted_dirt", DIRT),
+	("sand", SAND),
+	("scaffolding", SAND),
+	("sculk", COLOR_BLACK),
+	("sculk_catalyst", COLOR_BLACK),
+	("sculk_sensor", COLOR_CYAN),
+	("sculk_shrieker", COLOR_BLACK),
+	("sculk_vein", COLOR_BLACK),
+	("sea_lantern", QUARTZ),
+	("sea_pickle", COLOR_GREEN),
+	("shroomlight", COLOR_RED),
+	("shulker_box", COLOR_PURPLE),
+	("slime_block", GRASS),
+	("sniffer_egg", COLOR_RED),
+	("snow", SNOW),
+	("snow_block", SNOW),
+	("soul_campfire", PODZOL),
+	("soul_fire", COLOR_LIGHT_BLUE),
+	("soul_lantern", METAL),
+	("soul_sand", COLOR_BROWN),
+	("soul_soil", COLOR_BROWN),
+	("spawner", STONE),
+	("sponge", COLOR_YELLOW),
+	("spore_blossom", PLANT),
+	("structure_block", COLOR_LIGHT_GRAY),
+	("sugar_cane", PLANT),
+	("suspicious_gravel", STONE),
+	("suspicious_sand", SAND),
+	("sweet_berry_bush", PLANT),
+	("target", QUARTZ),
+	("terracotta", COLOR_ORANGE),
+	("tinted_glass", COLOR_GRAY),
+	("tnt", FIRE),
+	("trial_spawner", STONE),
+	("tube_coral_block", COLOR_BLUE),
+	("turtle_egg", SAND),
+	("vault", STONE),
+	("verdant_froglight", GLOW_LICHEN),
+	("warped_nylium", WARPED_NYLIUM),
+	("warped_roots", COLOR_CYAN),
+	("warped_wart_block", WARPED_WART_BLOCK),
+	("water", WATER),
+	("water_cauldron", STONE),
+	("wet_sponge", COLOR_YELLOW),
+];
+
+/// Block families that use the dye map colors
+///
+/// The block IDs of these families consist of the dye color and the family
+/// name, like `red_wool`.
+const DYED_BLOCKS: &[&str] = &[
+	"banner",
+	"bed",
+	"candle",
+	"carpet",
+	"concrete",
+	"concrete_powder",
+	"glazed_terracotta",
+	"shulker_box",
+	"stained_glass",
+	"stained_glass_pane",
+	"wool",
+];
+
+/// Block ID keywords of stone-like block families with their map colors
+///
+/// The first matching keyword determines the map color, so more specific
+/// keywords must come first.
+const MATERIALS: &[(&str, u8)] = &[
+	("deepslate", DEEPSLATE),
+	("blackstone", COLOR_BLACK),
+	("basalt", COLOR_BLACK),
+	("tuff", TERRACOTTA_GRAY),
+	("end_stone", SAND),
+	("red_sandstone", COLOR_ORANGE),
+	("sandstone", SAND),
+	("nether_brick", NETHER),
+	("purpur", COLOR_MAGENTA),
+	("dark_prismarine", DIAMOND),
+	("prismarine_brick", DIAMOND),
+	("prismarine", COLOR_CYAN),
+	("quartz", QUARTZ),
+	("granite", DIRT),
+	("diorite", QUARTZ),
+	("andesite", STONE),
+	("mud_brick", TERRACOTTA_LIGHT_GRAY),
+	("stone", STONE),
+	("brick", COLOR_RED),
+];
+
+/// Returns the map color ID of a block by applying the rules for the
+/// different block families
+fn map_color_id(id: &str) -> u8 {
+	if let Ok(index) = MAP_COLORS.binary_search_by_key(&id, |&(block, _)| block) {
+		return MAP_COLORS[index].1;
+	}
+
+	if id.starts_with("potted_") || id == "flower_pot" || id.ends_with("rail") {
+		return NONE;
+	}
+	if id.starts_with("infested_") {
+		return CLAY;
+	}
+	if id.starts_with("dead_") && id.ends_with("_coral_block") {
+		return COLOR_GRAY;
+	}
+
+	let id = id.strip_prefix("waxed_").unwrap_or(id);
+	if let Some(rest) = id.strip_suffix("_ore") {
+		return if rest.starts_with("deepslate_") {
+			DEEPSLATE
+		} else if rest.starts_with("nether_") {
+			NETHER
+		} else {
+			STONE
+		};
+	}
+	if id.contains("copper") {
+		return if id.contains("oxidized") {
+			WARPED_NYLIUM
+		} else if id.contains("weathered") {
+			WARPED_STEM
+		} else if id.contains("exposed") {
+			TERRACOTTA_LIGHT_GRAY
+		} else {
+			COLOR_ORANGE
+		};
+	}
+
+	for (index, color) in DYE_COLORS.iter().enumerate() {
+		let Some(rest) = id
+			.strip_prefix(color)
+			.and_then(|rest| rest.strip_prefix('_'))
+		else {
+			continue;
+		};
+		let index = index as u8;
+		if rest == "terracotta" {
+			return TERRACOTTA_WHITE + index;
+		}
+		if DYED_BLOCKS.contains(&rest) {
+			return if index == 0 {
+				SNOW
+			} else {
+				COLOR_ORANGE + index - 1
+			};
+		}
+	}
+
+	let stripped = id.strip_prefix("stripped_");
+	for &(wood, planks, bark) in WOOD_TYPES {
+		let Some(rest) = stripped
+			.unwrap_or(id)
+			.strip_prefix(wood)
+			.and_then(|rest| rest.strip_prefix('_'))
+		else {
+			continue;
+		};
+		return match rest {
+			"leaves" if wood == "cherry" => COLOR_PINK,
+			"leaves" | "sapling" | "propagule" => PLANT,
+			"wood" | "hyphae" if stripped.is_none() => bark,
+			_ => planks,
+		};
+	}
+	if id.ends_with("_leaves") || id.ends_with("azalea") {
+		return PLANT;
+	}
+
+	if let Some(&(_, color)) = MATERIALS.iter().find(|(material, _)| id.contains(material)) {
+		return color;
+	}
+
+	match id {
+		"barrel" | "bookshelf" | "cartography_table" | "chest" | "chiseled_bookshelf"
+		| "composter" | "crafting_table" | "daylight_detector" | "fletching_table" | "jukebox"
+		| "loom" | "note_block" | "smithing_table" | "trapped_chest" => WOOD,
+		"blast_furnace" | "dispenser" | "dropper" | "furnace" | "grindstone" | "observer"
+		| "smoker" | "sticky_piston" | "stonecutter" => STONE,
+		"attached_melon_stem"
+		| "attached_pumpkin_stem"
+		| "beetroots"
+		| "carrots"
+		| "cave_vines"
+		| "cave_vines_plant"
+		| "large_fern"
+		| "lilac"
+		| "melon_stem"
+		| "peony"
+		| "potatoes"
+		| "pumpkin_stem"
+		| "rose_bush"
+		| "sunflower"
+		| "tall_grass"
+		| "vine"
+		| "wheat" => PLANT,
+		"tall_seagrass" => WATER,
+		"twisting_vines" | "twisting_vines_plant" => COLOR_CYAN,
+		"weeping_vines" | "weeping_vines_plant" => NETHER,
+		_ => NONE,
+	}
+}
+
+/// Returns the map color of a standard Minecraft block type
+///
+/// *id* is the block ID without namespace. Only the block ID is known to
+/// MinedMap, so block types whose map color depends on the block state use
+/// the color of their default state (for example, logs are assumed to be
+/// placed upright).
+pub fn map_color(id: &str) -> MapColor {
+	MapColor(map_color_id(id))
+}
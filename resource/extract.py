@@ -22,11 +22,12 @@ def mean_color(texture):
 	r = sum([r * a for (r, g, b, a) in data])
 	g = sum([g * a for (r, g, b, a) in data])
 	b = sum([b * a for (r, g, b, a) in data])
+	visible = len([a for (r, g, b, a) in data if a != 0])
 	return {
 		'r': r / a,
 		'g': g / a,
 		'b': b / a,
-	}
+	}, a / visible
 
 
 with open(sys.argv[1]) as f:
@@ -39,7 +40,9 @@ for name, info in blocks.items():
 
 	output[id] = {
 		'color': {'r': 0, 'g': 0, 'b': 0},
+		'alpha': 255,
 		'opaque': False,
+		'translucent': False,
 		'grass': False,
 		'foliage': False,
 		'birch': False,
@@ -55,8 +58,9 @@ for name, info in blocks.items():
 	texture = info.get('texture', name)
 
 	color = None
+	alpha = 255
 	if texture:
-		color = mean_color(texture)
+		color, alpha = mean_color(texture) or (None, 255)
 	if color:
 		output[id]['color'] = color
 		output[id]['opaque'] = True
@@ -67,6 +71,9 @@ for name, info in blocks.items():
 	output[id]['spruce'] = info.get('spruce', False)
 	output[id]['water'] = info.get('water', False)
 	output[id]['wall_sign'] = info.get('wall_sign', False)
+	output[id]['translucent'] = info.get('translucent', False)
+	if output[id]['translucent']:
+		output[id]['alpha'] = alpha
 
 	output[id]['sign_material'] = info.get('sign_material')
 
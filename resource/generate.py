@@ -36,6 +36,8 @@ with open(sys.argv[2], 'w') as f:
 			flags.append('Water')
 		if info['wall_sign']:
 			flags.append('WallSign')
+		if info['translucent']:
+			flags.append('Translucent')
 		flags = 'make_bitflags!(BlockFlag::{' + '|'.join(flags) + '})'
 
 		sign_material = 'None'
@@ -43,11 +45,12 @@ with open(sys.argv[2], 'w') as f:
 			sign_material = 'Some("%s")' % info['sign_material']
 
 		print('\t("%s", ConstBlockType { ' % name, file=f)
-		print('\t\tblock_color: BlockColor { flags: %s, color: Color([%u, %u, %u]) },' % (
+		print('\t\tblock_color: BlockColor { flags: %s, color: Color([%u, %u, %u]), alpha: %u },' % (
 			flags,
 			info['color']['r'],
 			info['color']['g'],
 			info['color']['b'],
+			round(info['alpha']),
 		), file=f)
 		print('\t\tsign_material: %s,' % sign_material, file=f)
 		print('}),', file=f)
@@ -0,0 +1,442 @@
+//! Loading of block textures from resource packs
+
+use std::{
+	collections::{BTreeMap, HashMap},
+	path::PathBuf,
+	sync::{Arc, Mutex},
+};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Deserializer};
+use tracing::warn;
+
+use super::common::TEXTURE_LEVELS;
+use crate::io::resource_pack::ResourcePack;
+
+/// Texture variables of block models to consider for the top face of a
+/// block, in order of preference
+const TOP_TEXTURE_KEYS: &[&str] = &[
+	"top", "up", "end", "all", "texture", "wall", "cross", "plant", "crop", "rail", "torch",
+	"side", "particle",
+];
+
+/// Maximum length of block model parent chains and texture variable references
+const MAX_INDIRECTIONS: usize = 16;
+
+/// Block list used to generate the built-in block types
+const BLOCKS_JSON: &str = include_str!("../../resource/blocks.json");
+
+/// Deserializes a field that may be missing or `null`, distinguishing
+/// between the two cases
+fn deserialize_some<'de, T, D>(deserializer: D) -> std::result::Result<Option<T>, D::Error>
+where
+	T: Deserialize<'de>,
+	D: Deserializer<'de>,
+{
+	T::deserialize(deserializer).map(Some)
+}
+
+/// Entry of the `resource/blocks.json` block list
+#[derive(Debug, Deserialize)]
+struct BlockInfo {
+	/// Name of the top face texture
+	///
+	/// A missing field refers to the texture named like the block type,
+	/// `null` to a block type without texture.
+	#[serde(default, deserialize_with = "deserialize_some")]
+	texture: Option<Option<String>>,
+}
+
+/// Returns the top face texture IDs of the built-in block types
+///
+/// The textures are taken from `resource/blocks.json` like
+/// `resource/extract.py` does. Block types without texture are mapped
+/// to [None].
+fn builtin_textures() -> Result<HashMap<String, Option<String>>> {
+	let blocks: BTreeMap<String, Option<BlockInfo>> =
+		serde_json::from_str(BLOCKS_JSON).context("Failed to parse built-in block list")?;
+
+	Ok(blocks
+		.into_iter()
+		.map(|(name, info)| {
+			let texture = match info {
+				Some(BlockInfo {
+					texture: Some(texture),
+				}) => texture,
+				Some(BlockInfo { texture: None }) => Some(name.clone()),
+				None => None,
+			};
+			(
+				format!("minecraft:{}", name),
+				texture.map(|texture| format!("minecraft:block/{}", texture)),
+			)
+		})
+		.collect())
+}
+
+/// Derives the namespaced ID of a block type from the path of its
+/// blockstate definition in a resource pack
+///
+/// Returns [None] for files that are not blockstate definitions.
+fn blockstate_id(path: &str) -> Option<String> {
+	let (namespace, path) = path.strip_prefix("assets/")?.split_once('/')?;
+	let name = path.strip_prefix("blockstates/")?.strip_suffix(".json")?;
+	Some(format!("{}:{}", namespace, name))
+}
+
+/// Reference to a block model in a blockstate definition
+#[derive(Debug, Deserialize)]
+struct ModelRef {
+	/// Namespaced ID of the model
+	model: String,
+}
+
+/// One or multiple alternative models in a blockstate definition
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum ModelRefs {
+	/// A single model
+	One(ModelRef),
+	/// A list of randomly chosen models
+	Many(Vec<ModelRef>),
+}
+
+impl ModelRefs {
+	/// Returns the first model
+	fn first(&self) -> Option<&ModelRef> {
+		match self {
+			ModelRefs::One(model) => Some(model),
+			ModelRefs::Many(models) => models.first(),
+		}
+	}
+}
+
+/// Case of a multipart blockstate definition
+#[derive(Debug, Deserialize)]
+struct MultipartCase {
+	/// Models applied when the case matches
+	apply: ModelRefs,
+}
+
+/// Blockstate definition file (`assets/*/blockstates/*.json`)
+#[derive(Debug, Deserialize)]
+struct BlockState {
+	/// Models of the block states
+	#[serde(default)]
+	variants: BTreeMap<String, ModelRefs>,
+	/// Model parts of multipart blocks
+	#[serde(default)]
+	multipart: Vec<MultipartCase>,
+}
+
+/// Block model file (`assets/*/models/*.json`)
+#[derive(Debug, Deserialize)]
+struct Model {
+	/// Namespaced ID of the parent model
+	parent: Option<String>,
+	/// Texture variables
+	#[serde(default)]
+	textures: HashMap<String, String>,
+}
+
+/// Constructs the path of a resource pack file from a namespaced ID
+fn resource_path(id: &str, kind: &str, ext: &str) -> String {
+	let (namespace, path) = id.split_once(':').unwrap_or(("minecraft", id));
+	format!("assets/{}/{}/{}.{}", namespace, kind, path, ext)
+}
+
+/// Top face texture of a block type
+///
+/// The texture is stored scaled to the sizes used on the different texture
+/// levels.
+#[derive(Debug)]
+pub struct BlockTexture {
+	/// Texture images for texture levels 1 to [TEXTURE_LEVELS]
+	levels: Vec<image::RgbaImage>,
+}
+
+impl BlockTexture {
+	/// Creates a [BlockTexture] from a texture image
+	///
+	/// Animated textures are stored as vertically stacked frames; only the
+	/// first frame is used.
+	fn new(image: image::DynamicImage) -> Self {
+		let image = image.into_rgba8();
+		let size = image.width().min(image.height());
+		let frame = image::imageops::crop_imm(&image, 0, 0, size, size).to_image();
+
+		let levels = (1..=TEXTURE_LEVELS)
+			.map(|level| {
+				let n = 1 << level;
+				let filter = if n < size {
+					image::imageops::FilterType::Triangle
+				} else {
+					image::imageops::FilterType::Nearest
+				};
+				image::imageops::resize(&frame, n, n, filter)
+			})
+			.collect();
+
+		BlockTexture { levels }
+	}
+
+	/// Returns the texture image for a texture level
+	///
+	/// The image of level *n* has a size of 2^*n* x 2^*n* pixels.
+	pub fn level(&self, level: usize) -> &image::RgbaImage {
+		&self.levels[level - 1]
+	}
+}
+
+/// Average color of the top face texture of a block type
+#[derive(Debug, Clone, Copy)]
+pub struct TextureColor {
+	/// Mean color of the texture, weighted by pixel opacity
+	pub color: [f32; 3],
+	/// Mean opacity of the visible pixels
+	pub alpha: f32,
+	/// The texture has partially transparent pixels
+	pub translucent: bool,
+}
+
+impl TextureColor {
+	/// Computes the average color of a texture image
+	///
+	/// Returns [None] for fully transparent textures.
+	fn new(image: &image::RgbaImage) -> Option<Self> {
+		let mut sum = [0.0; 3];
+		let mut alpha_sum = 0.0;
+		let mut visible = 0;
+		let mut translucent = false;
+
+		for &image::Rgba([r, g, b, a]) in image.pixels() {
+			if a == 0 {
+				continue;
+			}
+			let a = f32::from(a);
+			for (sum, v) in sum.iter_mut().zip([r, g, b]) {
+				*sum += a * f32::from(v);
+			}
+			alpha_sum += a;
+			visible += 1;
+			translucent |= a < 255.0;
+		}
+
+		if visible == 0 {
+			return None;
+		}
+
+		Some(TextureColor {
+			color: sum.map(|v| v / alpha_sum),
+			alpha: alpha_sum / visible as f32,
+			translucent,
+		})
+	}
+}
+
+/// Loads and caches the top face textures of block types
+pub struct BlockTextures {
+	/// Resource packs to load the textures from
+	pack: ResourcePack,
+	/// Top face texture IDs of the built-in block types
+	builtin: HashMap<String, Option<String>>,
+	/// Previously loaded textures by block ID
+	///
+	/// Block types without texture are cached as [None].
+	cache: Mutex<HashMap<String, Option<Arc<BlockTexture>>>>,
+}
+
+impl BlockTextures {
+	/// Opens the resource packs to load textures from
+	///
+	/// Returns [None] if no resource packs are passed.
+	pub fn open(paths: &[PathBuf]) -> Result<Option<Self>> {
+		if paths.is_empty() {
+			return Ok(None);
+		}
+
+		Ok(Some(BlockTextures {
+			pack: ResourcePack::open(paths)?,
+			builtin: builtin_textures()?,
+			cache: Default::default(),
+		}))
+	}
+
+	/// Loads a block model and merges the texture variables of its parents
+	fn model_textures(&self, model: &str) -> Result<HashMap<String, String>> {
+		let mut textures = HashMap::new();
+		let mut next = Some(model.to_string());
+
+		for _ in 0..MAX_INDIRECTIONS {
+			let Some(model) = next.take() else {
+				break;
+			};
+			let Some(data) = self
+				.pack
+				.read_json::<Model>(&resource_path(&model, "models", "json"))?
+			else {
+				break;
+			};
+
+			for (key, value) in data.textures {
+				textures.entry(key).or_insert(value);
+			}
+			next = data.parent;
+		}
+
+		Ok(textures)
+	}
+
+	/// Returns the IDs of the block types with blockstate definitions in the
+	/// resource packs
+	pub fn block_ids(&self) -> Result<Vec<String>> {
+		Ok(self
+			.pack
+			.list("assets/")?
+			.iter()
+			.filter_map(|path| blockstate_id(path))
+			.collect())
+	}
+
+	/// Determines the ID of the top face texture of a block type from its
+	/// blockstate definition and block model
+	fn model_texture(&self, id: &str) -> Result<Option<String>> {
+		let Some(block_state) =
+			self.pack
+				.read_json::<BlockState>(&resource_path(id, "blockstates", "json"))?
+		else {
+			return Ok(None);
+		};
+
+		let Some(model) = block_state
+			.variants
+			.values()
+			.chain(block_state.multipart.iter().map(|case| &case.apply))
+			.find_map(ModelRefs::first)
+		else {
+			return Ok(None);
+		};
+
+		let textures = self.model_textures(&model.model)?;
+
+		let resolve = |key: &str| {
+			let mut value = textures.get(key)?;
+			for _ in 0..MAX_INDIRECTIONS {
+				let Some(key) = value.strip_prefix('#') else {
+					return Some(value.clone());
+				};
+				value = textures.get(key)?;
+			}
+			None
+		};
+
+		Ok(TOP_TEXTURE_KEYS.iter().find_map(|key| resolve(key)))
+	}
+
+	/// Loads the top face texture image of a block type
+	///
+	/// The textures of built-in block types are taken from
+	/// `resource/blocks.json`. Other block types use the texture of their
+	/// block model; if the block type has no blockstate definition, the
+	/// texture is looked up by the block ID with or without a `_top` suffix.
+	fn load_image(&self, id: &str) -> Result<Option<image::DynamicImage>> {
+		let candidates = match self.builtin.get(id) {
+			Some(Some(texture)) => vec![texture.clone()],
+			Some(None) => return Ok(None),
+			None => match self.model_texture(id)? {
+				Some(texture) => vec![texture],
+				None => {
+					let (namespace, name) = id.split_once(':').unwrap_or(("minecraft", id));
+					vec![
+						format!("{}:block/{}_top", namespace, name),
+						format!("{}:block/{}", namespace, name),
+					]
+				}
+			},
+		};
+
+		for texture in candidates {
+			let path = resource_path(&texture, "textures", "png");
+			let Some(data) = self.pack.read(&path)? else {
+				continue;
+			};
+			let image = image::load_from_memory_with_format(&data, image::ImageFormat::Png)
+				.with_context(|| format!("Failed to decode {}", path))?;
+			return Ok(Some(image));
+		}
+
+		Ok(None)
+	}
+
+	/// Loads the top face texture of a block type
+	fn load(&self, id: &str) -> Result<Option<BlockTexture>> {
+		Ok(self.load_image(id)?.map(BlockTexture::new))
+	}
+
+	/// Computes the average color of the top face texture of a block type
+	///
+	/// Like `resource/extract.py`, all frames of animated textures are
+	/// included in the average.
+	pub fn color(&self, id: &str) -> Result<Option<TextureColor>> {
+		let Some(image) = self.load_image(id)? else {
+			return Ok(None);
+		};
+		Ok(TextureColor::new(&image.into_rgba8()))
+	}
+
+	/// Returns the top face texture of a block type
+	///
+	/// Failures to load a texture are logged; the block type is treated as
+	/// having no texture in this case.
+	pub fn get(&self, id: &str) -> Option<Arc<BlockTexture>> {
+		if let Some(texture) = self.cache.lock().unwrap().get(id) {
+			return texture.clone();
+		}
+
+		let texture = match self.load(id) {
+			Ok(texture) => texture.map(Arc::new),
+			Err(err) => {
+				warn!("Failed to load texture of block type {}: {:?}", id, err);
+				None
+			}
+		};
+
+		self.cache
+			.lock()
+			.unwrap()
+			.insert(id.to_string(), texture.clone());
+		texture
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_builtin_textures() {
+		let textures = builtin_textures().unwrap();
+		let texture = |id: &str| textures.get(id).unwrap().as_deref();
+
+		assert_eq!(texture("minecraft:stone"), Some("minecraft:block/stone"));
+		assert_eq!(
+			texture("minecraft:acacia_log"),
+			Some("minecraft:block/acacia_log_top")
+		);
+		assert_eq!(texture("minecraft:acacia_sign"), None);
+		assert_eq!(texture("minecraft:air"), None);
+	}
+
+	#[test]
+	fn test_blockstate_id() {
+		assert_eq!(
+			blockstate_id("assets/examplemod/blockstates/ruby_block.json").as_deref(),
+			Some("examplemod:ruby_block")
+		);
+		assert_eq!(
+			blockstate_id("assets/examplemod/models/block/ruby_block.json"),
+			None
+		);
+		assert_eq!(blockstate_id("pack.mcmeta"), None);
+	}
+}
@@ -3,28 +3,31 @@
 use std::{
 	collections::{BTreeMap, BTreeSet},
 	fmt::Debug,
+	hash::{Hash, Hasher},
 	path::{Path, PathBuf},
 };
 
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use indexmap::IndexSet;
 use regex::{Regex, RegexSet};
 use serde::{Deserialize, Serialize};
 
+use super::{dimension::Dimension, overrides::Overrides, theme::Theme};
 use crate::{
 	io::fs::FileMetaVersion,
-	resource::Biome,
+	resource::{BiomeType, Color, ColorStyle, HeightCurve, HeightRamp},
 	types::*,
-	world::{block_entity::BlockEntity, layer},
+	world::{
+		block_entity::BlockEntity,
+		layer::{self, LayerOptions, VisibilityRule},
+	},
 };
 
-/// Increase to force regeneration of all output files
-
 /// MinedMap processed region data version number
 ///
 /// Increase when the generation of processed regions from region data changes
 /// (usually because of updated resource data)
-pub const REGION_FILE_META_VERSION: FileMetaVersion = FileMetaVersion(2);
+pub const REGION_FILE_META_VERSION: FileMetaVersion = FileMetaVersion(13);
 
 /// MinedMap map tile data version number
 ///
@@ -36,7 +39,19 @@ pub const MAP_FILE_META_VERSION: FileMetaVersion = FileMetaVersion(0);
 ///
 /// Increase when the generation of lightmap tiles from region data changes
 /// (usually because of updated resource data)
-pub const LIGHTMAP_FILE_META_VERSION: FileMetaVersion = FileMetaVersion(2);
+pub const LIGHTMAP_FILE_META_VERSION: FileMetaVersion = FileMetaVersion(4);
+
+/// MinedMap isometric map tile data version number
+///
+/// Increase when the generation of isometric map tiles from region data changes
+/// (usually because of updated resource data)
+pub const ISOMETRIC_FILE_META_VERSION: FileMetaVersion = FileMetaVersion(0);
+
+/// Number of texture levels above zoom level 0
+///
+/// Blocks are drawn with a texture of 2^*n* x 2^*n* pixels on texture level
+/// *n*, so the highest level shows the full 16x16 textures.
+pub const TEXTURE_LEVELS: usize = 4;
 
 /// MinedMap mipmap data version number
 ///
@@ -89,21 +104,49 @@ impl TileCoordMap {
 pub struct ProcessedChunk {
 	/// Block type data
 	pub blocks: Box<layer::BlockArray>,
+	/// Block ID data
+	pub block_ids: Box<layer::BlockIdArray>,
 	/// Biome data
 	pub biomes: Box<layer::BiomeArray>,
+	/// Block light data
+	pub block_light: Box<layer::BlockLightArray>,
+	/// Sky light data
+	pub sky_light: Box<layer::SkyLightArray>,
 	/// Block height/depth data
 	pub depths: Box<layer::DepthArray>,
+	/// Surface height data
+	pub heights: Box<layer::HeightArray>,
+	/// Translucent block data
+	///
+	/// Only stored when translucent blocks are blended.
+	pub translucent: Option<Box<layer::TranslucentArray>>,
+}
+
+impl ProcessedChunk {
+	/// Returns the translucent block on top of a column (if any)
+	pub fn translucent_at(&self, coords: LayerBlockCoords) -> Option<layer::TranslucentBlock> {
+		self.translucent.as_ref()?[coords]
+	}
 }
 
 /// Data structure for storing region data between processing and rendering steps
 #[derive(Debug, Default, Serialize, Deserialize)]
 pub struct ProcessedRegion {
+	/// List of block IDs used in the region
+	///
+	/// Indexed by [ProcessedChunk] block ID data
+	pub block_list: IndexSet<String>,
 	/// List of biomes used in the region
 	///
 	/// Indexed by [ProcessedChunk] biome data
-	pub biome_list: IndexSet<Biome>,
+	pub biome_list: IndexSet<BiomeType>,
 	/// Processed chunk data
 	pub chunks: ChunkArray<Option<Box<ProcessedChunk>>>,
+	/// Timestamps of the last modification of each chunk of the region data
+	///
+	/// Used to determine the chunks that need to be processed again when
+	/// the region data is modified.
+	pub chunk_timestamps: ChunkArray<Option<u32>>,
 }
 
 /// Data structure for storing entity data between processing and collection steps
@@ -124,16 +167,313 @@ fn coord_filename(coords: TileCoords, ext: &str) -> String {
 #[derive(Debug, Clone, Copy)]
 pub enum TileKind {
 	/// Regular map tile contains block colors
-	Map,
+	///
+	/// The value is the index of the [MapLayer] in [Config::layers].
+	Map(usize),
 	/// Lightmap tile for illumination layer
 	Lightmap,
+	/// Relief shading overlay tile
+	Shade,
+	/// Contour line overlay tile
+	Contour,
+	/// Color-ramped heightmap tile
+	Heightmap,
+	/// Heightmap tile storing the raw height values as 16-bit grayscale
+	HeightData,
+	/// Biome map tile
+	Biome,
+	/// Column data tile storing block IDs, biome IDs and heights as 16-bit RGB
+	ColumnData,
+	/// Isometric map tile
+	Isometric,
+	/// Night view map tile combining block colors with block and sky light
+	Night,
+	/// Map tile using the colors of Minecraft map items
+	VanillaMap,
+	/// Color-graded map tile
+	///
+	/// The value is the index of the [Theme] in [Config::themes].
+	Theme(usize),
+}
+
+/// Shared dictionary of the IDs referenced by column data tiles
+///
+/// Column data tiles store IDs as indices into the dictionary lists,
+/// incremented by 1 (0 marks columns without data).
+#[derive(Debug, Serialize)]
+pub struct IdDictionary {
+	/// Namespaced block IDs
+	pub blocks: IndexSet<String>,
+	/// Namespaced biome IDs
+	pub biomes: IndexSet<String>,
+	/// Hash of the block and biome IDs
+	#[serde(skip)]
+	digest: u64,
+}
+
+impl IdDictionary {
+	/// Creates a dictionary of all known block and biome types
+	///
+	/// The IDs are sorted, so the dictionary only changes when the
+	/// resource data or the overrides are updated.
+	fn new(overrides: &Overrides) -> Self {
+		let mut blocks: Vec<_> = overrides
+			.block_types()
+			.iter()
+			.map(|block_type| block_type.id.clone())
+			.collect();
+		blocks.sort();
+
+		let mut biomes: Vec<_> = overrides
+			.biome_types()
+			.iter()
+			.map(|biome_type| biome_type.id.clone())
+			.collect();
+		biomes.sort();
+
+		let mut hasher = rustc_hash::FxHasher::default();
+		(&blocks, &biomes).hash(&mut hasher);
+
+		IdDictionary {
+			blocks: blocks.into_iter().collect(),
+			biomes: biomes.into_iter().collect(),
+			digest: hasher.finish(),
+		}
+	}
+
+	/// Returns a hash of the block and biome IDs
+	///
+	/// The hash is computed once when the dictionary is created, so it can be
+	/// included in the versions of generated files cheaply.
+	pub fn digest(&self) -> u64 {
+		self.digest
+	}
+}
+
+/// A map layer rendered into a separate set of map tiles
+#[derive(Debug, Clone, Serialize)]
+pub struct MapLayer {
+	/// Identifier of the layer
+	///
+	/// Used as the name of the layer's output directories.
+	pub id: String,
+	/// User-defined name of the layer
+	pub name: Option<String>,
+	/// Options for the search of the visible top layer
+	#[serde(flatten)]
+	pub options: LayerOptions,
+}
+
+/// Relief shading configuration
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct Hillshade {
+	/// Output mode
+	pub mode: super::HillshadeMode,
+	/// Direction of the light source in degrees clockwise from north
+	pub azimuth: f32,
+	/// Angle of the light source above the horizon in degrees
+	pub altitude: f32,
+}
+
+impl Hillshade {
+	/// Returns the unit vector pointing towards the light source
+	///
+	/// The components are given in X (east), Y (up), Z (south) order.
+	pub fn light(&self) -> [f32; 3] {
+		let (az_sin, az_cos) = self.azimuth.to_radians().sin_cos();
+		let (alt_sin, alt_cos) = self.altitude.to_radians().sin_cos();
+		[alt_cos * az_sin, alt_sin, -alt_cos * az_cos]
+	}
+
+	/// Computes the shading factor for a surface with the given slopes
+	///
+	/// *dx* and *dz* are the height differences per block in X and Z
+	/// direction. The factor is 1.0 for flat surfaces, greater for surfaces
+	/// facing the light source, and smaller for surfaces facing away from it.
+	pub fn factor(&self, dx: f32, dz: f32) -> f32 {
+		let [lx, ly, lz] = self.light();
+		let len = (dx * dx + 1.0 + dz * dz).sqrt();
+		let lit = (-dx * lx + ly - dz * lz) / len;
+		1.0 + lit.max(0.0) - ly
+	}
+}
+
+/// Biome blending configuration
+///
+/// Determines how the colors of grass, foliage and water are blended at
+/// biome borders.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum BiomeBlend {
+	/// Weighted 5x5 kernel
+	Smooth,
+	/// Box blur over the blocks up to the given distance
+	///
+	/// A radius of 0 disables blending.
+	Box(u8),
+}
+
+/// Maximum radius of [BiomeBlend::Box]
+const MAX_BIOME_BLEND_RADIUS: u8 = 16;
+
+/// Parses the value of the `--biome-blend` command line option
+///
+/// Accepts `none`, `smooth`, `vanilla` (a 15x15 box blur like in Minecraft's
+/// default settings) or a box blur radius.
+fn parse_biome_blend(blend: &str) -> Result<BiomeBlend> {
+	let radius = match blend {
+		"smooth" => return Ok(BiomeBlend::Smooth),
+		"none" => 0,
+		"vanilla" => 7,
+		_ => blend.parse()?,
+	};
+	if radius > MAX_BIOME_BLEND_RADIUS {
+		bail!("Blend radius must be at most {}", MAX_BIOME_BLEND_RADIUS);
+	}
+	Ok(BiomeBlend::Box(radius))
+}
+
+/// Contour line overlay configuration
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct Contours {
+	/// Height difference between neighboring contour lines
+	pub interval: u32,
+	/// Every *major*-th contour line is a major line (0 for none)
+	pub major: u32,
+}
+
+impl Contours {
+	/// Returns the index of the band between two contour lines containing a height
+	///
+	/// Band *n* contains the heights from the *n*-th contour line (at height
+	/// *n* times the interval) up to the next line.
+	pub fn band(&self, height: i32) -> i32 {
+		height.div_euclid(self.interval as i32)
+	}
+
+	/// Returns true if one of the contour lines separating two bands is a major line
+	pub fn has_major(&self, lower: i32, upper: i32) -> bool {
+		self.major != 0 && (lower + 1..=upper).any(|line| line.rem_euclid(self.major as i32) == 0)
+	}
+}
+
+/// Parses the value of the `--slice` command line option
+///
+/// Accepts single Y coordinates as well as inclusive ranges of the form
+/// `FROM..TO` or `FROM..TO/STEP`. The default step is 16.
+fn parse_slice(slice: &str) -> Result<Vec<i32>> {
+	let Some((from, to)) = slice.split_once("..") else {
+		return Ok(vec![slice.trim().parse()?]);
+	};
+	let (to, step) = to.split_once('/').unwrap_or((to, "16"));
+
+	let from: i32 = from.trim().parse()?;
+	let to: i32 = to.trim().parse()?;
+	let step: usize = step.trim().parse()?;
+	if step == 0 {
+		bail!("Step must not be zero");
+	}
+	if from > to {
+		bail!("Range start must not be greater than its end");
+	}
+
+	Ok((from..=to).step_by(step).collect())
+}
+
+/// Parses a list of height control points of the form `HEIGHT:VALUE[,...]`
+///
+/// The returned points are sorted by height.
+fn parse_height_points<T>(
+	points: &str,
+	parse_value: impl Fn(&str) -> Result<T>,
+) -> Result<Vec<(f32, T)>> {
+	let mut points = points
+		.split(',')
+		.map(|point| {
+			let (height, value) = point
+				.split_once(':')
+				.context("Expected height and value separated by ':'")?;
+			let height: f32 = height.trim().parse()?;
+			if !height.is_finite() {
+				bail!("Invalid height '{}'", height);
+			}
+			Ok((height, parse_value(value.trim())?))
+		})
+		.collect::<Result<Vec<_>>>()?;
+
+	points.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+	Ok(points)
+}
+
+/// Parses the value of the `--brightness` and `--water-brightness` command line options
+///
+/// Accepts a list of control points or a single constant factor.
+fn parse_height_curve(curve: &str) -> Result<HeightCurve> {
+	let parse_factor = |factor: &str| -> Result<f32> {
+		let factor: f32 = factor.parse()?;
+		if !(factor.is_finite() && factor >= 0.0) {
+			bail!("Invalid brightness factor '{}'", factor);
+		}
+		Ok(factor)
+	};
+
+	if !curve.contains(':') {
+		return Ok(HeightCurve(vec![(0.0, parse_factor(curve.trim())?)]));
+	}
+
+	Ok(HeightCurve(parse_height_points(curve, parse_factor)?))
+}
+
+/// Parses a color of the form `RRGGBB` or `#RRGGBB`
+pub fn parse_color(color: &str) -> Result<Color> {
+	let hex = color.strip_prefix('#').unwrap_or(color);
+	if hex.len() != 6 {
+		bail!("Invalid color '{}'", color);
+	}
+	let value =
+		u32::from_str_radix(hex, 16).with_context(|| format!("Invalid color '{}'", color))?;
+	let [_, r, g, b] = value.to_be_bytes();
+	Ok(Color([r, g, b]))
+}
+
+/// Parses the value of the `--height-tint` command line option
+fn parse_height_ramp(ramp: &str) -> Result<HeightRamp> {
+	Ok(HeightRamp(parse_height_points(ramp, parse_color)?))
+}
+
+/// Parses the value of the `--transparent-layer` command line option
+///
+/// The value has the form `NAME=RULE[,RULE...]`, each rule being a block ID
+/// or a block tag like `#leaves`.
+fn parse_transparent_layer(layer: &str) -> Result<(String, Vec<VisibilityRule>)> {
+	let (name, rules) = layer
+		.split_once('=')
+		.context("Expected layer name and rules separated by '='")?;
+
+	if name.is_empty()
+		|| !name
+			.chars()
+			.all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+	{
+		bail!("Layer name must consist of alphanumeric characters, '-' and '_'");
+	}
+
+	let rules = rules
+		.split(',')
+		.map(|rule| rule.trim().parse())
+		.collect::<Result<_>>()?;
+
+	Ok((name.to_string(), rules))
 }
 
 /// Common configuration based on command line arguments
+///
+/// A separate Config is created for each rendered [Dimension].
 #[derive(Debug)]
 pub struct Config {
 	/// Number of threads for parallel processing
 	pub num_threads: usize,
+	/// The dimension to render
+	pub dimension: Dimension,
 	/// Path of input region directory
 	pub region_dir: PathBuf,
 	/// Path of input `level.dat` file
@@ -150,30 +490,89 @@ pub struct Config {
 	pub viewer_info_path: PathBuf,
 	/// Path of viewer entities file
 	pub viewer_entities_path: PathBuf,
+	/// Path of column data ID dictionary file
+	pub viewer_ids_path: PathBuf,
+	/// Path of unknown block and biome ID report file (if enabled)
+	pub unknown_report_path: Option<PathBuf>,
+	/// Fail if unknown block or biome IDs are found
+	pub strict: bool,
+	/// Map layers to render
+	///
+	/// The first entry is the main map layer, which is also used to generate
+	/// the lightmap.
+	pub layers: Vec<MapLayer>,
+	/// Relief shading configuration
+	pub hillshade: Option<Hillshade>,
+	/// Height-dependent adjustments of block colors
+	pub color_style: ColorStyle,
+	/// Biome blending for biome-dependent block colors
+	pub biome_blend: BiomeBlend,
+	/// Contour line overlay configuration
+	pub contours: Option<Contours>,
+	/// Generate heightmap tiles
+	pub heightmap: bool,
+	/// Generate biome map tiles
+	pub biome_layer: bool,
+	/// Generate night view map tiles
+	pub night: bool,
+	/// Generate map tiles using the colors of Minecraft map items
+	pub vanilla_map: bool,
+	/// Color grading themes to generate map tiles for
+	pub themes: Vec<Theme>,
+	/// ID dictionary for column data tiles (if enabled)
+	pub column_data: Option<IdDictionary>,
+	/// Viewing direction of the isometric map layer (if enabled)
+	pub isometric: Option<super::IsometricDirection>,
+	/// Resource packs to load block textures from
+	///
+	/// Texture tiles are generated if the list is not empty.
+	pub texture_packs: Vec<PathBuf>,
 	/// Sign text filter patterns
 	pub sign_patterns: RegexSet,
 	/// Sign text transformation pattern
 	pub sign_transforms: Vec<(Regex, String)>,
+	/// Block and biome types added to or replacing the built-in types
+	pub overrides: Overrides,
 }
 
 impl Config {
-	/// Crates a new [Config] from [command line arguments](super::Args)
-	pub fn new(args: &super::Args) -> Result<Self> {
-		let num_threads = match args.jobs {
-			Some(0) => num_cpus::get(),
-			Some(threads) => threads,
-			None => 1,
-		};
+	/// Crates a new [Config] for a [Dimension] from [command line arguments](super::Args)
+	pub fn new(args: &super::Args, dimension: &Dimension, overrides: &Overrides) -> Result<Self> {
+		let num_threads = Self::num_threads(args);
 
-		let region_dir = [&args.input_dir, Path::new("region")].iter().collect();
+		let region_dir = dimension.region_dir.clone();
 		let level_dat_path = [&args.input_dir, Path::new("level.dat")].iter().collect();
-		let processed_dir: PathBuf = [&args.output_dir, Path::new("processed")].iter().collect();
-		let entities_dir: PathBuf = [&processed_dir, Path::new("entities")].iter().collect();
-		let entities_path_final = [&entities_dir, Path::new("entities.bin")].iter().collect();
-		let viewer_info_path = [&args.output_dir, Path::new("info.json")].iter().collect();
-		let viewer_entities_path = [&args.output_dir, Path::new("entities.json")]
+		let output_dir: PathBuf = [&args.output_dir, Path::new(&dimension.path)]
 			.iter()
 			.collect();
+		let processed_dir: PathBuf = [
+			&args.output_dir,
+			Path::new("processed"),
+			Path::new(&dimension.path),
+		]
+		.iter()
+		.collect();
+		let entities_dir: PathBuf = [&processed_dir, Path::new("entities")].iter().collect();
+		let entities_path_final = [&entities_dir, Path::new("entities.bin")].iter().collect();
+		let viewer_info_path = [&output_dir, Path::new("info.json")].iter().collect();
+		let viewer_entities_path = [&output_dir, Path::new("entities.json")].iter().collect();
+		let viewer_ids_path = [&output_dir, Path::new("ids.json")].iter().collect();
+		let unknown_report_path = args
+			.unknown_report
+			.then(|| [&output_dir, Path::new("unknown.json")].iter().collect());
+
+		let skip_ceiling = match args.ceiling {
+			super::CeilingMode::Auto => dimension.has_ceiling(),
+			super::CeilingMode::Skip => true,
+			super::CeilingMode::Render => false,
+		};
+		let layers =
+			Self::layers(args, dimension, skip_ceiling).context("Failed to parse layer options")?;
+
+		let hillshade = Self::hillshade(args)?;
+		let color_style = Self::color_style(args).context("Failed to parse color options")?;
+		let biome_blend = parse_biome_blend(&args.biome_blend)
+			.with_context(|| format!("Invalid biome blend '{}'", args.biome_blend))?;
 
 		let sign_patterns = Self::sign_patterns(args).context("Failed to parse sign patterns")?;
 		let sign_transforms =
@@ -181,19 +580,190 @@ impl Config {
 
 		Ok(Config {
 			num_threads,
+			dimension: dimension.clone(),
 			region_dir,
 			level_dat_path,
-			output_dir: args.output_dir.clone(),
+			output_dir,
 			processed_dir,
 			entities_dir,
 			entities_path_final,
 			viewer_info_path,
 			viewer_entities_path,
+			viewer_ids_path,
+			unknown_report_path,
+			strict: args.strict,
+			layers,
+			hillshade,
+			color_style,
+			biome_blend,
+			contours: args.contours.map(|interval| Contours {
+				interval,
+				major: args.contour_major,
+			}),
+			heightmap: args.heightmap,
+			biome_layer: args.biome_layer,
+			night: args.night,
+			vanilla_map: args.vanilla_map,
+			themes: Self::themes(args)?,
+			column_data: args.column_data.then(|| IdDictionary::new(overrides)),
+			isometric: args.isometric,
+			texture_packs: args.textures.clone(),
 			sign_patterns,
 			sign_transforms,
+			overrides: overrides.clone(),
 		})
 	}
 
+	/// Determines the number of threads to use for parallel processing
+	pub fn num_threads(args: &super::Args) -> usize {
+		match args.jobs {
+			Some(0) => num_cpus::get(),
+			Some(threads) => threads,
+			None => 1,
+		}
+	}
+
+	/// Builds the list of [MapLayer]s from the main layer, transparency layer and slice options
+	fn layers(
+		args: &super::Args,
+		dimension: &Dimension,
+		skip_ceiling: bool,
+	) -> Result<Vec<MapLayer>> {
+		let main_options = LayerOptions {
+			skip_ceiling,
+			ceiling_min_y: dimension.ceiling_min_y(),
+			max_y: args.max_y,
+			transparent: Vec::new(),
+			blend_translucent: args.translucent,
+			has_sky_light: dimension.has_sky_light(),
+		};
+
+		let mut layers = vec![MapLayer {
+			id: String::from("map"),
+			name: None,
+			options: main_options.clone(),
+		}];
+
+		for layer in &args.transparent_layer {
+			let (name, transparent) = parse_transparent_layer(layer)
+				.with_context(|| format!("Invalid transparent layer '{}'", layer))?;
+			let id = format!("map-{}", name);
+			if layers.iter().any(|other| other.id == id) {
+				bail!("Duplicate layer name '{}'", name);
+			}
+
+			layers.push(MapLayer {
+				id,
+				name: Some(name),
+				options: LayerOptions {
+					transparent,
+					..main_options.clone()
+				},
+			});
+		}
+
+		let mut slices = BTreeSet::new();
+		for slice in &args.slice {
+			slices
+				.extend(parse_slice(slice).with_context(|| format!("Invalid slice '{}'", slice))?);
+		}
+
+		for max_y in slices {
+			let id = format!("map-y{}", max_y);
+			if layers.iter().any(|other| other.id == id) {
+				bail!("Layer name 'y{}' conflicts with slice layer", max_y);
+			}
+
+			layers.push(MapLayer {
+				id,
+				name: None,
+				options: LayerOptions {
+					skip_ceiling: false,
+					ceiling_min_y: None,
+					max_y: Some(max_y),
+					transparent: Vec::new(),
+					blend_translucent: args.translucent,
+					has_sky_light: dimension.has_sky_light(),
+				},
+			});
+		}
+
+		Ok(layers)
+	}
+
+	/// Builds the [Hillshade] configuration from the command line arguments
+	fn hillshade(args: &super::Args) -> Result<Option<Hillshade>> {
+		let Some(mode) = args.hillshade else {
+			return Ok(None);
+		};
+
+		if !(args.light_altitude > 0.0 && args.light_altitude <= 90.0) {
+			bail!("Light altitude must be greater than 0 and at most 90 degrees");
+		}
+
+		Ok(Some(Hillshade {
+			mode,
+			azimuth: args.light_azimuth,
+			altitude: args.light_altitude,
+		}))
+	}
+
+	/// Builds the [ColorStyle] from the command line arguments
+	fn color_style(args: &super::Args) -> Result<ColorStyle> {
+		let mut style = ColorStyle::default();
+
+		if let Some(brightness) = &args.brightness {
+			style.brightness = parse_height_curve(brightness)
+				.with_context(|| format!("Invalid brightness curve '{}'", brightness))?;
+			style.water_brightness = style.brightness.clone();
+		}
+		if let Some(brightness) = &args.water_brightness {
+			style.water_brightness = parse_height_curve(brightness)
+				.with_context(|| format!("Invalid water brightness curve '{}'", brightness))?;
+		}
+		if let Some(tint) = &args.height_tint {
+			style.tint = parse_height_ramp(tint)
+				.with_context(|| format!("Invalid height tint '{}'", tint))?;
+		}
+		if !(0.0..=1.0).contains(&args.height_tint_strength) {
+			bail!("Height tint strength must be between 0 and 1");
+		}
+		style.tint_strength = args.height_tint_strength;
+		style.height_temperature = !args.no_height_temperature;
+
+		Ok(style)
+	}
+
+	/// Loads the color grading [Theme]s selected on the command line
+	fn themes(args: &super::Args) -> Result<Vec<Theme>> {
+		let mut themes: Vec<Theme> = Vec::new();
+
+		for theme in &args.theme {
+			let theme =
+				Theme::parse(theme).with_context(|| format!("Invalid theme '{}'", theme))?;
+			if themes.iter().any(|other| other.id == theme.id) {
+				bail!("Duplicate theme '{}'", theme.name);
+			}
+			themes.push(theme);
+		}
+
+		Ok(themes)
+	}
+
+	/// Returns the [Hillshade] configuration if shading is applied to map tiles
+	pub fn baked_hillshade(&self) -> Option<&Hillshade> {
+		self.hillshade
+			.as_ref()
+			.filter(|hillshade| hillshade.mode == super::HillshadeMode::Baked)
+	}
+
+	/// Returns the [Hillshade] configuration if a shading overlay is generated
+	pub fn overlay_hillshade(&self) -> Option<&Hillshade> {
+		self.hillshade
+			.as_ref()
+			.filter(|hillshade| hillshade.mode == super::HillshadeMode::Overlay)
+	}
+
 	/// Parses the sign prefixes and sign filters into a [RegexSet]
 	fn sign_patterns(args: &super::Args) -> Result<RegexSet> {
 		let prefix_patterns: Vec<_> = args
@@ -228,16 +798,158 @@ impl Config {
 		Ok((regexp, replacement))
 	}
 
+	/// Returns the configuration affecting the processed region data of a map layer
+	///
+	/// All generated files depend on the layer options and the block and
+	/// biome type overrides.
+	fn layer_config(&self, layer: usize) -> (&LayerOptions, u64) {
+		(&self.layers[layer].options, self.overrides.digest())
+	}
+
+	/// Returns the [FileMetaVersion] of processed region data for a map layer
+	///
+	/// Changing the layer options or overrides invalidates existing processed
+	/// data. Strict mode is included, as processed data generated without it
+	/// may contain unknown block and biome types.
+	pub fn region_file_meta_version(&self, layer: usize) -> FileMetaVersion {
+		REGION_FILE_META_VERSION.with_config(&(self.layer_config(layer), self.strict))
+	}
+
+	/// Returns the [FileMetaVersion] of rendered map tiles for a map layer
+	///
+	/// Regenerated processed region data retains the timestamp of the region
+	/// input file, so changes to the layer options must invalidate the map
+	/// tiles as well.
+	pub fn map_file_meta_version(&self, layer: usize) -> FileMetaVersion {
+		MAP_FILE_META_VERSION.with_config(&(
+			self.layer_config(layer),
+			self.baked_hillshade(),
+			&self.color_style,
+			self.biome_blend,
+		))
+	}
+
+	/// Returns the [FileMetaVersion] of relief shading overlay tiles
+	pub fn shade_file_meta_version(&self) -> FileMetaVersion {
+		MAP_FILE_META_VERSION.with_config(&(self.layer_config(0), self.overlay_hillshade()))
+	}
+
+	/// Returns the [FileMetaVersion] of contour line overlay tiles
+	pub fn contour_file_meta_version(&self) -> FileMetaVersion {
+		MAP_FILE_META_VERSION.with_config(&(self.layer_config(0), self.contours))
+	}
+
+	/// Returns the [FileMetaVersion] of heightmap tiles
+	pub fn heightmap_file_meta_version(&self) -> FileMetaVersion {
+		MAP_FILE_META_VERSION.with_config(&self.layer_config(0))
+	}
+
+	/// Returns the [FileMetaVersion] of biome map tiles
+	pub fn biome_file_meta_version(&self) -> FileMetaVersion {
+		MAP_FILE_META_VERSION.with_config(&self.layer_config(0))
+	}
+
+	/// Returns the [FileMetaVersion] of night view tiles
+	pub fn night_file_meta_version(&self) -> FileMetaVersion {
+		MAP_FILE_META_VERSION.with_config(&(
+			self.layer_config(0),
+			self.baked_hillshade(),
+			&self.color_style,
+			self.biome_blend,
+		))
+	}
+
+	/// Returns the [FileMetaVersion] of vanilla map tiles
+	pub fn vanilla_map_file_meta_version(&self) -> FileMetaVersion {
+		MAP_FILE_META_VERSION.with_config(&self.layer_config(0))
+	}
+
+	/// Returns the [FileMetaVersion] of color-graded map tiles
+	///
+	/// The version includes the digest of the color transformation, so
+	/// changes to theme files invalidate existing tiles.
+	pub fn theme_file_meta_version(&self, theme: usize) -> FileMetaVersion {
+		MAP_FILE_META_VERSION.with_config(&(
+			self.layer_config(0),
+			self.baked_hillshade(),
+			&self.color_style,
+			self.biome_blend,
+			self.themes[theme].digest(),
+		))
+	}
+
+	/// Returns the [FileMetaVersion] of column data tiles
+	///
+	/// Changes to the ID dictionary invalidate existing tiles.
+	pub fn column_data_file_meta_version(&self) -> FileMetaVersion {
+		MAP_FILE_META_VERSION.with_config(&(
+			self.layer_config(0),
+			self.column_data.as_ref().map(IdDictionary::digest),
+		))
+	}
+
+	/// Returns the [FileMetaVersion] of isometric map tiles
+	pub fn isometric_file_meta_version(&self) -> FileMetaVersion {
+		ISOMETRIC_FILE_META_VERSION.with_config(&(
+			self.layer_config(0),
+			self.isometric,
+			&self.color_style,
+		))
+	}
+
+	/// Returns the [FileMetaVersion] of texture tiles
+	///
+	/// Using different resource packs invalidates existing tiles.
+	pub fn texture_file_meta_version(&self) -> FileMetaVersion {
+		MAP_FILE_META_VERSION.with_config(&(
+			self.layer_config(0),
+			self.baked_hillshade(),
+			&self.color_style,
+			self.biome_blend,
+			&self.texture_packs,
+		))
+	}
+
+	/// Returns the [FileMetaVersion] of level 0 tiles of a [TileKind]
+	pub fn tile_file_meta_version(&self, kind: TileKind) -> FileMetaVersion {
+		match kind {
+			TileKind::Map(layer) => self.map_file_meta_version(layer),
+			TileKind::Lightmap => self.lightmap_file_meta_version(),
+			TileKind::Shade => self.shade_file_meta_version(),
+			TileKind::Contour => self.contour_file_meta_version(),
+			TileKind::Heightmap | TileKind::HeightData => self.heightmap_file_meta_version(),
+			TileKind::Biome => self.biome_file_meta_version(),
+			TileKind::ColumnData => self.column_data_file_meta_version(),
+			TileKind::Isometric => self.isometric_file_meta_version(),
+			TileKind::Night => self.night_file_meta_version(),
+			TileKind::VanillaMap => self.vanilla_map_file_meta_version(),
+			TileKind::Theme(theme) => self.theme_file_meta_version(theme),
+		}
+	}
+
+	/// Returns the [FileMetaVersion] of lightmap tiles
+	pub fn lightmap_file_meta_version(&self) -> FileMetaVersion {
+		LIGHTMAP_FILE_META_VERSION.with_config(&self.layer_config(0))
+	}
+
 	/// Constructs the path to an input region file
 	pub fn region_path(&self, coords: TileCoords) -> PathBuf {
 		let filename = coord_filename(coords, "mca");
 		[&self.region_dir, Path::new(&filename)].iter().collect()
 	}
 
+	/// Constructs the base path for intermediate processed region files of a map layer
+	pub fn processed_layer_dir(&self, layer: usize) -> PathBuf {
+		[&self.processed_dir, Path::new(&self.layers[layer].id)]
+			.iter()
+			.collect()
+	}
+
 	/// Constructs the path of an intermediate processed region file
-	pub fn processed_path(&self, coords: TileCoords) -> PathBuf {
+	pub fn processed_path(&self, layer: usize, coords: TileCoords) -> PathBuf {
 		let filename = coord_filename(coords, "bin");
-		[&self.processed_dir, Path::new(&filename)].iter().collect()
+		let dir = self.processed_layer_dir(layer);
+		[Path::new(&dir), Path::new(&filename)].iter().collect()
 	}
 
 	/// Constructs the base output path for processed entity data
@@ -257,8 +969,18 @@ impl Config {
 	/// Constructs the base output path for a [TileKind] and mipmap level
 	pub fn tile_dir(&self, kind: TileKind, level: usize) -> PathBuf {
 		let prefix = match kind {
-			TileKind::Map => "map",
+			TileKind::Map(layer) => &self.layers[layer].id,
 			TileKind::Lightmap => "light",
+			TileKind::Shade => "shade",
+			TileKind::Contour => "contour",
+			TileKind::Heightmap => "height",
+			TileKind::HeightData => "height-data",
+			TileKind::Biome => "biome",
+			TileKind::ColumnData => "columns",
+			TileKind::Isometric => "isometric",
+			TileKind::Night => "night",
+			TileKind::VanillaMap => "vanilla",
+			TileKind::Theme(theme) => &self.themes[theme].id,
 		};
 		let dir = format!("{}/{}", prefix, level);
 		[&self.output_dir, Path::new(&dir)].iter().collect()
@@ -270,6 +992,22 @@ impl Config {
 		let dir = self.tile_dir(kind, level);
 		[Path::new(&dir), Path::new(&filename)].iter().collect()
 	}
+
+	/// Constructs the base output path for a texture level
+	pub fn texture_tile_dir(&self, level: usize) -> PathBuf {
+		let dir = format!("texture/{}", level);
+		[&self.output_dir, Path::new(&dir)].iter().collect()
+	}
+
+	/// Constructs the path of a texture tile image
+	///
+	/// Each region is split into 2^*level* x 2^*level* texture tiles;
+	/// *coords* are the coordinates of the tile on its texture level.
+	pub fn texture_tile_path(&self, level: usize, coords: TileCoords) -> PathBuf {
+		let filename = coord_filename(coords, "png");
+		let dir = self.texture_tile_dir(level);
+		[Path::new(&dir), Path::new(&filename)].iter().collect()
+	}
 }
 
 /// Copies a chunk image into a region tile
@@ -285,3 +1023,106 @@ where
 		coords.z.0 as i64 * BLOCKS_PER_CHUNK as i64,
 	);
 }
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_parse_slice() {
+		assert_eq!(parse_slice("32").unwrap(), vec![32]);
+		assert_eq!(parse_slice("-16").unwrap(), vec![-16]);
+		assert_eq!(parse_slice("-64..0").unwrap(), vec![-64, -48, -32, -16, 0]);
+		assert_eq!(parse_slice("0..20/8").unwrap(), vec![0, 8, 16]);
+		assert!(parse_slice("0..20/0").is_err());
+		assert!(parse_slice("64..0").is_err());
+		assert!(parse_slice("foo").is_err());
+	}
+
+	#[test]
+	fn test_parse_transparent_layer() {
+		let (name, rules) = parse_transparent_layer("no-leaves=#leaves, glass,mod:roof").unwrap();
+		assert_eq!(name, "no-leaves");
+		assert_eq!(
+			rules,
+			vec![
+				VisibilityRule::Tag(layer::BlockTag::Leaves),
+				VisibilityRule::Block(String::from("minecraft:glass")),
+				VisibilityRule::Block(String::from("mod:roof")),
+			]
+		);
+
+		assert!(parse_transparent_layer("#leaves").is_err());
+		assert!(parse_transparent_layer("a/b=#leaves").is_err());
+		assert!(parse_transparent_layer("x=#foo").is_err());
+	}
+
+	#[test]
+	fn test_parse_height_curve() {
+		let curve = parse_height_curve("64:1, -64:0.5,320:1.5").unwrap();
+		assert_eq!(
+			curve,
+			HeightCurve(vec![(-64.0, 0.5), (64.0, 1.0), (320.0, 1.5)])
+		);
+		assert_eq!(curve.value(-100.0), 0.5);
+		assert_eq!(curve.value(0.0), 0.75);
+		assert_eq!(curve.value(400.0), 1.5);
+
+		let constant = parse_height_curve("1").unwrap();
+		assert_eq!(constant.value(-64.0), 1.0);
+		assert_eq!(constant.value(320.0), 1.0);
+
+		assert!(parse_height_curve("64:-1").is_err());
+		assert!(parse_height_curve("64:1,foo").is_err());
+	}
+
+	#[test]
+	fn test_parse_biome_blend() {
+		assert_eq!(parse_biome_blend("smooth").unwrap(), BiomeBlend::Smooth);
+		assert_eq!(parse_biome_blend("none").unwrap(), BiomeBlend::Box(0));
+		assert_eq!(parse_biome_blend("vanilla").unwrap(), BiomeBlend::Box(7));
+		assert_eq!(parse_biome_blend("3").unwrap(), BiomeBlend::Box(3));
+		assert!(parse_biome_blend("17").is_err());
+		assert!(parse_biome_blend("-1").is_err());
+		assert!(parse_biome_blend("box").is_err());
+	}
+
+	#[test]
+	fn test_parse_height_ramp() {
+		let ramp = parse_height_ramp("0:#000000,100:ff8000").unwrap();
+		assert_eq!(
+			ramp,
+			HeightRamp(vec![(0.0, Color([0, 0, 0])), (100.0, Color([255, 128, 0]))])
+		);
+		assert_eq!(
+			ramp.color(50.0),
+			Some(crate::resource::Colorf::new(127.5, 64.0, 0.0))
+		);
+
+		assert!(parse_height_ramp("0:fff").is_err());
+		assert!(parse_height_ramp("0:gggggg").is_err());
+	}
+
+	#[test]
+	fn test_contours() {
+		let contours = Contours {
+			interval: 10,
+			major: 5,
+		};
+		assert_eq!(contours.band(0), 0);
+		assert_eq!(contours.band(59), 5);
+		assert_eq!(contours.band(-1), -1);
+		assert_eq!(contours.band(-10), -1);
+		assert!(contours.has_major(-1, 0));
+		assert!(contours.has_major(4, 5));
+		assert!(contours.has_major(3, 7));
+		assert!(!contours.has_major(5, 9));
+		assert!(!contours.has_major(5, 5));
+
+		let minor_only = Contours {
+			interval: 10,
+			major: 0,
+		};
+		assert!(!minor_only.has_major(-1, 0));
+	}
+}
@@ -0,0 +1,210 @@
+//! Discovery of the dimensions of a Minecraft save directory
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+
+/// Namespace of vanilla Minecraft dimensions
+const MINECRAFT_NAMESPACE: &str = "minecraft";
+
+/// A dimension of a Minecraft save
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Dimension {
+	/// Namespaced dimension ID (like `minecraft:the_nether`)
+	pub id: String,
+	/// Path of the dimension's output directories relative to the output base path
+	pub path: String,
+	/// Directory containing the region files of the dimension
+	#[serde(skip)]
+	pub region_dir: PathBuf,
+}
+
+impl Dimension {
+	/// Adds the `minecraft` namespace to dimension IDs passed without a namespace
+	fn normalize_id(id: &str) -> String {
+		if id.contains(':') {
+			id.to_string()
+		} else {
+			format!("{}:{}", MINECRAFT_NAMESPACE, id)
+		}
+	}
+
+	/// Returns the candidate region directories for a dimension ID
+	///
+	/// Datapack dimensions are stored in `dimensions/<namespace>/<path>`. The
+	/// vanilla Nether and End are stored in `DIM-1` and `DIM1` respectively,
+	/// with Bukkit-style servers using separate `<world>_nether` and
+	/// `<world>_the_end` directories.
+	fn region_dir_candidates(input_dir: &Path, id: &str) -> Vec<PathBuf> {
+		let (namespace, path) = id.split_once(':').expect("dimension ID must be namespaced");
+
+		let mut candidates = vec![[
+			input_dir,
+			Path::new("dimensions"),
+			Path::new(namespace),
+			Path::new(path),
+			Path::new("region"),
+		]
+		.iter()
+		.collect()];
+
+		let with_suffix = |suffix: &str, dim_dir: &str| {
+			let mut name = input_dir.file_name()?.to_os_string();
+			name.push(suffix);
+			Some(input_dir.with_file_name(name).join(dim_dir).join("region"))
+		};
+
+		match (namespace, path) {
+			(MINECRAFT_NAMESPACE, "overworld") => {
+				candidates.push(input_dir.join("region"));
+			}
+			(MINECRAFT_NAMESPACE, "the_nether") => {
+				candidates.push(input_dir.join("DIM-1").join("region"));
+				candidates.extend(with_suffix("_nether", "DIM-1"));
+			}
+			(MINECRAFT_NAMESPACE, "the_end") => {
+				candidates.push(input_dir.join("DIM1").join("region"));
+				candidates.extend(with_suffix("_the_end", "DIM1"));
+			}
+			_ => {}
+		}
+
+		candidates
+	}
+
+	/// Looks up a dimension by ID
+	///
+	/// Returns [None] if the save directory does not contain region data for
+	/// the dimension.
+	fn find(input_dir: &Path, id: &str) -> Option<Self> {
+		let id = Self::normalize_id(id);
+		let region_dir = Self::region_dir_candidates(input_dir, &id)
+			.into_iter()
+			.find(|dir| dir.is_dir())?;
+		let path = id.replace(':', "/");
+
+		Some(Dimension {
+			id,
+			path,
+			region_dir,
+		})
+	}
+
+	/// Collects the IDs of all datapack dimensions below a `dimensions` directory
+	///
+	/// Dimension paths may contain slashes, so the directory tree is searched
+	/// recursively for `region` directories.
+	fn collect_datapack_ids(dir: &Path, prefix: &str, ids: &mut Vec<String>) {
+		let Ok(entries) = dir.read_dir() else {
+			return;
+		};
+
+		for entry in entries.filter_map(|entry| entry.ok()) {
+			if !entry.file_type().is_ok_and(|file_type| file_type.is_dir()) {
+				continue;
+			}
+			let Some(name) = entry.file_name().to_str().map(String::from) else {
+				continue;
+			};
+
+			if name == "region" && prefix.contains(':') && !prefix.ends_with(':') {
+				ids.push(prefix.to_string());
+				continue;
+			}
+
+			let sub_prefix = if prefix.is_empty() {
+				format!("{}:", name)
+			} else if prefix.ends_with(':') {
+				format!("{}{}", prefix, name)
+			} else {
+				format!("{}/{}", prefix, name)
+			};
+			Self::collect_datapack_ids(&entry.path(), &sub_prefix, ids);
+		}
+	}
+
+	/// Finds all dimensions of a save directory
+	///
+	/// The vanilla dimensions are returned first, followed by datapack
+	/// dimensions sorted by ID.
+	fn find_all(input_dir: &Path) -> Vec<Self> {
+		let mut ids: Vec<String> = ["overworld", "the_nether", "the_end"]
+			.into_iter()
+			.map(Self::normalize_id)
+			.collect();
+
+		let mut datapack_ids = Vec::new();
+		Self::collect_datapack_ids(&input_dir.join("dimensions"), "", &mut datapack_ids);
+		datapack_ids.sort();
+		ids.extend(datapack_ids.into_iter().filter(|id| {
+			!matches!(
+				id.as_str(),
+				"minecraft:overworld" | "minecraft:the_nether" | "minecraft:the_end"
+			)
+		}));
+
+		ids.iter()
+			.filter_map(|id| Self::find(input_dir, id))
+			.collect()
+	}
+
+	/// Determines the list of dimensions to render from the command line arguments
+	///
+	/// If no dimensions are selected, only the Overworld is rendered.
+	pub fn from_args(args: &super::Args) -> Result<Vec<Self>> {
+		if args.dimension.iter().any(|id| id == "all") {
+			let dimensions = Self::find_all(&args.input_dir);
+			if dimensions.is_empty() {
+				bail!(
+					"No dimensions found in save directory {}",
+					args.input_dir.display()
+				);
+			}
+			return Ok(dimensions);
+		}
+
+		let ids = if args.dimension.is_empty() {
+			vec![String::from("overworld")]
+		} else {
+			args.dimension.clone()
+		};
+
+		let mut dimensions: Vec<Self> = Vec::new();
+		for id in ids {
+			let dimension = Self::find(&args.input_dir, &id)
+				.with_context(|| format!("Dimension '{}' not found in save directory", id))?;
+			if dimensions.iter().any(|other| other.id == dimension.id) {
+				continue;
+			}
+			dimensions.push(dimension);
+		}
+
+		Ok(dimensions)
+	}
+
+	/// Returns true for the Overworld dimension
+	pub fn is_overworld(&self) -> bool {
+		self.id == "minecraft:overworld"
+	}
+
+	/// Returns true for dimensions with a solid ceiling like the Nether
+	pub fn has_ceiling(&self) -> bool {
+		self.id == "minecraft:the_nether"
+	}
+
+	/// Returns the lowest Y coordinate of the bedrock ceiling of dimensions with a ceiling
+	///
+	/// The bedrock roof of the Nether spans Y=123 to Y=127.
+	pub fn ceiling_min_y(&self) -> Option<i32> {
+		self.has_ceiling().then_some(123)
+	}
+
+	/// Returns true for dimensions with sky light
+	///
+	/// The Nether and the End do not have sky light; other dimensions are
+	/// assumed to have it.
+	pub fn has_sky_light(&self) -> bool {
+		self.id != "minecraft:the_nether" && self.id != "minecraft:the_end"
+	}
+}
@@ -0,0 +1,872 @@
+//! The [IsometricRenderer] and related types and functions
+
+use std::{
+	cell,
+	collections::BTreeSet,
+	num::NonZeroUsize,
+	path::Path,
+	sync::{Arc, Mutex},
+	time::SystemTime,
+};
+
+use anyhow::{Context, Result};
+use lru::LruCache;
+use rayon::prelude::*;
+use rustc_hash::FxHashMap;
+use tokio::sync::OnceCell;
+use tracing::{debug, info, warn};
+
+use super::{common::*, IsometricDirection};
+use crate::{
+	io::fs,
+	resource::{
+		block_color, needs_biome, Biome, BiomeTypes, BlockColor, BlockTypes, ColorStyle, Colorf,
+	},
+	types::*,
+	util::{from_flat_coord, ShiftMask},
+	world::{
+		chunk::Chunk,
+		de,
+		layer::{self, BlockHeight, LayerOptions},
+	},
+};
+
+/// Height of the blocks whose top faces are drawn without vertical offset
+pub const ISOMETRIC_BASE_HEIGHT: i32 = 64;
+
+/// Lowest block height shown on the isometric map
+const ISOMETRIC_MIN_Y: i32 = -64;
+
+/// Highest block height shown on the isometric map
+const ISOMETRIC_MAX_Y: i32 = 319;
+
+/// Brightness factor applied to block faces facing east or west
+///
+/// Like in Minecraft, east/west and north/south faces are shaded differently.
+const X_SHADE: f32 = 0.6;
+
+/// Brightness factor applied to block faces facing north or south
+const Z_SHADE: f32 = 0.8;
+
+/// Width/height of a region in blocks
+const REGION_SIZE: i32 = (BLOCKS_PER_CHUNK * CHUNKS_PER_REGION) as i32;
+
+/// Width/height of a tile image in pixels
+const TILE_SIZE: i32 = 512;
+
+/// Number of tile image pixels per unit of the projected coordinates
+pub const ISOMETRIC_PIXELS_PER_UNIT: i32 = 2;
+
+/// Width/height of a tile in units of the projected coordinates
+const TILE_UNITS: i32 = TILE_SIZE / ISOMETRIC_PIXELS_PER_UNIT;
+
+/// Mirrors a coordinate if *sign* is negative
+///
+/// Block coordinate *v* is mapped to -*v*-1, so blocks keep their unit
+/// extent in positive direction. Mirroring is its own inverse.
+fn mirror(sign: i32, v: i32) -> i32 {
+	if sign > 0 {
+		v
+	} else {
+		-v - 1
+	}
+}
+
+/// Isometric projection for a viewing direction
+///
+/// The projection uses mirrored block coordinates A and B, which are the X
+/// and Z coordinates, mirrored such that the viewer is located towards
+/// positive A and B. A point at A, Y, B is projected to the coordinates
+/// U = ±(A - B) (pointing right) and V = (A + B) / 2 - Y (pointing down),
+/// offset by [ISOMETRIC_BASE_HEIGHT]. Each block top is a diamond two units
+/// wide and one unit high, and each block side is one unit high.
+///
+/// Tile images have [ISOMETRIC_PIXELS_PER_UNIT] pixels per unit, so every
+/// face of a block covers several pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Projection {
+	/// Sign of the X axis in mirrored coordinates
+	sx: i32,
+	/// Sign of the Z axis in mirrored coordinates
+	sz: i32,
+}
+
+impl Projection {
+	/// Returns the projection for a viewing direction
+	fn new(direction: IsometricDirection) -> Self {
+		let (sx, sz) = match direction {
+			IsometricDirection::NorthEast => (1, -1),
+			IsometricDirection::SouthEast => (1, 1),
+			IsometricDirection::SouthWest => (-1, 1),
+			IsometricDirection::NorthWest => (-1, -1),
+		};
+		Projection { sx, sz }
+	}
+
+	/// Sign of the U coordinate relative to A - B
+	fn su(&self) -> i32 {
+		self.sx * self.sz
+	}
+
+	/// Returns the ranges of P = A - B and Q = A + B that the rays of the
+	/// pixels of a tile pass through, for blocks in the shown height range
+	fn tile_bounds(&self, tile: TileCoords) -> ((i32, i32), (i32, i32)) {
+		let (u0, u1) = (tile.x * TILE_UNITS, (tile.x + 1) * TILE_UNITS);
+		let (v0, v1) = (tile.z * TILE_UNITS, (tile.z + 1) * TILE_UNITS);
+
+		let p = if self.su() > 0 { (u0, u1) } else { (-u1, -u0) };
+		// Blocks are included if any part of them lies in the range
+		let q = (
+			2 * (v0 + ISOMETRIC_MIN_Y - ISOMETRIC_BASE_HEIGHT) - 2,
+			2 * (v1 + ISOMETRIC_MAX_Y + 1 - ISOMETRIC_BASE_HEIGHT),
+		);
+		(p, q)
+	}
+
+	/// Returns the center value of A - B and the lowest value of A + B of
+	/// the blocks of a region
+	///
+	/// The blocks of a region form a diamond in P = A - B and Q = A + B
+	/// coordinates, with a radius of [REGION_SIZE] in both directions.
+	fn region_bounds(&self, region: TileCoords) -> (i32, i32) {
+		let a0 = REGION_SIZE * mirror(self.sx, region.x);
+		let b0 = REGION_SIZE * mirror(self.sz, region.z);
+		(a0 - b0, a0 + b0)
+	}
+
+	/// Checks whether the rays of the pixels of a tile can pass through the
+	/// blocks of a region
+	fn intersects(&self, tile: TileCoords, region: TileCoords) -> bool {
+		let ((p0, p1), (q0, q1)) = self.tile_bounds(tile);
+		let (pc, q_min) = self.region_bounds(region);
+		let qc = q_min + REGION_SIZE;
+
+		let dist = |c: i32, lo: i32, hi: i32| (lo - c).max(c - hi).max(0);
+		dist(pc, p0, p1) + dist(qc, q0, q1) <= REGION_SIZE
+	}
+
+	/// Returns the tiles showing blocks of a region
+	fn region_tiles(&self, region: TileCoords) -> impl Iterator<Item = TileCoords> + '_ {
+		let (pc, q0) = self.region_bounds(region);
+		let (p0, p1) = (pc - REGION_SIZE, pc + REGION_SIZE);
+		let (u0, u1) = if self.su() > 0 { (p0, p1) } else { (-p1, -p0) };
+		let v0 = q0 / 2 - (ISOMETRIC_MAX_Y + 1 - ISOMETRIC_BASE_HEIGHT) - 1;
+		let v1 = q0 / 2 + REGION_SIZE - (ISOMETRIC_MIN_Y - ISOMETRIC_BASE_HEIGHT) + 1;
+
+		let xs = u0.div_euclid(TILE_UNITS)..=u1.div_euclid(TILE_UNITS);
+		let zs = v0.div_euclid(TILE_UNITS)..=v1.div_euclid(TILE_UNITS);
+		zs.flat_map(move |z| xs.clone().map(move |x| TileCoords { x, z }))
+			.filter(move |&tile| self.intersects(tile, region))
+	}
+
+	/// Returns the regions whose blocks can be shown on a tile
+	fn tile_regions(&self, tile: TileCoords) -> impl Iterator<Item = TileCoords> + '_ {
+		let ((p0, p1), (q0, q1)) = self.tile_bounds(tile);
+		let (a0, a1) = ((p0 + q0).div_euclid(2) - 1, (p1 + q1).div_euclid(2) + 1);
+		let (b0, b1) = ((q0 - p1).div_euclid(2) - 1, (q1 - p0).div_euclid(2) + 1);
+
+		let xs = a0.div_euclid(REGION_SIZE)..=a1.div_euclid(REGION_SIZE);
+		let zs = b0.div_euclid(REGION_SIZE)..=b1.div_euclid(REGION_SIZE);
+		zs.flat_map(move |b| xs.clone().map(move |a| (a, b)))
+			.map(|(a, b)| TileCoords {
+				x: mirror(self.sx, a),
+				z: mirror(self.sz, b),
+			})
+			.filter(move |&region| self.intersects(tile, region))
+	}
+}
+
+/// Height of the topmost visible block of each column of a chunk
+type ChunkTops = LayerBlockArray<Option<BlockHeight>>;
+
+/// Region data prepared for isometric rendering
+///
+/// Used as the value of the region cache, so each region file is only read
+/// and searched for visible blocks once for all tiles that show it.
+#[derive(Default)]
+struct RegionData {
+	/// Chunk data, without the parts not needed for rendering
+	chunks: ChunkArray<Option<Box<de::Chunk>>>,
+	/// Height of the topmost visible block of each column
+	///
+	/// Blocks above these heights are ignored.
+	tops: ChunkArray<Option<Box<ChunkTops>>>,
+	/// Range of block heights containing visible blocks
+	heights: Option<(i32, i32)>,
+}
+
+/// Removes the parts of chunk data that are not needed for rendering
+///
+/// Reduces the size of cached region data.
+fn strip_chunk(chunk: &mut de::Chunk) {
+	match &mut chunk.chunk {
+		de::ChunkVariant::V1_18 {
+			sections,
+			block_entities,
+			..
+		} => {
+			*block_entities = Vec::new();
+			for section in sections {
+				if let de::SectionV1_18Variant::V1_18 {
+					block_light,
+					sky_light,
+					..
+				} = &mut section.section
+				{
+					*block_light = None;
+					*sky_light = None;
+				}
+			}
+		}
+		de::ChunkVariant::V0 { level } => {
+			level.tile_entities = Vec::new();
+			for section in &mut level.sections {
+				section.block_light = None;
+				section.sky_light = None;
+			}
+		}
+	}
+}
+
+/// Region data used to render a tile
+///
+/// Chunks are decoded when a ray reaches them for the first time.
+struct TileRegion<'a> {
+	/// Prepared region data
+	data: &'a RegionData,
+	/// Decoded chunks
+	chunks: ChunkArray<cell::OnceCell<Option<Chunk<'a>>>>,
+}
+
+/// Returns the visible block at a block coordinate
+///
+/// Blocks above the top of their column and blocks that are not visible with
+/// the given [LayerOptions] are ignored.
+fn block_at<'a>(
+	regions: &'a FxHashMap<TileCoords, TileRegion<'a>>,
+	block_types: &'a BlockTypes,
+	biome_types: &'a BiomeTypes,
+	options: &LayerOptions,
+	x: i32,
+	y: i32,
+	z: i32,
+) -> Result<Option<(BlockColor, Option<&'a Biome>)>> {
+	let (region_x, offset_x) = x.shift_mask(BLOCK_BITS + CHUNK_BITS);
+	let (region_z, offset_z) = z.shift_mask(BLOCK_BITS + CHUNK_BITS);
+	let (_, chunk_x, block_x) = from_flat_coord(offset_x as i32);
+	let (_, chunk_z, block_z) = from_flat_coord(offset_z as i32);
+	let Some(region) = regions.get(&TileCoords {
+		x: region_x,
+		z: region_z,
+	}) else {
+		return Ok(None);
+	};
+	let chunk_coords = ChunkCoords {
+		x: chunk_x,
+		z: chunk_z,
+	};
+	let Some(tops) = &region.data.tops[chunk_coords] else {
+		return Ok(None);
+	};
+
+	let xz = LayerBlockCoords {
+		x: block_x,
+		z: block_z,
+	};
+	if tops[xz].filter(|top| top.0 >= y).is_none() {
+		return Ok(None);
+	}
+
+	let chunk = region.chunks[chunk_coords].get_or_init(|| {
+		let data = region.data.chunks[chunk_coords].as_ref()?;
+		// Chunks that fail to decode were dropped when the region was loaded
+		Chunk::new(data, block_types, biome_types)
+			.ok()
+			.map(|(chunk, _)| chunk)
+	});
+	let Some(chunk) = chunk else {
+		return Ok(None);
+	};
+
+	let (section_y, block_y) = y.shift_mask(BLOCK_BITS);
+	let Some(section) = chunk.section(SectionY(section_y)) else {
+		return Ok(None);
+	};
+	let coords = SectionBlockCoords {
+		xz,
+		y: BlockY::new(block_y),
+	};
+
+	let Some(block_type) = section
+		.section
+		.block_at(coords)?
+		.filter(|block_type| options.is_visible(block_type))
+	else {
+		return Ok(None);
+	};
+	let biome = section.biomes.biome_at(section.y, coords)?;
+
+	Ok(Some((
+		block_type.block_color,
+		biome.map(|biome_type| &biome_type.biome),
+	)))
+}
+
+/// Determines the color of a pixel of an isometric tile
+///
+/// Each pixel corresponds to a ray descending away from the viewer, moving
+/// by one block along both horizontal axes per block of height. In each
+/// layer of blocks, the ray passes the top face of a block and then the
+/// sides facing the viewer of two neighboring blocks, in an order depending
+/// on the position of the pixel. The first visible face determines the
+/// color.
+///
+/// *block_at* returns the visible block at an X, Y and Z coordinate. *x* and
+/// *y* are the pixel coordinates, with [ISOMETRIC_PIXELS_PER_UNIT] pixels per
+/// unit of the projected coordinates.
+fn trace<'b>(
+	block_at: impl Fn(i32, i32, i32) -> Result<Option<(BlockColor, Option<&'b Biome>)>>,
+	style: &ColorStyle,
+	projection: Projection,
+	(min_y, max_y): (i32, i32),
+	x: i32,
+	y: i32,
+) -> Result<Option<Colorf>> {
+	// Eightfold A and B coordinates of the ray at the top of the highest
+	// layer of blocks. As pixel centers are located at odd multiples of
+	// 1/4 units, these values are always odd, so the ray never passes the
+	// edges of blocks.
+	let p8 = projection.su() * (2 * x + 1);
+	let q8 = 4 * y + 2 + 8 * (max_y + 1 - ISOMETRIC_BASE_HEIGHT);
+	let (a8, b8) = (q8 + p8, q8 - p8);
+	let (mut a, mut b) = (a8.div_euclid(8), b8.div_euclid(8));
+
+	// The ray leaves the block in the direction it is closest to the border
+	// of first
+	let faces = if a8.rem_euclid(8) < b8.rem_euclid(8) {
+		[(0, 0, 1.0), (-1, 0, X_SHADE), (-1, -1, Z_SHADE)]
+	} else {
+		[(0, 0, 1.0), (0, -1, Z_SHADE), (-1, -1, X_SHADE)]
+	};
+
+	for layer in (min_y..=max_y).rev() {
+		for (da, db, shade) in faces {
+			let Some((block, biome)) = block_at(
+				mirror(projection.sx, a + da),
+				layer,
+				mirror(projection.sz, b + db),
+			)?
+			else {
+				continue;
+			};
+
+			if needs_biome(block) && biome.is_none() {
+				return Ok(None);
+			}
+
+			return Ok(Some(block_color(block, biome, layer as f32, style) * shade));
+		}
+
+		a -= 1;
+		b -= 1;
+	}
+
+	Ok(None)
+}
+
+/// The IsometricRenderer generates isometric map tiles from region data
+///
+/// Unlike the [TileRenderer](super::tile_renderer::TileRenderer), the
+/// IsometricRenderer needs the full block data of the regions, so it reads
+/// the region files directly instead of the processed region data.
+///
+/// The isometric map is rotated by 45 degrees relative to the regular map
+/// layers, so its tiles do not correspond to regions. Each tile shows
+/// blocks of several regions.
+pub struct IsometricRenderer<'a> {
+	/// Common MinedMap configuration from command line
+	config: &'a Config,
+	/// Runtime for asynchronous region loading
+	rt: &'a tokio::runtime::Runtime,
+	/// List of populated regions
+	regions: &'a [TileCoords],
+	/// Set of populated regions for fast existence checking
+	region_set: rustc_hash::FxHashSet<TileCoords>,
+	/// Projection for the viewing direction
+	projection: Projection,
+	/// Lookup table for Minecraft block types
+	block_types: BlockTypes,
+	/// Lookup table for Minecraft biome types
+	biome_types: BiomeTypes,
+	/// Cache of previously loaded regions
+	region_cache: Mutex<LruCache<TileCoords, Arc<OnceCell<Arc<RegionData>>>>>,
+}
+
+impl<'a> IsometricRenderer<'a> {
+	/// Constructs a new IsometricRenderer
+	pub fn new(
+		config: &'a Config,
+		rt: &'a tokio::runtime::Runtime,
+		regions: &'a [TileCoords],
+	) -> Self {
+		let direction = config
+			.isometric
+			.expect("isometric rendering must be enabled");
+
+		// Each tile shows blocks of up to 10 regions; tiles rendered in
+		// parallel are neighbors sharing most of their regions
+		let region_cache = Mutex::new(LruCache::new(
+			NonZeroUsize::new(10 + 3 * config.num_threads).unwrap(),
+		));
+		let region_set = regions.iter().copied().collect();
+		IsometricRenderer {
+			config,
+			rt,
+			regions,
+			region_set,
+			projection: Projection::new(direction),
+			block_types: config.overrides.block_types(),
+			biome_types: config.overrides.biome_types(),
+			region_cache,
+		}
+	}
+
+	/// Determines the height of the topmost visible block of each column of a
+	/// chunk
+	fn chunk_tops(&self, chunk: &de::Chunk) -> Result<(ChunkTops, Option<(i32, i32)>)> {
+		let options = &self.config.layers[0].options;
+
+		let (chunk, _) = Chunk::new(chunk, &self.block_types, &self.biome_types)
+			.context("Failed to decode chunk")?;
+		let tops = layer::column_tops(&chunk, options).context("Failed to process chunk")?;
+
+		let max_y = tops.0.iter().flatten().flatten().map(|top| top.0).max();
+		let min_y = chunk
+			.sections()
+			.next()
+			.map(|section| section.y.0 * BLOCKS_PER_CHUNK as i32);
+		let heights = min_y.zip(max_y).and_then(|(min_y, max_y)| {
+			let heights = (min_y.max(ISOMETRIC_MIN_Y), max_y.min(ISOMETRIC_MAX_Y));
+			(heights.0 <= heights.1).then_some(heights)
+		});
+
+		Ok((tops, heights))
+	}
+
+	/// Reads a region file and determines the visible blocks of its chunks
+	///
+	/// Like the [RegionProcessor](super::region_processor::RegionProcessor),
+	/// the renderer tolerates broken data: chunks that fail to decode are
+	/// skipped with a warning, and the chunks read before an error in the
+	/// region file are kept.
+	fn read_region(&self, path: &Path) -> RegionData {
+		let mut region = RegionData::default();
+
+		let result = crate::nbt::region::from_file(path).and_then(|file| {
+			file.foreach_chunk(|chunk_coords, mut chunk: de::Chunk| {
+				strip_chunk(&mut chunk);
+
+				let (tops, heights) = match self.chunk_tops(&chunk) {
+					Ok(value) => value,
+					Err(err) => {
+						warn!(
+							"Skipping chunk {:?} of region file {}: {:?}",
+							chunk_coords,
+							path.display(),
+							err,
+						);
+						return Ok(());
+					}
+				};
+
+				if let Some((min_y, max_y)) = heights {
+					region.heights = Some(match region.heights {
+						Some((prev_min, prev_max)) => (prev_min.min(min_y), prev_max.max(max_y)),
+						None => (min_y, max_y),
+					});
+				}
+				region.chunks[chunk_coords] = Some(Box::new(chunk));
+				region.tops[chunk_coords] = Some(Box::new(tops));
+
+				Ok(())
+			})
+		});
+
+		if let Err(err) = result {
+			warn!("Failed to read region file {}: {:?}", path.display(), err);
+		}
+
+		region
+	}
+
+	/// Loads the data of a region or returns previously loaded data from
+	/// the region cache
+	async fn load_region(&self, coords: TileCoords) -> Arc<RegionData> {
+		let region_loader = {
+			let mut region_cache = self.region_cache.lock().unwrap();
+			if let Some(region_loader) = region_cache.get(&coords) {
+				Arc::clone(region_loader)
+			} else {
+				let region_loader = Default::default();
+				region_cache.put(coords, Arc::clone(&region_loader));
+				region_loader
+			}
+		};
+
+		region_loader
+			.get_or_init(|| async { Arc::new(self.read_region(&self.config.region_path(coords))) })
+			.await
+			.clone()
+	}
+
+	/// Returns the list of isometric tiles showing blocks of the populated
+	/// regions, in row order
+	fn tiles(&self) -> Vec<TileCoords> {
+		let tiles: BTreeSet<_> = self
+			.regions
+			.iter()
+			.flat_map(|&region| self.projection.region_tiles(region))
+			.map(|TileCoords { x, z }| (z, x))
+			.collect();
+		tiles
+			.into_iter()
+			.map(|(z, x)| TileCoords { x, z })
+			.collect()
+	}
+
+	/// Returns the input regions needed to render a tile and the time of
+	/// last modification for any of their region files
+	fn sources(&self, coords: TileCoords) -> Result<(Vec<TileCoords>, Option<SystemTime>)> {
+		let sources: Vec<_> = self
+			.projection
+			.tile_regions(coords)
+			.filter(|region| self.region_set.contains(region))
+			.collect();
+
+		let mut max_timestamp = None;
+		for &region in &sources {
+			let timestamp = fs::modified_timestamp(&self.config.region_path(region))?;
+			max_timestamp = max_timestamp.max(Some(timestamp));
+		}
+
+		Ok((sources, max_timestamp))
+	}
+
+	/// Renders and saves an isometric tile image
+	fn render_tile(&self, coords: TileCoords) -> Result<bool> {
+		/// Width/height of a tile image
+		const N: u32 = TILE_SIZE as u32;
+
+		let (sources, input_timestamp) = self.sources(coords)?;
+		let input_timestamp = input_timestamp.context("Tile without populated regions")?;
+
+		let version = self.config.isometric_file_meta_version();
+		let output_path = self.config.tile_path(TileKind::Isometric, 0, coords);
+		let output_timestamp = fs::read_timestamp(&output_path, version);
+
+		if Some(input_timestamp) <= output_timestamp {
+			debug!(
+				"Skipping unchanged isometric tile {}",
+				output_path
+					.strip_prefix(&self.config.output_dir)
+					.expect("tile path must be in output directory")
+					.display(),
+			);
+			return Ok(false);
+		}
+
+		debug!(
+			"Rendering isometric tile {}",
+			output_path
+				.strip_prefix(&self.config.output_dir)
+				.expect("tile path must be in output directory")
+				.display(),
+		);
+
+		let data = self.rt.block_on(async {
+			let mut data = Vec::with_capacity(sources.len());
+			for region in sources {
+				data.push((region, self.load_region(region).await));
+			}
+			data
+		});
+		let regions: FxHashMap<_, _> = data
+			.iter()
+			.map(|(coords, data)| {
+				(
+					*coords,
+					TileRegion {
+						data,
+						chunks: Default::default(),
+					},
+				)
+			})
+			.collect();
+
+		let mut image = image::RgbaImage::new(N, N);
+
+		// An empty tile is kept for tiles without visible blocks, as the
+		// mipmapper expects all listed tiles to exist
+		if let Some(heights) = data
+			.iter()
+			.filter_map(|(_, region)| region.heights)
+			.reduce(|(min_a, max_a), (min_b, max_b)| (min_a.min(min_b), max_a.max(max_b)))
+		{
+			let options = &self.config.layers[0].options;
+			let (x0, y0) = (coords.x * TILE_SIZE, coords.z * TILE_SIZE);
+
+			for (x, y, pixel) in image.enumerate_pixels_mut() {
+				let color = trace(
+					|x, y, z| {
+						block_at(
+							&regions,
+							&self.block_types,
+							&self.biome_types,
+							options,
+							x,
+							y,
+							z,
+						)
+					},
+					&self.config.color_style,
+					self.projection,
+					heights,
+					x0 + x as i32,
+					y0 + y as i32,
+				)?;
+				if let Some(color) = color {
+					*pixel = image::Rgba([color[0] as u8, color[1] as u8, color[2] as u8, 255]);
+				}
+			}
+		}
+
+		fs::create_with_timestamp(&output_path, version, input_timestamp, |file| {
+			image
+				.write_to(file, image::ImageFormat::Png)
+				.context("Failed to save image")
+		})?;
+
+		Ok(true)
+	}
+
+	/// Runs the isometric tile generation
+	///
+	/// Tiles that fail to render are skipped with a warning. Returns the
+	/// list of available tiles for mipmap generation.
+	pub fn run(self) -> Result<Vec<TileCoords>> {
+		fs::create_dir_all(&self.config.tile_dir(TileKind::Isometric, 0))?;
+
+		info!("Rendering isometric tiles...");
+
+		// Use par_bridge to process items in order (for better use of region cache)
+		let results: Vec<_> = self
+			.tiles()
+			.into_iter()
+			.par_bridge()
+			.map(|coords| match self.render_tile(coords) {
+				Ok(processed) => (coords, Some(processed)),
+				Err(err) => {
+					warn!("Failed to render isometric tile {:?}: {:?}", coords, err);
+					(coords, None)
+				}
+			})
+			.collect();
+
+		let processed = results
+			.iter()
+			.filter(|(_, result)| *result == Some(true))
+			.count();
+		let tiles: Vec<_> = results
+			.iter()
+			.filter(|(_, result)| result.is_some())
+			.map(|&(coords, _)| coords)
+			.collect();
+
+		info!(
+			"Rendered isometric tiles ({} processed, {} unchanged, {} errors)",
+			processed,
+			tiles.len() - processed,
+			results.len() - tiles.len(),
+		);
+
+		Ok(tiles)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::resource::{BlockFlag, Color};
+
+	/// Returns an opaque block of the given color
+	fn block(color: [u8; 3]) -> BlockColor {
+		BlockColor {
+			flags: BlockFlag::Opaque.into(),
+			color: Color(color),
+			alpha: 255,
+		}
+	}
+
+	/// Returns the projected coordinates of the center of a block's top face
+	fn project(projection: Projection, x: i32, y: i32, z: i32) -> (f32, f32) {
+		let a = mirror(projection.sx, x) as f32 + 0.5;
+		let b = mirror(projection.sz, z) as f32 + 0.5;
+		let u = projection.su() as f32 * (a - b);
+		let v = (a + b) / 2.0 - (y + 1 - ISOMETRIC_BASE_HEIGHT) as f32;
+		(u, v)
+	}
+
+	/// Block coordinates and color of a block of a test world
+	type TestBlock = ((i32, i32, i32), [u8; 3]);
+
+	/// Traces the pixels around a block's projection
+	///
+	/// The world contains the given blocks. Returns the non-empty pixels.
+	fn trace_area(
+		direction: IsometricDirection,
+		blocks: &[TestBlock],
+		(x, y, z): (i32, i32, i32),
+	) -> Vec<((i32, i32), Colorf)> {
+		let block_at = |bx, by, bz| {
+			Ok(blocks
+				.iter()
+				.find(|(coords, _)| *coords == (bx, by, bz))
+				.map(|&(_, color)| (block(color), None)))
+		};
+		let style = ColorStyle::default();
+		let projection = Projection::new(direction);
+		let (u, v) = project(projection, x, y, z);
+		let scale = ISOMETRIC_PIXELS_PER_UNIT as f32;
+		let (u, v) = ((u * scale) as i32, (v * scale) as i32);
+
+		(v - 8..v + 8)
+			.flat_map(|v| (u - 8..u + 8).map(move |u| (u, v)))
+			.filter_map(|(u, v)| {
+				let color = trace(block_at, &style, projection, (0, 100), u, v).unwrap()?;
+				Some(((u, v), color))
+			})
+			.collect()
+	}
+
+	#[test]
+	fn test_trace() {
+		let style = ColorStyle::default();
+		let top = block_color(block([125; 3]), None, 70.0, &style);
+
+		for direction in [
+			IsometricDirection::NorthEast,
+			IsometricDirection::SouthEast,
+			IsometricDirection::SouthWest,
+			IsometricDirection::NorthWest,
+		] {
+			let pixels = trace_area(direction, &[((10, 70, 10), [125; 3])], (10, 70, 10));
+			let find = |color| {
+				pixels
+					.iter()
+					.filter(|(_, c)| *c == color)
+					.map(|&(pos, _)| pos)
+					.collect::<Vec<_>>()
+			};
+
+			// A block is drawn as its top face and the two sides facing the
+			// viewer below it
+			let tops = find(top);
+			let x_sides = find(top * X_SHADE);
+			let z_sides = find(top * Z_SHADE);
+			assert_eq!(
+				tops.len() + x_sides.len() + z_sides.len(),
+				pixels.len(),
+				"{:?}",
+				direction,
+			);
+			assert_eq!(tops.len(), 4, "{:?}", direction);
+			assert_eq!(x_sides.len(), 4, "{:?}", direction);
+			assert_eq!(z_sides.len(), 4, "{:?}", direction);
+
+			let top_v = tops.iter().map(|&(_, v)| v).min().unwrap();
+			assert!(x_sides.iter().all(|&(_, v)| v > top_v), "{:?}", direction);
+			assert!(z_sides.iter().all(|&(_, v)| v > top_v), "{:?}", direction);
+
+			// East-facing sides are shown on the left when looking from
+			// the north-east
+			let x_u = x_sides.iter().map(|&(u, _)| u).max().unwrap();
+			let z_u = z_sides.iter().map(|&(u, _)| u).min().unwrap();
+			let x_left = matches!(
+				direction,
+				IsometricDirection::NorthEast | IsometricDirection::SouthWest
+			);
+			assert_eq!(x_u < z_u, x_left, "{:?}", direction);
+		}
+	}
+
+	#[test]
+	fn test_trace_hidden() {
+		let style = ColorStyle::default();
+		let upper = block_color(block([255, 0, 0]), None, 70.0, &style);
+		let lower = block_color(block([0, 255, 0]), None, 69.0, &style);
+
+		let pixels = trace_area(
+			IsometricDirection::SouthEast,
+			&[((0, 70, 0), [255, 0, 0]), ((0, 69, 0), [0, 255, 0])],
+			(0, 70, 0),
+		);
+		let colors: Vec<_> = pixels.iter().map(|&(_, color)| color).collect();
+
+		// The upper block hides the top of the lower block, but not its sides
+		assert!(colors.contains(&upper));
+		assert!(!colors.contains(&lower));
+		assert!(colors.contains(&(lower * X_SHADE)));
+		assert!(colors.contains(&(lower * Z_SHADE)));
+	}
+
+	#[test]
+	fn test_tiles_regions() {
+		const N: i32 = REGION_SIZE;
+
+		for direction in [
+			IsometricDirection::NorthEast,
+			IsometricDirection::SouthEast,
+			IsometricDirection::SouthWest,
+			IsometricDirection::NorthWest,
+		] {
+			let projection = Projection::new(direction);
+
+			for region in [
+				TileCoords { x: 0, z: 0 },
+				TileCoords { x: -1, z: 2 },
+				TileCoords { x: 3, z: -5 },
+			] {
+				let tiles: Vec<_> = projection.region_tiles(region).collect();
+
+				// Each tile showing the region lists the region as a source
+				for &tile in &tiles {
+					assert!(
+						projection.tile_regions(tile).any(|r| r == region),
+						"{:?} {:?} {:?}",
+						direction,
+						region,
+						tile,
+					);
+				}
+
+				// The corner blocks of the region are shown on its tiles
+				for (x, z) in [(0, 0), (N - 1, 0), (0, N - 1), (N - 1, N - 1)] {
+					for y in [ISOMETRIC_MIN_Y, ISOMETRIC_MAX_Y] {
+						let (u, v) = project(projection, region.x * N + x, y, region.z * N + z);
+						let tile = TileCoords {
+							x: (u.floor() as i32).div_euclid(TILE_UNITS),
+							z: (v.floor() as i32).div_euclid(TILE_UNITS),
+						};
+						assert!(
+							tiles.contains(&tile),
+							"{:?} {:?} {:?}",
+							direction,
+							region,
+							tile,
+						);
+					}
+				}
+			}
+		}
+	}
+}
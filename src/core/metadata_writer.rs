@@ -1,12 +1,19 @@
 //! The [MetadataWriter] and related types
 
+use std::{collections::BTreeMap, path::Path};
+
 use anyhow::{Context, Result};
 use regex::Regex;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{
-	core::common::*,
+	core::{
+		common::*,
+		dimension::Dimension,
+		isometric_renderer::{ISOMETRIC_BASE_HEIGHT, ISOMETRIC_PIXELS_PER_UNIT},
+	},
 	io::{fs, storage},
+	resource::biome_color,
 	world::{
 		block_entity::{self, BlockEntity, BlockEntityData},
 		de, sign,
@@ -45,11 +52,59 @@ struct Spawn {
 	z: i32,
 }
 
+/// Map layer information in viewer metadata file
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Layer<'t> {
+	/// Layer identifier, used as the name of the tile directory
+	id: &'t str,
+	/// User-defined name of the layer
+	name: Option<&'t str>,
+	/// Maximum Y coordinate of the blocks shown on the layer
+	max_y: Option<i32>,
+}
+
 /// Keeps track of enabled MinedMap features
 #[derive(Debug, Serialize)]
 struct Features {
 	/// Sign layer
 	signs: bool,
+	/// Relief shading overlay layer
+	hillshade: bool,
+	/// Contour line overlay layer
+	contours: bool,
+	/// Heightmap layer
+	heightmap: bool,
+	/// Biome map layer
+	biomes: bool,
+	/// Night view map layer
+	night: bool,
+	/// Vanilla map item layer
+	vanilla_map: bool,
+	/// Column data tiles
+	columns: bool,
+	/// Isometric map layer
+	isometric: bool,
+	/// Texture zoom levels
+	textures: bool,
+}
+
+/// Isometric map layer information in viewer metadata file
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Isometric<'t> {
+	/// Corner from which the isometric view looks at the world
+	direction: crate::core::IsometricDirection,
+	/// Height of the blocks whose top faces are drawn without vertical offset
+	base_y: i32,
+	/// Number of tile image pixels per map unit
+	///
+	/// The top face of a block is two map units wide.
+	scale: i32,
+	/// Tile information for each mipmap level
+	///
+	/// The isometric map layer uses its own tile grid.
+	mipmaps: Vec<Mipmap<'t>>,
 }
 
 /// Viewer metadata JSON data structure
@@ -57,10 +112,32 @@ struct Features {
 struct Metadata<'t> {
 	/// Tile information for each mipmap level
 	mipmaps: Vec<Mipmap<'t>>,
+	/// Rendered map layers, starting with the main map layer
+	///
+	/// Color-graded layers follow the layers with processed region data.
+	layers: Vec<Layer<'t>>,
 	/// Initial spawn point for new players
 	spawn: Spawn,
 	/// Enabled MinedMap features
 	features: Features,
+	/// Legend of the biome map layer
+	///
+	/// Maps biome IDs to their colors in `#rrggbb` notation.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	biomes: Option<BTreeMap<String, String>>,
+	/// Isometric map layer
+	#[serde(skip_serializing_if = "Option::is_none")]
+	isometric: Option<Isometric<'t>>,
+}
+
+/// Toplevel viewer metadata JSON data structure
+///
+/// Lists the rendered dimensions. The metadata of each dimension is stored
+/// in a separate [Metadata] file in the dimension's output directory.
+#[derive(Debug, Serialize, Deserialize)]
+struct DimensionIndex {
+	/// Rendered dimensions
+	dimensions: Vec<Dimension>,
 }
 
 /// Viewer entity JSON data structure
@@ -76,16 +153,26 @@ pub struct MetadataWriter<'a> {
 	config: &'a Config,
 	/// Map of generated tiles for each mipmap level
 	tiles: &'a [TileCoordMap],
+	/// Map of generated isometric tiles for each mipmap level
+	isometric_tiles: Option<&'a [TileCoordMap]>,
 }
 
 impl<'a> MetadataWriter<'a> {
 	/// Creates a new MetadataWriter
-	pub fn new(config: &'a Config, tiles: &'a [TileCoordMap]) -> Self {
-		MetadataWriter { config, tiles }
+	pub fn new(
+		config: &'a Config,
+		tiles: &'a [TileCoordMap],
+		isometric_tiles: Option<&'a [TileCoordMap]>,
+	) -> Self {
+		MetadataWriter {
+			config,
+			tiles,
+			isometric_tiles,
+		}
 	}
 
 	/// Helper to construct a [Mipmap] data structure from a [TileCoordMap]
-	fn mipmap_entry(regions: &TileCoordMap) -> Mipmap {
+	fn mipmap_entry(regions: &TileCoordMap) -> Mipmap<'_> {
 		let mut min_x = i32::MAX;
 		let mut max_x = i32::MIN;
 		let mut min_z = i32::MAX;
@@ -133,6 +220,19 @@ impl<'a> MetadataWriter<'a> {
 		}
 	}
 
+	/// Determines the initial view position for the rendered dimension
+	///
+	/// The spawn point stored in `level.dat` only applies to the Overworld;
+	/// the view is centered at the origin for other dimensions.
+	fn dimension_spawn(&self) -> Result<Spawn> {
+		if !self.config.dimension.is_overworld() {
+			return Ok(Spawn { x: 0, z: 0 });
+		}
+
+		let level_dat = self.read_level_dat()?;
+		Ok(Self::spawn(&level_dat))
+	}
+
 	/// Filter signs according to the sign pattern configuration
 	fn sign_filter(&self, sign: &block_entity::Sign) -> bool {
 		let front_text = sign.front_text.to_string();
@@ -193,19 +293,64 @@ impl<'a> MetadataWriter<'a> {
 		Ok(ret)
 	}
 
+	/// Generates the legend of the biome map layer
+	fn biome_legend(&self) -> BTreeMap<String, String> {
+		self.config
+			.overrides
+			.biome_types()
+			.iter()
+			.map(|biome| {
+				let [r, g, b] = biome_color(&biome.id).0;
+				(biome.id.clone(), format!("#{:02x}{:02x}{:02x}", r, g, b))
+			})
+			.collect()
+	}
+
 	/// Runs the viewer metadata file generation
 	pub fn run(self) -> Result<()> {
-		let level_dat = self.read_level_dat()?;
-
 		let features = Features {
 			signs: !self.config.sign_patterns.is_empty(),
+			hillshade: self.config.overlay_hillshade().is_some(),
+			contours: self.config.contours.is_some(),
+			heightmap: self.config.heightmap,
+			biomes: self.config.biome_layer,
+			night: self.config.night,
+			vanilla_map: self.config.vanilla_map,
+			columns: self.config.column_data.is_some(),
+			isometric: self.config.isometric.is_some(),
+			textures: !self.config.texture_packs.is_empty(),
 		};
 
-		let mut metadata = Metadata {
-			mipmaps: Vec::new(),
-			spawn: Self::spawn(&level_dat),
-			features,
-		};
+		let mut metadata =
+			Metadata {
+				mipmaps: Vec::new(),
+				layers: self
+					.config
+					.layers
+					.iter()
+					.map(|layer| Layer {
+						id: &layer.id,
+						name: layer.name.as_deref(),
+						max_y: layer.options.max_y,
+					})
+					.chain(self.config.themes.iter().map(|theme| Layer {
+						id: &theme.id,
+						name: Some(&theme.name),
+						max_y: self.config.layers[0].options.max_y,
+					}))
+					.collect(),
+				spawn: self.dimension_spawn()?,
+				features,
+				biomes: self.config.biome_layer.then(|| self.biome_legend()),
+				isometric: self.config.isometric.zip(self.isometric_tiles).map(
+					|(direction, tiles)| Isometric {
+						direction,
+						base_y: ISOMETRIC_BASE_HEIGHT,
+						scale: ISOMETRIC_PIXELS_PER_UNIT,
+						mipmaps: tiles.iter().map(Self::mipmap_entry).collect(),
+					},
+				),
+			};
 
 		for tile_map in self.tiles.iter() {
 			metadata.mipmaps.push(Self::mipmap_entry(tile_map));
@@ -215,6 +360,12 @@ impl<'a> MetadataWriter<'a> {
 			serde_json::to_writer(file, &metadata).context("Failed to write info.json")
 		})?;
 
+		if let Some(dictionary) = &self.config.column_data {
+			fs::create_with_tmpfile(&self.config.viewer_ids_path, |file| {
+				serde_json::to_writer(file, dictionary).context("Failed to write ids.json")
+			})?;
+		}
+
 		let entities = self.entities()?;
 		fs::create_with_tmpfile(&self.config.viewer_entities_path, |file| {
 			serde_json::to_writer(file, &entities).context("Failed to write entities.json")
@@ -223,3 +374,100 @@ impl<'a> MetadataWriter<'a> {
 		Ok(())
 	}
 }
+
+/// Merges the dimensions of an existing index with the dimensions rendered in this run
+///
+/// Existing entries keep their position and are replaced by the rendered
+/// dimension with the same ID. Existing entries of other dimensions are
+/// kept if *exists* returns true for them. Newly rendered dimensions are
+/// appended.
+fn merge_dimensions(
+	existing: Vec<Dimension>,
+	rendered: &[Dimension],
+	exists: impl Fn(&Dimension) -> bool,
+) -> Vec<Dimension> {
+	let mut dimensions: Vec<_> = existing
+		.into_iter()
+		.filter_map(
+			|dimension| match rendered.iter().find(|other| other.id == dimension.id) {
+				Some(other) => Some(other.clone()),
+				None => exists(&dimension).then_some(dimension),
+			},
+		)
+		.collect();
+
+	for dimension in rendered {
+		if !dimensions.iter().any(|other| other.id == dimension.id) {
+			dimensions.push(dimension.clone());
+		}
+	}
+
+	dimensions
+}
+
+/// Writes the toplevel viewer metadata file listing all rendered dimensions
+///
+/// Dimensions rendered in previous runs are kept in the list as long as
+/// their metadata file exists, so rendering a subset of the dimensions of
+/// a save does not remove the others from the viewer.
+pub fn write_dimension_index(output_dir: &Path, dimensions: &[Dimension]) -> Result<()> {
+	let path = output_dir.join("info.json");
+
+	// An unreadable index (for example from an older MinedMap version) is replaced
+	let existing = std::fs::read(&path)
+		.ok()
+		.and_then(|data| serde_json::from_slice::<DimensionIndex>(&data).ok())
+		.map(|index| index.dimensions)
+		.unwrap_or_default();
+	let dimensions = merge_dimensions(existing, dimensions, |dimension| {
+		output_dir.join(&dimension.path).join("info.json").is_file()
+	});
+	let index = DimensionIndex { dimensions };
+
+	fs::create_with_tmpfile(&path, |file| {
+		serde_json::to_writer(file, &index).context("Failed to write info.json")
+	})
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	/// Constructs a [Dimension] for tests
+	fn dimension(id: &str) -> Dimension {
+		Dimension {
+			id: id.to_string(),
+			path: id.replace(':', "/"),
+			region_dir: Default::default(),
+		}
+	}
+
+	/// Returns the IDs of a list of dimensions
+	fn ids(dimensions: &[Dimension]) -> Vec<&str> {
+		dimensions
+			.iter()
+			.map(|dimension| dimension.id.as_str())
+			.collect()
+	}
+
+	#[test]
+	fn test_merge_dimensions() {
+		let existing = vec![
+			dimension("minecraft:overworld"),
+			dimension("minecraft:the_nether"),
+			dimension("minecraft:the_end"),
+		];
+		let rendered = [dimension("minecraft:the_nether"), dimension("foo:bar")];
+
+		let merged = merge_dimensions(existing, &rendered, |dimension| {
+			dimension.id != "minecraft:the_end"
+		});
+		assert_eq!(
+			ids(&merged),
+			["minecraft:overworld", "minecraft:the_nether", "foo:bar"]
+		);
+
+		let merged = merge_dimensions(Vec::new(), &rendered, |_| true);
+		assert_eq!(ids(&merged), ["minecraft:the_nether", "foo:bar"]);
+	}
+}
@@ -1,10 +1,15 @@
 //! Core functions of the MinedMap CLI
 
+mod block_textures;
 mod common;
+mod dimension;
 mod entity_collector;
+mod isometric_renderer;
 mod metadata_writer;
+mod overrides;
 mod region_group;
 mod region_processor;
+mod theme;
 mod tile_collector;
 mod tile_merger;
 mod tile_mipmapper;
@@ -13,11 +18,17 @@ mod tile_renderer;
 use std::path::PathBuf;
 
 use anyhow::{Context, Result};
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use git_version::git_version;
+use serde::Serialize;
+use tracing::info;
 
+use block_textures::BlockTextures;
 use common::Config;
+use dimension::Dimension;
+use isometric_renderer::IsometricRenderer;
 use metadata_writer::MetadataWriter;
+use overrides::Overrides;
 use region_processor::RegionProcessor;
 use tile_mipmapper::TileMipmapper;
 use tile_renderer::TileRenderer;
@@ -30,6 +41,41 @@ const VERSION: &str = git_version!(
 	cargo_prefix = "v",
 );
 
+/// Handling of solid level ceilings
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum CeilingMode {
+	/// Skip the ceiling of dimensions known to have one (the Nether)
+	Auto,
+	/// Skip the topmost solid blocks in all dimensions
+	Skip,
+	/// Always render the topmost blocks
+	Render,
+}
+
+/// Output mode of relief shading
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HillshadeMode {
+	/// Generate a separate shading layer
+	Overlay,
+	/// Apply the shading to the map tiles
+	Baked,
+}
+
+/// Corner from which the isometric view looks at the world
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum IsometricDirection {
+	/// Look from the north-east, showing north- and east-facing sides
+	NorthEast,
+	/// Look from the south-east, showing south- and east-facing sides
+	SouthEast,
+	/// Look from the south-west, showing south- and west-facing sides
+	SouthWest,
+	/// Look from the north-west, showing north- and west-facing sides
+	NorthWest,
+}
+
 /// Command line arguments for minedmap CLI
 #[derive(Debug, Parser)]
 #[command(
@@ -65,6 +111,197 @@ pub struct Args {
 	/// are applied to each line of sign texts separately.
 	#[arg(long)]
 	pub sign_transform: Vec<String>,
+	/// Dimension to render
+	///
+	/// Accepts vanilla dimension IDs (overworld, the_nether, the_end) as well
+	/// as namespaced IDs of datapack dimensions. May be passed multiple times.
+	/// Pass 'all' to render all dimensions found in the save directory. By
+	/// default, only the Overworld is rendered.
+	#[arg(long)]
+	pub dimension: Vec<String>,
+	/// Handling of solid ceilings like the bedrock roof of the Nether
+	///
+	/// When the ceiling is skipped, the first floor below a gap under the
+	/// ceiling is rendered instead.
+	#[arg(long, value_enum, default_value_t = CeilingMode::Auto)]
+	pub ceiling: CeilingMode,
+	/// Maximum Y coordinate of blocks shown on the main map layer
+	///
+	/// Blocks above the given height are ignored, making the blocks below
+	/// visible.
+	#[arg(long, allow_hyphen_values = true)]
+	pub max_y: Option<i32>,
+	/// Generate an additional map layer of the blocks below a Y coordinate
+	///
+	/// Accepts single Y coordinates or ranges of the form FROM..TO/STEP
+	/// (like '-48..64/16') to generate multiple slice layers. The step
+	/// defaults to 16. May be passed multiple times.
+	#[arg(long, allow_hyphen_values = true)]
+	pub slice: Vec<String>,
+	/// Generate an additional map layer treating the given blocks as transparent
+	///
+	/// The value has the form NAME=RULE[,RULE...], where each rule is a
+	/// block ID (like 'oak_leaves') or one of the tags '#leaves', '#water',
+	/// '#glass' and '#snow'. For example, 'no-trees=#leaves' renders the map
+	/// without tree canopies. May be passed multiple times.
+	#[arg(long)]
+	pub transparent_layer: Vec<String>,
+	/// Blend translucent blocks like water, ice and stained glass with the blocks below
+	#[arg(long)]
+	pub translucent: bool,
+	/// Enable relief shading based on the height differences of neighboring blocks
+	///
+	/// 'overlay' generates a separate shading layer that can be toggled in
+	/// the viewer, 'baked' applies the shading to all map layers.
+	#[arg(long, value_enum)]
+	pub hillshade: Option<HillshadeMode>,
+	/// Direction of the light source for relief shading
+	///
+	/// Given in degrees clockwise from north.
+	#[arg(long, default_value_t = 315.0, allow_hyphen_values = true)]
+	pub light_azimuth: f32,
+	/// Angle of the light source above the horizon for relief shading
+	///
+	/// Given in degrees; must be greater than 0 and at most 90.
+	#[arg(long, default_value_t = 45.0)]
+	pub light_altitude: f32,
+	/// Brightness of blocks depending on their height
+	///
+	/// Accepts a list of control points of the form HEIGHT:FACTOR[,...]
+	/// (like '-64:0.4,64:0.8,320:1.4'); the brightness factor is
+	/// interpolated between the points. A single FACTOR without height
+	/// sets a constant brightness, for example '1' for unshaded colors. By
+	/// default, the brightness increases linearly with the height.
+	#[arg(long, value_name = "CURVE", allow_hyphen_values = true)]
+	pub brightness: Option<String>,
+	/// Brightness of water depending on the height of the ground below it
+	///
+	/// Uses the same format as --brightness, which is also used as the
+	/// default.
+	#[arg(long, value_name = "CURVE", allow_hyphen_values = true)]
+	pub water_brightness: Option<String>,
+	/// Tint blocks with a color ramp depending on their height
+	///
+	/// Accepts a list of control points of the form HEIGHT:RRGGBB[,...]
+	/// (like '0:2d6e2d,80:c8b464,160:8c5a32,240:ffffff'). Water is not
+	/// tinted.
+	#[arg(long, value_name = "RAMP", allow_hyphen_values = true)]
+	pub height_tint: Option<String>,
+	/// Strength of the height tint, from 0 (none) to 1 (replaces the block colors)
+	#[arg(long, value_name = "STRENGTH", default_value_t = 0.5)]
+	pub height_tint_strength: f32,
+	/// Do not desaturate grass and foliage colors at high altitudes
+	#[arg(long)]
+	pub no_height_temperature: bool,
+	/// Blending of grass, foliage and water colors at biome borders
+	///
+	/// 'smooth' blends over a small weighted 5x5 area, 'vanilla' blends
+	/// over a 15x15 area like Minecraft, and 'none' shows sharp biome
+	/// borders. A number N blends over a (2N+1)x(2N+1) area (up to 16).
+	#[arg(long, value_name = "BLEND", default_value = "smooth")]
+	pub biome_blend: String,
+	/// Generate heightmap tiles
+	///
+	/// In addition to a color-ramped visualization, the heights are stored
+	/// losslessly in 16-bit grayscale PNG files for use by external tools.
+	#[arg(long)]
+	pub heightmap: bool,
+	/// Generate a contour line overlay with the given height interval in blocks
+	///
+	/// Contour lines are derived from the heights of the topmost opaque
+	/// blocks, so they follow the ocean floor below water.
+	#[arg(long, value_name = "INTERVAL", value_parser = clap::value_parser!(u32).range(1..))]
+	pub contours: Option<u32>,
+	/// Draw every Nth contour line as a major contour line
+	///
+	/// Major contour lines are drawn thicker and darker than the other
+	/// lines. Pass 0 to draw minor lines only.
+	#[arg(long, value_name = "N", default_value_t = 5)]
+	pub contour_major: u32,
+	/// Generate a biome map layer
+	///
+	/// Each biome is shown in a distinct color. A legend listing the colors
+	/// of all biomes is included in the viewer metadata.
+	#[arg(long)]
+	pub biome_layer: bool,
+	/// Generate a night view map layer
+	///
+	/// The layer combines the block colors with the stored block light and
+	/// sky light, showing the world as it looks at night: areas only lit by
+	/// the sky are dark, while areas lit by torches and other light sources
+	/// glow.
+	#[arg(long)]
+	pub night: bool,
+	/// Generate a map layer in the style of Minecraft map items
+	///
+	/// Blocks are drawn using the color palette of in-game maps, shaded
+	/// depending on the height of the block to their north.
+	#[arg(long)]
+	pub vanilla_map: bool,
+	/// Generate an additional map layer with a color grading theme
+	///
+	/// Accepts one of the built-in themes 'sepia', 'high-contrast' and
+	/// 'colorblind', or NAME=PATH to load a theme from a file: files with the
+	/// extension '.cube' are read as 3D lookup tables, other files as
+	/// palettes listing one RRGGBB color per line, replacing each color with
+	/// the nearest palette color. May be passed multiple times.
+	#[arg(long, value_name = "THEME")]
+	pub theme: Vec<String>,
+	/// Generate column data tiles
+	///
+	/// The tiles store the top block ID, biome ID and height of each column
+	/// as 16-bit RGB PNG files. The IDs refer to a dictionary written to
+	/// ids.json. The viewer uses the data to show the block and biome under
+	/// the cursor.
+	#[arg(long)]
+	pub column_data: bool,
+	/// Generate an isometric map layer viewed from the given corner
+	///
+	/// The layer shows the world from a diagonal direction, with the two
+	/// sides of blocks facing the viewer drawn in addition to their tops.
+	/// As the map is rotated by 45 degrees, it uses its own tile grid.
+	#[arg(long, value_enum)]
+	pub isometric: Option<IsometricDirection>,
+	/// Generate zoom levels showing block textures from a resource pack
+	///
+	/// Accepts a Minecraft client JAR file, a resource pack ZIP archive or
+	/// an unpacked resource pack directory. May be passed multiple times;
+	/// later resource packs take precedence, so the vanilla JAR should be
+	/// passed first when it is combined with other resource packs.
+	#[arg(long, value_name = "PATH")]
+	pub textures: Vec<PathBuf>,
+	/// Derive block colors from the textures of a resource pack
+	///
+	/// Accepts a Minecraft client JAR file, a resource pack ZIP archive or
+	/// an unpacked resource pack directory. The colors of the built-in block
+	/// types are replaced with the average colors of their top face textures.
+	/// May be passed multiple times; later resource packs take precedence.
+	#[arg(long, value_name = "PATH")]
+	pub color_pack: Vec<PathBuf>,
+	/// Load additional block and biome types from a JSON file
+	///
+	/// The file may add block and biome types of any namespace (for example
+	/// for modded worlds) or replace built-in ones. May be passed multiple
+	/// times; later files take precedence. Block types of override files take
+	/// precedence over block colors derived from resource packs.
+	#[arg(long, value_name = "PATH")]
+	pub overrides: Vec<PathBuf>,
+	/// Write the unknown block and biome IDs found during processing to unknown.json
+	///
+	/// The report lists the number of blocks with each ID, example block
+	/// coordinates and the fallback block type used for
+	/// rendering (if any). A separate report is written for each dimension.
+	/// Only chunks processed in the current run are covered.
+	#[arg(long)]
+	pub unknown_report: bool,
+	/// Fail if unknown block or biome IDs are found during processing
+	///
+	/// Unknown block types rendered using a fallback block type also cause
+	/// the run to fail. The regions of all dimensions are checked before any
+	/// map tiles are written, and regions with unknown IDs are not saved, so
+	/// they are checked again in subsequent runs.
+	#[arg(long)]
+	pub strict: bool,
 	/// Minecraft save directory
 	pub input_dir: PathBuf,
 	/// MinedMap data directory
@@ -82,7 +319,6 @@ fn setup_threads(num_threads: usize) -> Result<()> {
 /// MinedMap CLI main function
 pub fn cli() -> Result<()> {
 	let args = Args::parse();
-	let config = Config::new(&args)?;
 
 	tracing_subscriber::fmt()
 		.with_max_level(if args.verbose {
@@ -93,17 +329,51 @@ pub fn cli() -> Result<()> {
 		.with_target(false)
 		.init();
 
-	setup_threads(config.num_threads)?;
+	let dimensions = Dimension::from_args(&args)?;
+	let textures = BlockTextures::open(&args.textures)?;
+	let datapack_dir = args.input_dir.join("datapacks");
+	let overrides = Overrides::load(&datapack_dir, &args.color_pack, &args.overrides)
+		.context("Failed to load block and biome types")?;
+
+	setup_threads(Config::num_threads(&args))?;
 
 	let rt = tokio::runtime::Builder::new_current_thread()
 		.build()
 		.unwrap();
 
-	let regions = RegionProcessor::new(&config).run()?;
-	TileRenderer::new(&config, &rt, &regions).run()?;
-	let tiles = TileMipmapper::new(&config, &regions).run()?;
-	EntityCollector::new(&config, &regions).run()?;
-	MetadataWriter::new(&config, &tiles).run()?;
+	let configs = dimensions
+		.iter()
+		.map(|dimension| Config::new(&args, dimension, &overrides))
+		.collect::<Result<Vec<_>>>()?;
+
+	// The regions of all dimensions are processed before any tiles are
+	// rendered, so strict mode fails before map output is written
+	let mut dimension_regions = Vec::with_capacity(configs.len());
+	for config in &configs {
+		info!("Processing dimension {}...", config.dimension.id);
+
+		dimension_regions.push(RegionProcessor::new(config).run()?);
+	}
+
+	for (config, regions) in configs.iter().zip(&dimension_regions) {
+		info!("Rendering dimension {}...", config.dimension.id);
+
+		for layer in 0..config.layers.len() {
+			TileRenderer::new(config, &rt, regions, layer, textures.as_ref()).run()?;
+		}
+		let tiles = TileMipmapper::new(config, regions).run()?;
+		let isometric_tiles = match config.isometric {
+			Some(_) => {
+				let tiles = IsometricRenderer::new(config, &rt, regions).run()?;
+				Some(TileMipmapper::isometric(config, &tiles).run()?)
+			}
+			None => None,
+		};
+		EntityCollector::new(config, regions).run()?;
+		MetadataWriter::new(config, &tiles, isometric_tiles.as_deref()).run()?;
+	}
+
+	metadata_writer::write_dimension_index(&args.output_dir, &dimensions)?;
 
 	Ok(())
 }
@@ -0,0 +1,565 @@
+//! Runtime overrides of block and biome types
+
+use std::{
+	collections::BTreeMap,
+	fs,
+	hash::{Hash, Hasher},
+	io,
+	path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+
+use super::block_textures::{BlockTextures, TextureColor};
+use crate::{
+	io::resource_pack::ResourcePack,
+	resource::{
+		Biome, BiomeGrassColorModifier, BiomeType, BiomeTypes, BlockColor, BlockFlag, BlockType,
+		BlockTypes, Color,
+	},
+};
+
+/// Adds the `minecraft:` namespace to IDs without a namespace
+fn namespaced(id: &str) -> String {
+	if id.contains(':') {
+		id.to_string()
+	} else {
+		format!("minecraft:{}", id)
+	}
+}
+
+/// RGB color with components in the range 0..=255
+///
+/// Fractional values as generated by `resource/extract.py` are rounded.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+struct RgbColor {
+	/// Red component
+	r: f32,
+	/// Green component
+	g: f32,
+	/// Blue component
+	b: f32,
+}
+
+impl From<RgbColor> for Color {
+	fn from(value: RgbColor) -> Self {
+		Color([value.r, value.g, value.b].map(|v| v.round().clamp(0.0, 255.0) as u8))
+	}
+}
+
+/// Default value of [BlockOverride::alpha]
+fn default_alpha() -> f32 {
+	255.0
+}
+
+/// Default value of [BlockOverride::opaque]
+fn default_opaque() -> bool {
+	true
+}
+
+/// Block type specification of an override file
+///
+/// The fields match the entries of the `colors.json` file generated by
+/// `resource/extract.py`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct BlockOverride {
+	/// Base color of the block type
+	color: RgbColor,
+	/// Opacity of translucent block types
+	#[serde(default = "default_alpha")]
+	alpha: f32,
+	/// The block type is opaque
+	///
+	/// Block types are opaque by default; non-opaque block types are
+	/// invisible on the map.
+	#[serde(default = "default_opaque")]
+	opaque: bool,
+	/// The block type is translucent
+	#[serde(default)]
+	translucent: bool,
+	/// The block type is colored using biome grass colors
+	#[serde(default)]
+	grass: bool,
+	/// The block type is colored using biome foliage colors
+	#[serde(default)]
+	foliage: bool,
+	/// The block type is birch foliage
+	#[serde(default)]
+	birch: bool,
+	/// The block type is spruce foliage
+	#[serde(default)]
+	spruce: bool,
+	/// The block type is colored using biome water colors
+	#[serde(default)]
+	water: bool,
+	/// The block type is a wall sign
+	#[serde(default)]
+	wall_sign: bool,
+	/// Material of a sign block
+	#[serde(default)]
+	sign_material: Option<String>,
+}
+
+impl BlockOverride {
+	/// Constructs a specification from the average color of a block texture
+	///
+	/// The remaining properties are taken from the built-in block type if
+	/// there is one.
+	fn from_texture(block_type: Option<&BlockType>, texture: &TextureColor) -> Self {
+		let [r, g, b] = texture.color;
+		let is = |flag| block_type.is_some_and(|block_type| block_type.block_color.is(flag));
+
+		BlockOverride {
+			color: RgbColor { r, g, b },
+			alpha: if texture.translucent {
+				texture.alpha
+			} else {
+				default_alpha()
+			},
+			opaque: true,
+			translucent: texture.translucent,
+			grass: is(BlockFlag::Grass),
+			foliage: is(BlockFlag::Foliage),
+			birch: is(BlockFlag::Birch),
+			spruce: is(BlockFlag::Spruce),
+			water: is(BlockFlag::Water),
+			wall_sign: is(BlockFlag::WallSign),
+			sign_material: block_type.and_then(|block_type| block_type.sign_material.clone()),
+		}
+	}
+
+	/// Converts the specification into a [BlockType]
+	///
+	/// A missing specification results in an invisible block type.
+	fn block_type(id: &str, value: Option<&BlockOverride>) -> BlockType {
+		let Some(value) = value else {
+			let block_color = BlockColor {
+				flags: Default::default(),
+				color: Color([0, 0, 0]),
+				alpha: 255,
+			};
+			return BlockType::new(id, block_color, None);
+		};
+
+		let mut block_color = BlockColor {
+			flags: Default::default(),
+			color: value.color.into(),
+			alpha: value.alpha.round().clamp(0.0, 255.0) as u8,
+		};
+		for (set, flag) in [
+			(value.opaque, BlockFlag::Opaque),
+			(value.translucent, BlockFlag::Translucent),
+			(value.grass, BlockFlag::Grass),
+			(value.foliage, BlockFlag::Foliage),
+			(value.birch, BlockFlag::Birch),
+			(value.spruce, BlockFlag::Spruce),
+			(value.water, BlockFlag::Water),
+			(value.wall_sign, BlockFlag::WallSign),
+		] {
+			if set {
+				block_color.flags |= flag;
+			}
+		}
+
+		BlockType::new(id, block_color, value.sign_material.clone())
+	}
+}
+
+/// Grass color modifier of a biome override
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum GrassColorModifier {
+	/// No grass color modifier
+	#[default]
+	None,
+	/// Grass color modifier used by the dark forest biome
+	DarkForest,
+	/// Grass color modifier used by swamp biomes
+	Swamp,
+}
+
+/// Color settings of a biome override
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct BiomeEffects {
+	/// Water color as an RGB integer
+	water_color: Option<u32>,
+	/// Foliage color as an RGB integer
+	foliage_color: Option<u32>,
+	/// Grass color as an RGB integer
+	grass_color: Option<u32>,
+	/// Grass color modifier
+	#[serde(default)]
+	grass_color_modifier: GrassColorModifier,
+}
+
+/// Biome specification of an override file
+///
+/// The fields match the worldgen biome definitions of Minecraft and
+/// datapacks; unrelated fields of these definitions are ignored.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct BiomeOverride {
+	/// Temperature value
+	temperature: f32,
+	/// Downfall value
+	downfall: f32,
+	/// Color settings
+	#[serde(default)]
+	effects: BiomeEffects,
+}
+
+impl BiomeOverride {
+	/// Converts the specification into a [Biome]
+	fn biome(&self) -> Biome {
+		let color = |value: u32| Color([(value >> 16) as u8, (value >> 8) as u8, value as u8]);
+		let effects = &self.effects;
+
+		let mut biome = Biome::from_climate(self.temperature, self.downfall);
+		biome.water_color = effects.water_color.map(color);
+		biome.foliage_color = effects.foliage_color.map(color);
+		biome.grass_color = effects.grass_color.map(color);
+		biome.grass_color_modifier = match effects.grass_color_modifier {
+			GrassColorModifier::None => None,
+			GrassColorModifier::DarkForest => Some(BiomeGrassColorModifier::DarkForest),
+			GrassColorModifier::Swamp => Some(BiomeGrassColorModifier::Swamp),
+		};
+		biome
+	}
+}
+
+/// Derives the namespaced ID of a biome from the path of its definition in a datapack
+///
+/// Returns [None] for files that are not biome definitions.
+fn datapack_biome_id(path: &str) -> Option<String> {
+	let (namespace, path) = path.strip_prefix("data/")?.split_once('/')?;
+	let name = path
+		.strip_prefix("worldgen/biome/")?
+		.strip_suffix(".json")?;
+	Some(format!("{}:{}", namespace, name))
+}
+
+/// Contents of an override file
+#[derive(Debug, Default, Deserialize)]
+struct OverrideFile {
+	/// Block type specifications by ID (`null` for invisible block types)
+	#[serde(default)]
+	blocks: BTreeMap<String, Option<BlockOverride>>,
+	/// Biome specifications by ID
+	#[serde(default)]
+	biomes: BTreeMap<String, BiomeOverride>,
+}
+
+/// Block and biome types added to or replacing the built-in resource data
+///
+/// IDs without a namespace refer to the `minecraft:` namespace.
+#[derive(Debug, Clone, Default)]
+pub struct Overrides {
+	/// Block type specifications by namespaced ID
+	blocks: BTreeMap<String, Option<BlockOverride>>,
+	/// Biome specifications by namespaced ID
+	biomes: BTreeMap<String, BiomeOverride>,
+	/// Hash of the block type and biome specifications
+	digest: u64,
+}
+
+impl Overrides {
+	/// Loads the biomes of the world's datapacks, derives block colors from
+	/// resource packs and loads override files
+	///
+	/// Entries of override files take precedence over datapack biomes and
+	/// block colors derived from resource packs; entries of later files take
+	/// precedence over earlier ones.
+	pub fn load(datapack_dir: &Path, color_packs: &[PathBuf], paths: &[PathBuf]) -> Result<Self> {
+		let mut overrides = Overrides::default();
+
+		overrides
+			.load_datapack_biomes(datapack_dir)
+			.context("Failed to load datapacks")?;
+
+		if let Some(textures) = BlockTextures::open(color_packs)? {
+			overrides
+				.derive_block_colors(&textures)
+				.context("Failed to derive block colors")?;
+		}
+
+		for path in paths {
+			let data = fs::read_to_string(path)
+				.with_context(|| format!("Failed to read {}", path.display()))?;
+			let file: OverrideFile = serde_json::from_str(&data)
+				.with_context(|| format!("Failed to parse {}", path.display()))?;
+			overrides.extend(file);
+		}
+
+		let data = serde_json::to_vec(&(&overrides.blocks, &overrides.biomes))?;
+		let mut hasher = rustc_hash::FxHasher::default();
+		data.hash(&mut hasher);
+		overrides.digest = hasher.finish();
+
+		Ok(overrides)
+	}
+
+	/// Loads the biome definitions of all datapacks in a directory
+	///
+	/// Datapacks may be directories or ZIP archives. They are loaded in
+	/// alphabetical order, with later datapacks taking precedence.
+	fn load_datapack_biomes(&mut self, dir: &Path) -> Result<()> {
+		let entries = match fs::read_dir(dir) {
+			Ok(entries) => entries,
+			Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(()),
+			Err(err) => {
+				return Err(err).with_context(|| format!("Failed to list {}", dir.display()))
+			}
+		};
+
+		let mut paths = Vec::new();
+		for entry in entries {
+			let path = entry?.path();
+			let is_zip = path
+				.extension()
+				.is_some_and(|ext| ext.eq_ignore_ascii_case("zip"));
+			if path.is_dir() || is_zip {
+				paths.push(path);
+			}
+		}
+		if paths.is_empty() {
+			return Ok(());
+		}
+		paths.sort();
+
+		let pack = ResourcePack::open(&paths)?;
+		let mut count = 0;
+
+		for file in pack.list("data/")? {
+			let Some(id) = datapack_biome_id(&file) else {
+				continue;
+			};
+			match pack.read_json::<BiomeOverride>(&file) {
+				Ok(Some(biome)) => {
+					self.biomes.insert(id, biome);
+					count += 1;
+				}
+				Ok(None) => {}
+				Err(err) => warn!("Failed to load biome {}: {:?}", id, err),
+			}
+		}
+
+		info!("Loaded {} biomes from datapacks", count);
+
+		Ok(())
+	}
+
+	/// Derives block colors from the average colors of block textures
+	///
+	/// The colors of the built-in block types are replaced, keeping their
+	/// other properties; block types without texture keep their built-in
+	/// colors. Block types with blockstate definitions in the resource
+	/// packs that are not built in, like those of mods, are added as well.
+	fn derive_block_colors(&mut self, textures: &BlockTextures) -> Result<()> {
+		let block_types = BlockTypes::default();
+		let builtin = block_types
+			.iter()
+			.filter(|block_type| block_type.block_color.is(BlockFlag::Opaque))
+			.map(|block_type| (block_type.id.clone(), Some(block_type)));
+		let added = textures
+			.block_ids()?
+			.into_iter()
+			.filter(|id| block_types.get(id).is_none())
+			.map(|id| (id, None));
+
+		let mut count = 0;
+
+		for (id, block_type) in builtin.chain(added) {
+			let color = match textures.color(&id) {
+				Ok(Some(color)) => color,
+				Ok(None) => continue,
+				Err(err) => {
+					warn!("Failed to load texture of block type {}: {:?}", id, err);
+					continue;
+				}
+			};
+
+			self.blocks
+				.insert(id, Some(BlockOverride::from_texture(block_type, &color)));
+			count += 1;
+		}
+
+		info!(
+			"Derived colors of {} block types from resource packs",
+			count
+		);
+
+		Ok(())
+	}
+
+	/// Adds the entries of an override file, replacing existing entries
+	fn extend(&mut self, file: OverrideFile) {
+		self.blocks.extend(
+			file.blocks
+				.into_iter()
+				.map(|(id, value)| (namespaced(&id), value)),
+		);
+		self.biomes.extend(
+			file.biomes
+				.into_iter()
+				.map(|(id, value)| (namespaced(&id), value)),
+		);
+	}
+
+	/// Returns a hash of the block type and biome specifications
+	///
+	/// The hash is included in the versions of generated files, so changes
+	/// to the overrides invalidate existing files.
+	pub fn digest(&self) -> u64 {
+		self.digest
+	}
+
+	/// Returns the built-in block types with the overrides applied
+	pub fn block_types(&self) -> BlockTypes {
+		let mut block_types = BlockTypes::default();
+		for (id, value) in &self.blocks {
+			block_types.insert(BlockOverride::block_type(id, value.as_ref()));
+		}
+		block_types
+	}
+
+	/// Returns the built-in biome types with the overrides applied
+	pub fn biome_types(&self) -> BiomeTypes {
+		let mut biome_types = BiomeTypes::default();
+		for (id, value) in &self.biomes {
+			biome_types.insert(BiomeType {
+				id: id.clone(),
+				biome: value.biome(),
+			});
+		}
+		biome_types
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_datapack_biome_id() {
+		assert_eq!(
+			datapack_biome_id("data/terralith/worldgen/biome/cave/andesite_caves.json").as_deref(),
+			Some("terralith:cave/andesite_caves")
+		);
+		assert_eq!(datapack_biome_id("data/terralith/tags/biome/x.json"), None);
+		assert_eq!(datapack_biome_id("pack.mcmeta"), None);
+	}
+
+	#[test]
+	fn test_overrides() {
+		let file: OverrideFile = serde_json::from_str(
+			r#"{
+				"blocks": {
+					"stone": {"color": {"r": 1, "g": 2.4, "b": 2.6}},
+					"examplemod:vine": {
+						"color": {"r": 10, "g": 20, "b": 30},
+						"opaque": true,
+						"foliage": true
+					},
+					"examplemod:barrier": null
+				},
+				"biomes": {
+					"examplemod:marsh": {
+						"temperature": 0.8,
+						"downfall": 0.9,
+						"effects": {"water_color": 6388580, "grass_color_modifier": "swamp"}
+					}
+				}
+			}"#,
+		)
+		.unwrap();
+		let mut merged = Overrides::default();
+		merged.extend(file);
+
+		let block_types = merged.block_types();
+		let stone = block_types.get("minecraft:stone").unwrap();
+		assert_eq!(stone.block_color.color, Color([1, 2, 3]));
+		assert!(stone.block_color.is(BlockFlag::Opaque));
+		let vine = block_types.get("examplemod:vine").unwrap();
+		assert!(vine.block_color.is(BlockFlag::Foliage));
+		let barrier = block_types.get("examplemod:barrier").unwrap();
+		assert!(!barrier.block_color.is(BlockFlag::Opaque));
+
+		let biome_types = merged.biome_types();
+		let marsh = biome_types.get("examplemod:marsh").unwrap().biome;
+		assert_eq!(marsh.temp, 16);
+		assert_eq!(marsh.water_color, Some(Color([0x61, 0x7b, 0x64])));
+		assert_eq!(
+			marsh.grass_color_modifier,
+			Some(BiomeGrassColorModifier::Swamp)
+		);
+	}
+
+	#[test]
+	fn test_derive_block_colors() {
+		let dir = std::env::temp_dir().join(format!("minedmap-test-pack-{}", std::process::id()));
+		let write = |path: &str, data: &[u8]| {
+			let path = dir.join(path);
+			fs::create_dir_all(path.parent().unwrap()).unwrap();
+			fs::write(path, data).unwrap();
+		};
+		let write_texture = |path: &str, color: [u8; 3]| {
+			let [r, g, b] = color;
+			let image = image::RgbaImage::from_pixel(16, 16, image::Rgba([r, g, b, 255]));
+			let mut data = io::Cursor::new(Vec::new());
+			image.write_to(&mut data, image::ImageFormat::Png).unwrap();
+			write(path, &data.into_inner());
+		};
+
+		// Built-in block types use the textures of resource/blocks.json,
+		// regardless of their block models
+		write(
+			"assets/minecraft/blockstates/acacia_log.json",
+			br#"{"variants": {"": {"model": "minecraft:block/acacia_log"}}}"#,
+		);
+		write(
+			"assets/minecraft/models/block/acacia_log.json",
+			br#"{"textures": {"all": "minecraft:block/acacia_log"}}"#,
+		);
+		write_texture("assets/minecraft/textures/block/acacia_log.png", [1, 2, 3]);
+		write_texture(
+			"assets/minecraft/textures/block/acacia_log_top.png",
+			[10, 20, 30],
+		);
+		write_texture(
+			"assets/minecraft/textures/block/oak_leaves.png",
+			[0, 100, 0],
+		);
+
+		// Block types of mods use the textures of their block models
+		write(
+			"assets/examplemod/blockstates/ruby_block.json",
+			br#"{"variants": {"": {"model": "examplemod:block/ruby_block"}}}"#,
+		);
+		write(
+			"assets/examplemod/models/block/ruby_block.json",
+			br#"{"textures": {"all": "examplemod:block/ruby"}}"#,
+		);
+		write_texture("assets/examplemod/textures/block/ruby.png", [200, 0, 0]);
+
+		let textures = BlockTextures::open(std::slice::from_ref(&dir))
+			.unwrap()
+			.unwrap();
+		let mut overrides = Overrides::default();
+		overrides.derive_block_colors(&textures).unwrap();
+		fs::remove_dir_all(&dir).unwrap();
+
+		let block_types = overrides.block_types();
+		let acacia_log = block_types.get("minecraft:acacia_log").unwrap();
+		assert_eq!(acacia_log.block_color.color, Color([10, 20, 30]));
+		let acacia_wood = block_types.get("minecraft:acacia_wood").unwrap();
+		assert_eq!(acacia_wood.block_color.color, Color([1, 2, 3]));
+		let oak_leaves = block_types.get("minecraft:oak_leaves").unwrap();
+		assert_eq!(oak_leaves.block_color.color, Color([0, 100, 0]));
+		assert!(oak_leaves.block_color.is(BlockFlag::Foliage));
+		let ruby_block = block_types.get("examplemod:ruby_block").unwrap();
+		assert_eq!(ruby_block.block_color.color, Color([200, 0, 0]));
+		assert!(ruby_block.block_color.is(BlockFlag::Opaque));
+		assert!(!ruby_block.block_color.is(BlockFlag::Foliage));
+	}
+}
@@ -1,10 +1,17 @@
 //! The [RegionProcessor] and related functions
 
-use std::{ffi::OsStr, path::PathBuf, sync::mpsc, time::SystemTime};
+use std::{
+	collections::BTreeMap,
+	ffi::OsStr,
+	path::PathBuf,
+	sync::mpsc,
+	time::{SystemTime, UNIX_EPOCH},
+};
 
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use enum_map::{Enum, EnumMap};
 use rayon::prelude::*;
+use serde::Serialize;
 use tracing::{debug, info, warn};
 
 use super::common::*;
@@ -28,6 +35,51 @@ fn parse_region_filename(file_name: &OsStr) -> Option<TileCoords> {
 	})
 }
 
+/// Determines the chunks of a region that were modified since it was last processed
+///
+/// *previous* and *current* are the chunk timestamps stored in the region
+/// data when it was last processed and now, *previous_timestamp* is the
+/// modification time of the region file when it was last processed.
+///
+/// Minecraft stores chunk timestamps with a resolution of one second, so
+/// chunks with a timestamp not earlier than the second of
+/// *previous_timestamp* may have been saved again after processing and are
+/// treated as modified. Chunks with a timestamp of 0 (as written by some
+/// external tools) are always treated as modified.
+fn changed_chunks(
+	previous: &ChunkArray<Option<u32>>,
+	previous_timestamp: SystemTime,
+	current: &ChunkArray<Option<u32>>,
+) -> ChunkArray<bool> {
+	let previous_secs = previous_timestamp
+		.duration_since(UNIX_EPOCH)
+		.map_or(0, |duration| duration.as_secs());
+
+	let mut changed = ChunkArray::<bool>::default();
+	for (chunk_coords, &timestamp) in current.iter() {
+		changed[chunk_coords] = timestamp != previous[chunk_coords]
+			|| timestamp.is_some_and(|t| t == 0 || u64::from(t) >= previous_secs);
+	}
+	changed
+}
+
+/// Extends a set of chunks by their direct neighbors within the region
+fn with_neighbors(chunks: &ChunkArray<bool>) -> ChunkArray<bool> {
+	let mut ret = ChunkArray::<bool>::default();
+	for (chunk_coords, &value) in chunks.iter() {
+		if !value {
+			continue;
+		}
+		let (x, z) = (chunk_coords.x.0 as usize, chunk_coords.z.0 as usize);
+		for nz in z.saturating_sub(1)..=(z + 1).min(CHUNKS_PER_REGION - 1) {
+			for nx in x.saturating_sub(1)..=(x + 1).min(CHUNKS_PER_REGION - 1) {
+				ret.0[nz][nx] = true;
+			}
+		}
+	}
+	ret
+}
+
 /// [RegionProcessor::process_region] return values
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Enum)]
 enum RegionProcessorStatus {
@@ -43,8 +95,142 @@ enum RegionProcessorStatus {
 	ErrorMissing,
 }
 
+/// Occurrences of an unknown block or biome ID
+#[derive(Debug, Serialize)]
+struct UnknownId {
+	/// Number of blocks with the ID
+	///
+	/// Biomes are counted for all blocks of the 4x4x4 areas they are stored for.
+	count: usize,
+	/// Coordinates of a block with the ID
+	///
+	/// The lowest coordinates are used to make the report deterministic.
+	example: [i32; 3],
+	/// Namespaced ID of the block type used to render an unknown block type
+	#[serde(skip_serializing_if = "Option::is_none")]
+	fallback: Option<String>,
+}
+
+/// Unknown block and biome IDs found during processing
+#[derive(Debug, Default, Serialize)]
+struct UnknownReport {
+	/// Unknown block IDs
+	blocks: BTreeMap<String, UnknownId>,
+	/// Unknown biome IDs
+	biomes: BTreeMap<String, UnknownId>,
+}
+
+impl UnknownReport {
+	/// Records occurrences of an ID
+	fn add(map: &mut BTreeMap<String, UnknownId>, id: &str, count: usize, example: [i32; 3]) {
+		if let Some(entry) = map.get_mut(id) {
+			entry.count += count;
+			entry.example = entry.example.min(example);
+		} else {
+			map.insert(
+				id.to_string(),
+				UnknownId {
+					count,
+					example,
+					fallback: None,
+				},
+			);
+		}
+	}
+
+	/// Records the unknown IDs of a chunk
+	fn add_chunk(
+		&mut self,
+		region: TileCoords,
+		chunk_coords: ChunkCoords,
+		unknown: &world::chunk::UnknownIds,
+	) {
+		let x = (region.x * CHUNKS_PER_REGION as i32 + i32::from(chunk_coords.x.0))
+			* BLOCKS_PER_CHUNK as i32;
+		let z = (region.z * CHUNKS_PER_REGION as i32 + i32::from(chunk_coords.z.0))
+			* BLOCKS_PER_CHUNK as i32;
+		let coords = |example: [u8; 3], y: SectionY| {
+			[
+				x + i32::from(example[0]),
+				y.0 * BLOCKS_PER_CHUNK as i32 + i32::from(example[1]),
+				z + i32::from(example[2]),
+			]
+		};
+
+		for (entry, y) in &unknown.blocks {
+			Self::add(
+				&mut self.blocks,
+				entry.id,
+				entry.count,
+				coords(entry.example, *y),
+			);
+		}
+		for (entry, y) in &unknown.biomes {
+			Self::add(
+				&mut self.biomes,
+				entry.id,
+				entry.count,
+				coords(entry.example, *y),
+			);
+		}
+	}
+
+	/// Merges the IDs of another report into this report
+	fn merge(&mut self, other: UnknownReport) {
+		for (id, entry) in other.blocks {
+			Self::add(&mut self.blocks, &id, entry.count, entry.example);
+		}
+		for (id, entry) in other.biomes {
+			Self::add(&mut self.biomes, &id, entry.count, entry.example);
+		}
+	}
+
+	/// Returns true if no unknown IDs were found
+	fn is_empty(&self) -> bool {
+		self.blocks.is_empty() && self.biomes.is_empty()
+	}
+
+	/// Logs a warning for each unknown ID
+	fn warn(&self) {
+		for (id, entry) in &self.blocks {
+			let [x, y, z] = entry.example;
+			match &entry.fallback {
+				Some(fallback) => warn!(
+					"Unknown block type {} rendered as {} ({} blocks, e.g. at {} {} {})",
+					id, fallback, entry.count, x, y, z,
+				),
+				None => warn!(
+					"Unknown block type {} ({} blocks, e.g. at {} {} {})",
+					id, entry.count, x, y, z,
+				),
+			}
+		}
+		for (id, entry) in &self.biomes {
+			let [x, y, z] = entry.example;
+			warn!(
+				"Unknown biome type {} ({} blocks, e.g. at {} {} {})",
+				id, entry.count, x, y, z,
+			);
+		}
+	}
+}
+
+/// Processed region output of a single map layer
+struct LayerOutput {
+	/// Processed region data output filename
+	path: PathBuf,
+	/// Timestamp of last modification of processed region output file (if valid)
+	timestamp: Option<SystemTime>,
+	/// True if processed region output file needs to be updated
+	needed: bool,
+	/// Processed region intermediate data
+	region: ProcessedRegion,
+}
+
 /// Handles processing for a single region
 struct SingleRegionProcessor<'a> {
+	/// Common MinedMap configuration from command line
+	config: &'a Config,
 	/// Registry of known block types
 	block_types: &'a resource::BlockTypes,
 	/// Registry of known biome types
@@ -53,34 +239,28 @@ struct SingleRegionProcessor<'a> {
 	coords: TileCoords,
 	/// Input region filename
 	input_path: PathBuf,
-	/// Processed region data output filename
-	output_path: PathBuf,
 	/// Lightmap output filename
 	lightmap_path: PathBuf,
 	/// Processed entity output filename
 	entities_path: PathBuf,
 	/// Timestamp of last modification of input file
 	input_timestamp: SystemTime,
-	/// Timestamp of last modification of processed region output file (if valid)
-	output_timestamp: Option<SystemTime>,
 	/// Timestamp of last modification of lightmap output file (if valid)
 	lightmap_timestamp: Option<SystemTime>,
 	/// Timestamp of last modification of entity list output file (if valid)
 	entities_timestamp: Option<SystemTime>,
-	/// True if processed region output file needs to be updated
-	output_needed: bool,
 	/// True if lightmap output file needs to be updated
 	lightmap_needed: bool,
 	/// True if entity output file needs to be updated
 	entities_needed: bool,
-	/// Processed region intermediate data
-	processed_region: ProcessedRegion,
+	/// Processed region outputs, one for each map layer
+	outputs: Vec<LayerOutput>,
 	/// Lightmap intermediate data
 	lightmap: image::GrayAlphaImage,
 	/// Processed entity intermediate data
 	entities: ProcessedEntities,
-	/// True if any unknown block or biome types were encountered during processing
-	has_unknown: bool,
+	/// Unknown block and biome types encountered during processing
+	unknown: UnknownReport,
 }
 
 impl<'a> SingleRegionProcessor<'a> {
@@ -92,49 +272,166 @@ impl<'a> SingleRegionProcessor<'a> {
 		let input_path = processor.config.region_path(coords);
 		let input_timestamp = fs::modified_timestamp(&input_path)?;
 
-		let output_path = processor.config.processed_path(coords);
-		let output_timestamp = fs::read_timestamp(&output_path, REGION_FILE_META_VERSION);
+		let outputs = (0..processor.config.layers.len())
+			.map(|layer| {
+				let path = processor.config.processed_path(layer, coords);
+				let timestamp =
+					fs::read_timestamp(&path, processor.config.region_file_meta_version(layer));
+				LayerOutput {
+					path,
+					timestamp,
+					needed: Some(input_timestamp) > timestamp,
+					region: ProcessedRegion::default(),
+				}
+			})
+			.collect();
 
 		let lightmap_path = processor.config.tile_path(TileKind::Lightmap, 0, coords);
-		let lightmap_timestamp = fs::read_timestamp(&lightmap_path, LIGHTMAP_FILE_META_VERSION);
+		let lightmap_timestamp = fs::read_timestamp(
+			&lightmap_path,
+			processor.config.lightmap_file_meta_version(),
+		);
 
 		let entities_path = processor.config.entities_path(0, coords);
 		let entities_timestamp = fs::read_timestamp(&entities_path, ENTITIES_FILE_META_VERSION);
 
-		let output_needed = Some(input_timestamp) > output_timestamp;
 		let lightmap_needed = Some(input_timestamp) > lightmap_timestamp;
 		let entities_needed = Some(input_timestamp) > entities_timestamp;
 
-		let processed_region = ProcessedRegion::default();
 		let lightmap = image::GrayAlphaImage::new(N, N);
 		let entities = ProcessedEntities::default();
 
 		Ok(SingleRegionProcessor {
+			config: processor.config,
 			block_types: &processor.block_types,
 			biome_types: &processor.biome_types,
 			coords,
 			input_path,
-			output_path,
 			lightmap_path,
 			entities_path,
 			input_timestamp,
-			output_timestamp,
 			lightmap_timestamp,
 			entities_timestamp,
-			output_needed,
 			lightmap_needed,
 			entities_needed,
-			processed_region,
+			outputs,
 			lightmap,
 			entities,
-			has_unknown: false,
+			unknown: UnknownReport::default(),
 		})
 	}
 
+	/// Loads the previously generated outputs that need to be updated
+	///
+	/// Returns the chunk timestamps of the region data the outputs were
+	/// generated from and the modification time of the region file at that
+	/// time, or [None] when the outputs cannot be updated incrementally,
+	/// because they do not exist or were generated from different versions
+	/// of the region data.
+	fn load_previous(&mut self) -> Result<Option<(ChunkArray<Option<u32>>, SystemTime)>> {
+		let mut timestamps = self
+			.outputs
+			.iter()
+			.filter(|output| output.needed)
+			.map(|output| output.timestamp)
+			.collect::<Vec<_>>();
+		// The chunk timestamps are stored in the processed region data
+		if timestamps.is_empty() {
+			return Ok(None);
+		}
+		if self.lightmap_needed {
+			timestamps.push(self.lightmap_timestamp);
+		}
+		if self.entities_needed {
+			timestamps.push(self.entities_timestamp);
+		}
+		let Some(previous_timestamp) = timestamps[0] else {
+			return Ok(None);
+		};
+		if timestamps
+			.iter()
+			.any(|&timestamp| timestamp != Some(previous_timestamp))
+		{
+			return Ok(None);
+		}
+
+		let regions = self
+			.outputs
+			.iter()
+			.map(|output| {
+				if !output.needed {
+					return Ok(None);
+				}
+				storage::read_file(&output.path, storage::Format::Bincode).map(Some)
+			})
+			.collect::<Result<Vec<Option<ProcessedRegion>>>>()?;
+		let lightmap = if self.lightmap_needed {
+			let image = image::open(&self.lightmap_path)
+				.with_context(|| format!("Failed to read image {}", self.lightmap_path.display()))?
+				.into_luma_alpha8();
+			if image.dimensions() != self.lightmap.dimensions() {
+				bail!("Invalid lightmap size");
+			}
+			Some(image)
+		} else {
+			None
+		};
+		let entities = if self.entities_needed {
+			Some(storage::read_file(
+				&self.entities_path,
+				storage::Format::Json,
+			)?)
+		} else {
+			None
+		};
+
+		let mut chunk_timestamps = None;
+		for (output, region) in self.outputs.iter_mut().zip(regions) {
+			if let Some(region) = region {
+				chunk_timestamps.get_or_insert(region.chunk_timestamps);
+				output.region = region;
+			}
+		}
+		if let Some(lightmap) = lightmap {
+			self.lightmap = lightmap;
+		}
+		if let Some(entities) = entities {
+			self.entities = entities;
+		}
+
+		Ok(chunk_timestamps.map(|chunk_timestamps| (chunk_timestamps, previous_timestamp)))
+	}
+
+	/// Removes the previously generated data of chunks that are processed again
+	fn clear_chunks(&mut self, chunks: &ChunkArray<bool>) {
+		let empty = image::GrayAlphaImage::new(BLOCKS_PER_CHUNK as u32, BLOCKS_PER_CHUNK as u32);
+
+		for (chunk_coords, &clear) in chunks.iter() {
+			if !clear {
+				continue;
+			}
+			for output in &mut self.outputs {
+				output.region.chunks[chunk_coords] = None;
+			}
+			image::imageops::replace(
+				&mut self.lightmap,
+				&empty,
+				chunk_coords.x.0 as i64 * BLOCKS_PER_CHUNK as i64,
+				chunk_coords.z.0 as i64 * BLOCKS_PER_CHUNK as i64,
+			);
+		}
+
+		self.entities.block_entities.retain(|entity| {
+			let chunk_coords = ChunkCoords {
+				x: ChunkX::new((entity.x >> BLOCK_BITS).rem_euclid(CHUNKS_PER_REGION as i32)),
+				z: ChunkZ::new((entity.z >> BLOCK_BITS).rem_euclid(CHUNKS_PER_REGION as i32)),
+			};
+			!chunks[chunk_coords]
+		});
+	}
+
 	/// Renders a lightmap subtile from chunk block light data
-	fn render_chunk_lightmap(
-		block_light: Box<world::layer::BlockLightArray>,
-	) -> image::GrayAlphaImage {
+	fn render_chunk_lightmap(block_light: &world::layer::BlockLightArray) -> image::GrayAlphaImage {
 		/// Width/height of generated chunk lightmap
 		const N: u32 = BLOCKS_PER_CHUNK as u32;
 
@@ -148,21 +445,25 @@ impl<'a> SingleRegionProcessor<'a> {
 		})
 	}
 
-	/// Saves processed region data
+	/// Saves processed region data of all map layers
 	///
 	/// The timestamp is the time of the last modification of the input region data.
-	fn save_region(&self) -> Result<()> {
-		if !self.output_needed {
-			return Ok(());
+	fn save_regions(&self) -> Result<()> {
+		for (layer, output) in self.outputs.iter().enumerate() {
+			if !output.needed {
+				continue;
+			}
+
+			storage::write_file(
+				&output.path,
+				&output.region,
+				storage::Format::Bincode,
+				self.config.region_file_meta_version(layer),
+				self.input_timestamp,
+			)?;
 		}
 
-		storage::write_file(
-			&self.output_path,
-			&self.processed_region,
-			storage::Format::Bincode,
-			REGION_FILE_META_VERSION,
-			self.input_timestamp,
-		)
+		Ok(())
 	}
 
 	/// Saves a lightmap tile
@@ -175,7 +476,7 @@ impl<'a> SingleRegionProcessor<'a> {
 
 		fs::create_with_timestamp(
 			&self.lightmap_path,
-			LIGHTMAP_FILE_META_VERSION,
+			self.config.lightmap_file_meta_version(),
 			self.input_timestamp,
 			|file| {
 				self.lightmap
@@ -205,33 +506,56 @@ impl<'a> SingleRegionProcessor<'a> {
 	}
 
 	/// Processes a single chunk
-	fn process_chunk(&mut self, chunk_coords: ChunkCoords, data: world::de::Chunk) -> Result<()> {
-		let (chunk, has_unknown) =
-			world::chunk::Chunk::new(&data, self.block_types, self.biome_types)
+	fn process_chunk(&mut self, chunk_coords: ChunkCoords, data: &world::de::Chunk) -> Result<()> {
+		let (chunk, unknown) =
+			world::chunk::Chunk::new(data, self.block_types, self.biome_types)
 				.with_context(|| format!("Failed to decode chunk {:?}", chunk_coords))?;
-		self.has_unknown |= has_unknown;
+		self.unknown.add_chunk(self.coords, chunk_coords, &unknown);
 
-		if self.output_needed || self.lightmap_needed {
-			if let Some(layer::LayerData {
+		for (layer, output) in self.outputs.iter_mut().enumerate() {
+			// The lightmap is generated from the main map layer
+			let lightmap_needed = layer == 0 && self.lightmap_needed;
+			if !output.needed && !lightmap_needed {
+				continue;
+			}
+
+			let options = &self.config.layers[layer].options;
+			let Some(layer::LayerData {
 				blocks,
+				block_ids,
 				biomes,
 				block_light,
+				sky_light,
 				depths,
-			}) = world::layer::top_layer(&mut self.processed_region.biome_list, &chunk)
-				.with_context(|| format!("Failed to process chunk {:?}", chunk_coords))?
-			{
-				if self.output_needed {
-					self.processed_region.chunks[chunk_coords] = Some(Box::new(ProcessedChunk {
-						blocks,
-						biomes,
-						depths,
-					}));
-				}
+				heights,
+				translucent,
+			}) = world::layer::top_layer(
+				&mut output.region.block_list,
+				&mut output.region.biome_list,
+				&chunk,
+				options,
+			)
+			.with_context(|| format!("Failed to process chunk {:?}", chunk_coords))?
+			else {
+				continue;
+			};
+
+			if lightmap_needed {
+				let chunk_lightmap = Self::render_chunk_lightmap(&block_light);
+				overlay_chunk(&mut self.lightmap, &chunk_lightmap, chunk_coords);
+			}
 
-				if self.lightmap_needed {
-					let chunk_lightmap = Self::render_chunk_lightmap(block_light);
-					overlay_chunk(&mut self.lightmap, &chunk_lightmap, chunk_coords);
-				}
+			if output.needed {
+				output.region.chunks[chunk_coords] = Some(Box::new(ProcessedChunk {
+					blocks,
+					block_ids,
+					biomes,
+					block_light,
+					sky_light,
+					depths,
+					heights,
+					translucent: options.blend_translucent.then_some(translucent),
+				}));
 			}
 		}
 
@@ -249,19 +573,120 @@ impl<'a> SingleRegionProcessor<'a> {
 	}
 
 	/// Processes the chunks of the region
+	///
+	/// When the previously generated outputs are available, only chunks
+	/// modified since then are processed and merged into the existing data.
+	/// All chunks are processed otherwise.
+	///
+	/// Chunks with stored light data are processed as they are read. Chunks
+	/// without light data are processed afterwards, as computing their block
+	/// light requires access to their neighbors; only these chunks and their
+	/// neighbors are kept in memory at the same time.
 	fn process_chunks(&mut self) -> Result<()> {
-		crate::nbt::region::from_file(&self.input_path)?
-			.foreach_chunk(|chunk_coords, data| self.process_chunk(chunk_coords, data))
+		let mut region = crate::nbt::region::from_file(&self.input_path)?;
+		let timestamps = region.timestamps().map(Some).unwrap_or_else(|err| {
+			warn!(
+				"Failed to read chunk timestamps of region r.{}.{}.mca, processing all chunks: {:?}",
+				self.coords.x, self.coords.z, err
+			);
+			None
+		});
+
+		let previous = self.load_previous().unwrap_or_else(|err| {
+			warn!(
+				"Failed to load previous data of region r.{}.{}.mca, processing all chunks: {:?}",
+				self.coords.x, self.coords.z, err
+			);
+			None
+		});
+		let changed = match (previous, &timestamps) {
+			(Some((previous, previous_timestamp)), Some(timestamps)) => {
+				changed_chunks(&previous, previous_timestamp, timestamps)
+			}
+			_ => ChunkArray([[true; CHUNKS_PER_REGION]; CHUNKS_PER_REGION]),
+		};
+
+		self.clear_chunks(&changed);
+
+		// The computed block light of unlit chunks depends on their neighbors,
+		// so unlit neighbors of modified chunks are processed as well
+		let neighbors = with_neighbors(&changed);
+
+		let mut unlit = ChunkArray::<bool>::default();
+		let mut count = 0;
+		region.foreach_chunk_filtered(
+			|chunk_coords| neighbors[chunk_coords],
+			|chunk_coords, data: world::de::Chunk| {
+				if world::light::needs_block_light(&data) {
+					unlit[chunk_coords] = true;
+				} else if changed[chunk_coords] {
+					self.process_chunk(chunk_coords, &data)?;
+					count += 1;
+				}
+				Ok(())
+			},
+		)?;
+
+		if unlit.iter().any(|(_, &value)| value) {
+			// Unlit neighbors of modified chunks have not been cleared yet
+			self.clear_chunks(&unlit);
+
+			let load = with_neighbors(&unlit);
+			let mut chunks = Box::<world::light::RegionData>::default();
+			crate::nbt::region::from_file(&self.input_path)?.foreach_chunk_filtered(
+				|chunk_coords| load[chunk_coords],
+				|chunk_coords, data: world::de::Chunk| {
+					chunks[chunk_coords] = Some(Box::new(data));
+					Ok(())
+				},
+			)?;
+
+			world::light::fill_missing_block_light(
+				&mut chunks,
+				&unlit,
+				self.block_types,
+				self.biome_types,
+			)?;
+
+			for (chunk_coords, data) in chunks.iter() {
+				let Some(data) = data else {
+					continue;
+				};
+				if !unlit[chunk_coords] {
+					continue;
+				}
+				self.process_chunk(chunk_coords, data)?;
+				count += 1;
+			}
+		}
+
+		debug!(
+			"Processed {} chunks of region r.{}.{}.mca",
+			count, self.coords.x, self.coords.z
+		);
+
+		// Without timestamps, all chunks are processed again in the next run
+		for output in &mut self.outputs {
+			output.region.chunk_timestamps = timestamps.unwrap_or_default();
+		}
+
+		Ok(())
 	}
 
 	/// Processes the region
-	fn run(mut self) -> Result<RegionProcessorStatus> {
-		if !self.output_needed && !self.lightmap_needed && !self.entities_needed {
+	///
+	/// Returns the unknown block and biome types encountered in addition to
+	/// the processing status.
+	fn run(mut self) -> Result<(RegionProcessorStatus, UnknownReport)> {
+		if !self.outputs.iter().any(|output| output.needed)
+			&& !self.lightmap_needed
+			&& !self.entities_needed
+		{
 			debug!(
 				"Skipping unchanged region r.{}.{}.mca",
 				self.coords.x, self.coords.z
 			);
-			return Ok(RegionProcessorStatus::Skipped);
+			return Ok((RegionProcessorStatus::Skipped, self.unknown));
 		}
 
 		debug!(
@@ -270,7 +695,7 @@ impl<'a> SingleRegionProcessor<'a> {
 		);
 
 		if let Err(err) = self.process_chunks() {
-			if self.output_timestamp.is_some()
+			if self.outputs.iter().all(|output| output.timestamp.is_some())
 				&& self.lightmap_timestamp.is_some()
 				&& self.entities_timestamp.is_some()
 			{
@@ -278,25 +703,31 @@ impl<'a> SingleRegionProcessor<'a> {
 					"Failed to process region {:?}, using old data: {:?}",
 					self.coords, err
 				);
-				return Ok(RegionProcessorStatus::ErrorOk);
+				return Ok((RegionProcessorStatus::ErrorOk, self.unknown));
 			} else {
 				warn!(
 					"Failed to process region {:?}, no old data available: {:?}",
 					self.coords, err
 				);
-				return Ok(RegionProcessorStatus::ErrorMissing);
+				return Ok((RegionProcessorStatus::ErrorMissing, self.unknown));
 			}
 		}
 
-		self.save_region()?;
-		self.save_lightmap()?;
-		self.save_entities()?;
-
-		Ok(if self.has_unknown {
-			RegionProcessorStatus::OkWithUnknown
-		} else {
+		let status = if self.unknown.is_empty() {
 			RegionProcessorStatus::Ok
-		})
+		} else {
+			RegionProcessorStatus::OkWithUnknown
+		};
+
+		// In strict mode, the outputs of regions with unknown IDs are not
+		// saved, so the region is checked again in the next run
+		if status == RegionProcessorStatus::Ok || !self.config.strict {
+			self.save_regions()?;
+			self.save_lightmap()?;
+			self.save_entities()?;
+		}
+
+		Ok((status, self.unknown))
 	}
 }
 
@@ -317,8 +748,8 @@ impl<'a> RegionProcessor<'a> {
 	/// Constructs a new RegionProcessor
 	pub fn new(config: &'a Config) -> Self {
 		RegionProcessor {
-			block_types: resource::BlockTypes::default(),
-			biome_types: resource::BiomeTypes::default(),
+			block_types: config.overrides.block_types(),
+			biome_types: config.overrides.biome_types(),
 			config,
 		}
 	}
@@ -357,7 +788,7 @@ impl<'a> RegionProcessor<'a> {
 	}
 
 	/// Processes a single region file
-	fn process_region(&self, coords: TileCoords) -> Result<RegionProcessorStatus> {
+	fn process_region(&self, coords: TileCoords) -> Result<(RegionProcessorStatus, UnknownReport)> {
 		SingleRegionProcessor::new(self, coords)?.run()
 	}
 
@@ -367,7 +798,9 @@ impl<'a> RegionProcessor<'a> {
 	pub fn run(self) -> Result<Vec<TileCoords>> {
 		use RegionProcessorStatus as Status;
 
-		fs::create_dir_all(&self.config.processed_dir)?;
+		for layer in 0..self.config.layers.len() {
+			fs::create_dir_all(&self.config.processed_layer_dir(layer))?;
+		}
 		fs::create_dir_all(&self.config.tile_dir(TileKind::Lightmap, 0))?;
 		fs::create_dir_all(&self.config.entities_dir(0))?;
 
@@ -375,9 +808,10 @@ impl<'a> RegionProcessor<'a> {
 
 		let (region_send, region_recv) = mpsc::channel();
 		let (status_send, status_recv) = mpsc::channel();
+		let (unknown_send, unknown_recv) = mpsc::channel();
 
 		self.collect_regions()?.par_iter().try_for_each(|&coords| {
-			let ret = self
+			let (ret, unknown) = self
 				.process_region(coords)
 				.with_context(|| format!("Failed to process region {:?}", coords))?;
 
@@ -386,6 +820,9 @@ impl<'a> RegionProcessor<'a> {
 			}
 
 			status_send.send(ret).unwrap();
+			if !unknown.is_empty() {
+				unknown_send.send(unknown).unwrap();
+			}
 
 			anyhow::Ok(())
 		})?;
@@ -407,7 +844,20 @@ impl<'a> RegionProcessor<'a> {
 			status[Status::ErrorOk] + status[Status::ErrorMissing],
 		);
 
+		drop(unknown_send);
+
+		let mut unknown = UnknownReport::default();
+		for report in unknown_recv {
+			unknown.merge(report);
+		}
+		for (id, fallback) in self.block_types.fallbacks() {
+			if let Some(entry) = unknown.blocks.get_mut(&id) {
+				entry.fallback = Some(fallback);
+			}
+		}
+
 		if status[Status::OkWithUnknown] > 0 {
+			unknown.warn();
 			warn!("Unknown block or biome types found during processing");
 			eprint!(concat!(
 				"\n",
@@ -417,9 +867,103 @@ impl<'a> RegionProcessor<'a> {
 			));
 		}
 
+		if let Some(path) = &self.config.unknown_report_path {
+			fs::create_with_tmpfile(path, |file| {
+				serde_json::to_writer(file, &unknown).context("Failed to write unknown.json")
+			})?;
+		}
+
+		if self.config.strict && !unknown.is_empty() {
+			bail!("Unknown block or biome types found in strict mode");
+		}
+
 		// Sort regions in a zig-zag pattern to optimize cache usage
 		regions.sort_unstable_by_key(|&TileCoords { x, z }| (x, if x % 2 == 0 { z } else { -z }));
 
 		Ok(regions)
 	}
 }
+
+#[cfg(test)]
+mod test {
+	use std::time::Duration;
+
+	use super::*;
+
+	/// Returns the coordinates of a chunk in a region
+	fn chunk(x: u8, z: u8) -> ChunkCoords {
+		ChunkCoords {
+			x: ChunkX::new(x),
+			z: ChunkZ::new(z),
+		}
+	}
+
+	/// Returns the coordinates of all set entries of a [ChunkArray]
+	fn set_chunks(chunks: &ChunkArray<bool>) -> Vec<(u8, u8)> {
+		chunks
+			.iter()
+			.filter(|(_, &value)| value)
+			.map(|(coords, _)| (coords.x.0, coords.z.0))
+			.collect()
+	}
+
+	#[test]
+	fn test_changed_chunks() {
+		let mut previous = ChunkArray::<Option<u32>>::default();
+		previous[chunk(0, 0)] = Some(100);
+		previous[chunk(1, 0)] = Some(100);
+		previous[chunk(2, 0)] = Some(100);
+		previous[chunk(3, 0)] = Some(0);
+
+		let mut current = previous;
+		// Deleted chunk
+		current[chunk(1, 0)] = None;
+		// Modified chunk
+		current[chunk(2, 0)] = Some(200);
+		// New chunk
+		current[chunk(4, 0)] = Some(100);
+		// Chunk saved in the same second as the previous processing
+		current[chunk(5, 0)] = Some(150);
+		previous[chunk(5, 0)] = Some(150);
+
+		let changed = changed_chunks(
+			&previous,
+			UNIX_EPOCH + Duration::from_millis(150_500),
+			&current,
+		);
+		assert_eq!(
+			set_chunks(&changed),
+			[(1, 0), (2, 0), (3, 0), (4, 0), (5, 0)],
+		);
+	}
+
+	#[test]
+	fn test_with_neighbors() {
+		let mut chunks = ChunkArray::<bool>::default();
+		chunks[chunk(0, 0)] = true;
+		chunks[chunk(31, 10)] = true;
+
+		assert_eq!(
+			set_chunks(&with_neighbors(&chunks)),
+			[
+				(0, 0),
+				(1, 0),
+				(0, 1),
+				(1, 1),
+				(30, 9),
+				(31, 9),
+				(30, 10),
+				(31, 10),
+				(30, 11),
+				(31, 11),
+			],
+		);
+
+		let mut chunks = ChunkArray::<bool>::default();
+		chunks[chunk(5, 31)] = true;
+		assert_eq!(
+			set_chunks(&with_neighbors(&chunks)),
+			[(4, 30), (5, 30), (6, 30), (4, 31), (5, 31), (6, 31)],
+		);
+	}
+}
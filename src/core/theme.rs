@@ -0,0 +1,314 @@
+//! Color grading themes for map tiles
+
+use std::{
+	fs,
+	hash::{Hash, Hasher},
+	path::Path,
+};
+
+use anyhow::{bail, Context, Result};
+use serde::Serialize;
+
+use super::common::parse_color;
+use crate::resource::{Color, Colorf};
+
+/// Sepia toning matrix
+const SEPIA: [[f32; 3]; 3] = [
+	[0.393, 0.769, 0.189],
+	[0.349, 0.686, 0.168],
+	[0.272, 0.534, 0.131],
+];
+
+/// Color correction matrix for viewers with red-green color vision deficiency
+///
+/// Derived by daltonization: the difference between the original colors and
+/// their simulation for deuteranopia is shifted towards the green and blue
+/// components, so red-green contrasts become distinguishable as differences
+/// in brightness and blueness.
+const DALTONIZE: [[f32; 3]; 3] = [
+	[1.0, 0.0, 0.0],
+	[0.1628, 0.7250, 0.1122],
+	[0.4547, -0.6454, 1.1907],
+];
+
+/// Rec. 709 luma coefficients
+const LUMA: [f32; 3] = [0.2126, 0.7152, 0.0722];
+
+/// Returns a matrix scaling the saturation of colors by a factor
+fn saturation(factor: f32) -> [[f32; 3]; 3] {
+	let mut matrix = [LUMA.map(|w| (1.0 - factor) * w); 3];
+	for (i, row) in matrix.iter_mut().enumerate() {
+		row[i] += factor;
+	}
+	matrix
+}
+
+/// Color transformation of a [Theme]
+#[derive(Debug, Clone, Serialize)]
+enum Grading {
+	/// Linear transformation of the color components, followed by a contrast
+	/// adjustment around the middle gray
+	Matrix {
+		/// Transformation matrix (applied to column vectors)
+		matrix: [[f32; 3]; 3],
+		/// Contrast factor (1.0 for unchanged contrast)
+		contrast: f32,
+	},
+	/// 3D lookup table
+	Lut {
+		/// Number of entries along each axis
+		size: usize,
+		/// Table entries in the range 0..=1, with the red index changing fastest
+		table: Vec<[f32; 3]>,
+	},
+	/// Replacement of each color with the nearest color of a palette
+	Palette(Vec<Color>),
+}
+
+impl Grading {
+	/// Applies a [Grading::Lut] to a color with components in the range 0..=1
+	///
+	/// Colors between the table entries are interpolated trilinearly.
+	fn lookup(size: usize, table: &[[f32; 3]], color: Colorf) -> Colorf {
+		let max = (size - 1) as f32;
+		let pos = color.clamp(Colorf::ZERO, Colorf::ONE) * max;
+		let base = pos.floor().min(Colorf::splat(max - 1.0));
+		let frac = pos - base;
+		let [x, y, z] = base.to_array().map(|v| v as usize);
+
+		let at =
+			|dx, dy, dz| Colorf::from(table[(x + dx) + (y + dy) * size + (z + dz) * size * size]);
+		let lerp_x = |dy, dz| at(0, dy, dz).lerp(at(1, dy, dz), frac.x);
+		let lerp_y = |dz| lerp_x(0, dz).lerp(lerp_x(1, dz), frac.y);
+		lerp_y(0).lerp(lerp_y(1), frac.z)
+	}
+
+	/// Applies the grading to a color with components in the range 0..=255
+	fn apply(&self, color: Colorf) -> Colorf {
+		let color = match self {
+			Grading::Matrix { matrix, contrast } => {
+				let transformed = Colorf::new(
+					Colorf::from(matrix[0]).dot(color),
+					Colorf::from(matrix[1]).dot(color),
+					Colorf::from(matrix[2]).dot(color),
+				);
+				(transformed - 127.5) * *contrast + 127.5
+			}
+			Grading::Lut { size, table } => 255.0 * Self::lookup(*size, table, color / 255.0),
+			Grading::Palette(palette) => palette
+				.iter()
+				.map(|&Color(c)| Colorf::from(c.map(f32::from)))
+				.min_by(|a, b| {
+					a.distance_squared(color)
+						.total_cmp(&b.distance_squared(color))
+				})
+				.unwrap_or(color),
+		};
+		color.clamp(Colorf::ZERO, Colorf::splat(255.0))
+	}
+}
+
+/// Parses a 3D lookup table in the `.cube` format
+fn parse_cube(data: &str) -> Result<Grading> {
+	let mut size = None;
+	let mut table = Vec::new();
+
+	for line in data.lines() {
+		let line = line.trim();
+		if line.is_empty() || line.starts_with('#') || line.starts_with("TITLE") {
+			continue;
+		}
+
+		let mut fields = line.split_whitespace();
+		match fields.next() {
+			Some("LUT_3D_SIZE") => {
+				let value: usize = fields.next().context("Missing LUT size")?.parse()?;
+				if !(2..=256).contains(&value) {
+					bail!("Unsupported LUT size {}", value);
+				}
+				size = Some(value);
+			}
+			Some(key @ ("DOMAIN_MIN" | "DOMAIN_MAX")) => {
+				let expected = if key == "DOMAIN_MIN" { 0.0 } else { 1.0 };
+				if !fields.all(|v| v.parse::<f32>().is_ok_and(|v| v == expected)) {
+					bail!("Only LUTs with the domain 0..1 are supported");
+				}
+			}
+			Some("LUT_1D_SIZE") => bail!("1D LUTs are not supported"),
+			_ => {
+				let entry = line
+					.split_whitespace()
+					.map(|v| v.parse::<f32>())
+					.collect::<Result<Vec<_>, _>>()
+					.with_context(|| format!("Invalid LUT entry '{}'", line))?;
+				let Ok(entry) = <[f32; 3]>::try_from(entry) else {
+					bail!("Invalid LUT entry '{}'", line);
+				};
+				table.push(entry);
+			}
+		}
+	}
+
+	let size = size.context("Missing LUT_3D_SIZE")?;
+	if table.len() != size * size * size {
+		bail!(
+			"Expected {} LUT entries, found {}",
+			size * size * size,
+			table.len()
+		);
+	}
+
+	Ok(Grading::Lut { size, table })
+}
+
+/// Parses a palette file listing one color per line
+fn parse_palette(data: &str) -> Result<Grading> {
+	let palette = data
+		.lines()
+		.map(str::trim)
+		.filter(|line| !line.is_empty())
+		.map(parse_color)
+		.collect::<Result<Vec<_>>>()?;
+	if palette.is_empty() {
+		bail!("Palette is empty");
+	}
+
+	Ok(Grading::Palette(palette))
+}
+
+/// A color grading theme
+///
+/// Each theme is rendered as a separate map layer, applying its color
+/// transformation to the tiles of the main map layer.
+#[derive(Debug, Clone)]
+pub struct Theme {
+	/// Layer identifier, used as the name of the tile directory
+	pub id: String,
+	/// Name of the layer shown in the viewer
+	pub name: String,
+	/// Color transformation
+	grading: Grading,
+	/// Hash of the color transformation
+	digest: u64,
+}
+
+impl Theme {
+	/// Constructs a theme, computing the digest of its color transformation
+	fn new(id: String, name: String, grading: Grading) -> Self {
+		let data = serde_json::to_vec(&grading).expect("grading must be serializable");
+		let mut hasher = rustc_hash::FxHasher::default();
+		data.hash(&mut hasher);
+
+		Theme {
+			id,
+			name,
+			grading,
+			digest: hasher.finish(),
+		}
+	}
+
+	/// Constructs a built-in theme
+	fn builtin(name: &str) -> Result<Self> {
+		let (display_name, matrix, contrast) = match name {
+			"sepia" => ("Paper map", SEPIA, 0.85),
+			"high-contrast" => ("High contrast", saturation(1.4), 1.5),
+			"colorblind" => ("Colorblind-safe", DALTONIZE, 1.0),
+			_ => bail!("Unknown theme '{}'", name),
+		};
+
+		Ok(Theme::new(
+			format!("theme-{}", name),
+			display_name.to_string(),
+			Grading::Matrix { matrix, contrast },
+		))
+	}
+
+	/// Loads a user-defined theme from a lookup table or palette file
+	fn load(name: &str, path: &Path) -> Result<Self> {
+		if name.is_empty()
+			|| !name
+				.chars()
+				.all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+		{
+			bail!("Theme name must consist of alphanumeric characters, '-' and '_'");
+		}
+
+		let data = fs::read_to_string(path)
+			.with_context(|| format!("Failed to read {}", path.display()))?;
+		let is_cube = path
+			.extension()
+			.is_some_and(|ext| ext.eq_ignore_ascii_case("cube"));
+		let grading = if is_cube {
+			parse_cube(&data)
+		} else {
+			parse_palette(&data)
+		}
+		.with_context(|| format!("Failed to parse {}", path.display()))?;
+
+		Ok(Theme::new(
+			format!("theme-{}", name),
+			name.to_string(),
+			grading,
+		))
+	}
+
+	/// Parses the value of the `--theme` command line option
+	///
+	/// Accepts the name of a built-in theme or `NAME=PATH` for a theme
+	/// loaded from a file.
+	pub fn parse(theme: &str) -> Result<Self> {
+		match theme.split_once('=') {
+			Some((name, path)) => Self::load(name, Path::new(path)),
+			None => Self::builtin(theme),
+		}
+	}
+
+	/// Returns a hash of the color transformation
+	///
+	/// The hash is computed once when the theme is loaded, so it can be
+	/// included in the versions of generated files cheaply, even for large
+	/// lookup tables.
+	pub fn digest(&self) -> u64 {
+		self.digest
+	}
+
+	/// Applies the color transformation of the theme
+	///
+	/// The color components are given in the range 0..=255.
+	pub fn apply(&self, color: Colorf) -> Colorf {
+		self.grading.apply(color)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_parse_cube() {
+		let identity = "TITLE \"identity\"\nLUT_3D_SIZE 2\n\
+			0 0 0\n1 0 0\n0 1 0\n1 1 0\n0 0 1\n1 0 1\n0 1 1\n1 1 1\n";
+		let grading = parse_cube(identity).unwrap();
+		let color = Colorf::new(10.0, 100.0, 200.0);
+		assert!(grading.apply(color).abs_diff_eq(color, 1e-3));
+
+		assert!(parse_cube("LUT_3D_SIZE 2\n0 0 0\n").is_err());
+		assert!(parse_cube("0 0 0\n").is_err());
+	}
+
+	#[test]
+	fn test_palette() {
+		let grading = parse_palette("#000000\nffffff\n").unwrap();
+		assert_eq!(
+			grading.apply(Colorf::new(100.0, 100.0, 100.0)),
+			Colorf::ZERO
+		);
+		assert_eq!(
+			grading.apply(Colorf::new(200.0, 150.0, 100.0)),
+			Colorf::splat(255.0)
+		);
+
+		assert!(parse_palette("\n").is_err());
+		assert!(parse_palette("red\n").is_err());
+	}
+}
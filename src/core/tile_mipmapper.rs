@@ -155,6 +155,11 @@ pub struct TileMipmapper<'a> {
 	config: &'a Config,
 	/// List of populated tiles for base mipmap level (level 0)
 	regions: &'a [TileCoords],
+	/// Generate mipmaps of the isometric map layer instead of the regular
+	/// map layers
+	///
+	/// The isometric map layer uses its own tile grid.
+	isometric: bool,
 }
 
 impl<'a> TileCollector for TileMipmapper<'a> {
@@ -165,10 +170,39 @@ impl<'a> TileCollector for TileMipmapper<'a> {
 	}
 
 	fn prepare(&self, level: usize) -> Result<()> {
+		if self.isometric {
+			info!("Generating level {} isometric mipmaps...", level);
+			fs::create_dir_all(&self.config.tile_dir(TileKind::Isometric, level))?;
+			return Ok(());
+		}
+
 		info!("Generating level {} mipmaps...", level);
 
-		fs::create_dir_all(&self.config.tile_dir(TileKind::Map, level))?;
+		for layer in 0..self.config.layers.len() {
+			fs::create_dir_all(&self.config.tile_dir(TileKind::Map(layer), level))?;
+		}
 		fs::create_dir_all(&self.config.tile_dir(TileKind::Lightmap, level))?;
+		if self.config.overlay_hillshade().is_some() {
+			fs::create_dir_all(&self.config.tile_dir(TileKind::Shade, level))?;
+		}
+		if self.config.contours.is_some() {
+			fs::create_dir_all(&self.config.tile_dir(TileKind::Contour, level))?;
+		}
+		if self.config.heightmap {
+			fs::create_dir_all(&self.config.tile_dir(TileKind::Heightmap, level))?;
+		}
+		if self.config.biome_layer {
+			fs::create_dir_all(&self.config.tile_dir(TileKind::Biome, level))?;
+		}
+		if self.config.night {
+			fs::create_dir_all(&self.config.tile_dir(TileKind::Night, level))?;
+		}
+		if self.config.vanilla_map {
+			fs::create_dir_all(&self.config.tile_dir(TileKind::VanillaMap, level))?;
+		}
+		for theme in 0..self.config.themes.len() {
+			fs::create_dir_all(&self.config.tile_dir(TileKind::Theme(theme), level))?;
+		}
 
 		Ok(())
 	}
@@ -186,8 +220,9 @@ impl<'a> TileCollector for TileMipmapper<'a> {
 			MipmapStat::add,
 		);
 		info!(
-			"Generated level {} mipmaps ({} processed, {} unchanged)",
+			"Generated level {} {}mipmaps ({} processed, {} unchanged)",
 			level,
+			if self.isometric { "isometric " } else { "" },
 			stat.processed,
 			stat.total - stat.processed,
 		);
@@ -201,17 +236,85 @@ impl<'a> TileCollector for TileMipmapper<'a> {
 		coords: TileCoords,
 		prev: &TileCoordMap,
 	) -> Result<Self::CollectOutput> {
-		let map_stat = self.render_mipmap::<image::Rgba<u8>>(TileKind::Map, level, coords, prev)?;
-		let lightmap_stat =
+		if self.isometric {
+			return self.render_mipmap::<image::Rgba<u8>>(TileKind::Isometric, level, coords, prev);
+		}
+
+		let mut stat =
 			self.render_mipmap::<image::LumaA<u8>>(TileKind::Lightmap, level, coords, prev)?;
-		Ok(map_stat + lightmap_stat)
+		if self.config.overlay_hillshade().is_some() {
+			stat = stat
+				+ self.render_mipmap::<image::LumaA<u8>>(TileKind::Shade, level, coords, prev)?;
+		}
+		if self.config.contours.is_some() {
+			stat = stat
+				+ self.render_mipmap::<image::Rgba<u8>>(TileKind::Contour, level, coords, prev)?;
+		}
+		if self.config.heightmap {
+			stat = stat
+				+ self.render_mipmap::<image::Rgba<u8>>(
+					TileKind::Heightmap,
+					level,
+					coords,
+					prev,
+				)?;
+		}
+		if self.config.biome_layer {
+			stat = stat
+				+ self.render_mipmap::<image::Rgba<u8>>(TileKind::Biome, level, coords, prev)?;
+		}
+		if self.config.night {
+			stat = stat
+				+ self.render_mipmap::<image::Rgba<u8>>(TileKind::Night, level, coords, prev)?;
+		}
+		if self.config.vanilla_map {
+			stat = stat
+				+ self.render_mipmap::<image::Rgba<u8>>(
+					TileKind::VanillaMap,
+					level,
+					coords,
+					prev,
+				)?;
+		}
+		for theme in 0..self.config.themes.len() {
+			stat = stat
+				+ self.render_mipmap::<image::Rgba<u8>>(
+					TileKind::Theme(theme),
+					level,
+					coords,
+					prev,
+				)?;
+		}
+		for layer in 0..self.config.layers.len() {
+			stat = stat
+				+ self.render_mipmap::<image::Rgba<u8>>(
+					TileKind::Map(layer),
+					level,
+					coords,
+					prev,
+				)?;
+		}
+		Ok(stat)
 	}
 }
 
 impl<'a> TileMipmapper<'a> {
 	/// Constructs a new TileMipmapper
 	pub fn new(config: &'a Config, regions: &'a [TileCoords]) -> Self {
-		TileMipmapper { config, regions }
+		TileMipmapper {
+			config,
+			regions,
+			isometric: false,
+		}
+	}
+
+	/// Constructs a new TileMipmapper for the isometric map layer
+	pub fn isometric(config: &'a Config, tiles: &'a [TileCoords]) -> Self {
+		TileMipmapper {
+			config,
+			regions: tiles,
+			isometric: true,
+		}
 	}
 
 	/// Renders and saves a single mipmap tile image
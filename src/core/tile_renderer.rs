@@ -1,24 +1,34 @@
 //! The [TileRenderer] and related types and functions
 
 use std::{
-	num::NonZeroUsize,
-	path::PathBuf,
+	num::{NonZeroU16, NonZeroUsize},
+	path::{Path, PathBuf},
 	sync::{Arc, Mutex},
 	time::SystemTime,
 };
 
 use anyhow::{Context, Result};
+use indexmap::IndexSet;
 use lru::LruCache;
 use rayon::prelude::*;
 use tokio::sync::OnceCell;
 use tracing::{debug, info};
 
-use super::{common::*, region_group::RegionGroup};
+use super::{
+	block_textures::{BlockTexture, BlockTextures},
+	common::*,
+	region_group::RegionGroup,
+	theme::Theme,
+};
 use crate::{
 	io::{fs, storage},
-	resource::{block_color, needs_biome, Colorf},
+	resource::{
+		biome_color, block_color, needs_biome, BlockColor, BlockFlag, BlockTypes, ColorStyle,
+		Colorf, MapBrightness, MapColor,
+	},
 	types::*,
 	util::coord_offset,
+	world::layer::BlockHeight,
 };
 
 /// Type for referencing loaded [ProcessedRegion] data
@@ -56,6 +66,94 @@ fn biome_at(
 	))
 }
 
+/// Offset added to block heights in heightmap data tiles
+///
+/// Heights are stored as unsigned 16-bit values; the value 0 marks columns
+/// without height information.
+pub const HEIGHT_DATA_OFFSET: i32 = 32768;
+
+/// Color stops of the heightmap color ramp
+const HEIGHT_RAMP: &[(f32, Colorf)] = &[
+	(-64.0, Colorf::new(20.0, 30.0, 90.0)),
+	(0.0, Colorf::new(40.0, 90.0, 170.0)),
+	(62.0, Colorf::new(110.0, 180.0, 220.0)),
+	(63.0, Colorf::new(60.0, 130.0, 60.0)),
+	(100.0, Colorf::new(190.0, 190.0, 90.0)),
+	(160.0, Colorf::new(140.0, 90.0, 50.0)),
+	(230.0, Colorf::new(235.0, 235.0, 235.0)),
+	(320.0, Colorf::new(255.0, 255.0, 255.0)),
+];
+
+/// Returns the color of a block height in the heightmap color ramp
+fn height_ramp_color(height: f32) -> Colorf {
+	let upper = HEIGHT_RAMP
+		.iter()
+		.position(|&(stop, _)| stop >= height)
+		.unwrap_or(HEIGHT_RAMP.len() - 1);
+	if upper == 0 {
+		return HEIGHT_RAMP[0].1;
+	}
+
+	let (h0, c0) = HEIGHT_RAMP[upper - 1];
+	let (h1, c1) = HEIGHT_RAMP[upper];
+	c0.lerp(c1, ((height - h0) / (h1 - h0)).clamp(0.0, 1.0))
+}
+
+/// Color of contour lines
+const CONTOUR_COLOR: [u8; 3] = [110, 70, 30];
+
+/// Opacity of minor contour lines
+const CONTOUR_MINOR_ALPHA: u8 = 96;
+
+/// Opacity of major contour lines
+const CONTOUR_MAJOR_ALPHA: u8 = 208;
+
+/// Color of sky light at night
+///
+/// Moonlight is much dimmer than daylight and has a bluish tint.
+const NIGHT_SKY_LIGHT: Colorf = Colorf::new(0.16, 0.18, 0.3);
+
+/// Color of block light from torches and other light sources
+const BLOCK_LIGHT: Colorf = Colorf::new(1.0, 0.85, 0.6);
+
+/// Returns the brightness of a light level
+///
+/// Follows the nonlinear brightness curve used by Minecraft.
+fn light_brightness(level: u8) -> f32 {
+	let f = f32::from(level) / 15.0;
+	f / (4.0 - 3.0 * f)
+}
+
+/// Blends the color of a translucent block over the visible block below it
+///
+/// Returns the resulting color and opacity. The translucent color is passed
+/// together with its opacity.
+fn blend_translucent(
+	block_color: Option<Colorf>,
+	translucent_color: Option<(Colorf, f32)>,
+) -> Option<(Colorf, f32)> {
+	match (block_color, translucent_color) {
+		(Some(color), Some((overlay, alpha))) => Some((color.lerp(overlay, alpha), 1.0)),
+		(Some(color), None) => Some((color, 1.0)),
+		(None, translucent_color) => translucent_color,
+	}
+}
+
+/// Column data used to render texture tiles
+struct TexturedColumn {
+	/// Color of the column on regular map tiles
+	color: Colorf,
+	/// Opacity of the column on regular map tiles
+	alpha: f32,
+	/// Factor applied to the texture colors
+	///
+	/// Accounts for biome tinting, depth and relief shading in the same way
+	/// as for the column color.
+	tint: Colorf,
+	/// Top face texture of the topmost visible block
+	texture: Option<Arc<BlockTexture>>,
+}
+
 /// The TileRenderer generates map tiles from processed region data
 pub struct TileRenderer<'a> {
 	/// Common MinedMap configuration from command line
@@ -66,6 +164,12 @@ pub struct TileRenderer<'a> {
 	regions: &'a [TileCoords],
 	/// Set of populated regions for fast existence checking
 	region_set: rustc_hash::FxHashSet<TileCoords>,
+	/// Index of the map layer to render
+	layer: usize,
+	/// Block textures for texture tiles
+	textures: Option<&'a BlockTextures>,
+	/// Lookup table for Minecraft block types
+	block_types: BlockTypes,
 	/// Cache of previously loaded regions
 	region_cache: Mutex<LruCache<PathBuf, Arc<OnceCell<RegionRef>>>>,
 }
@@ -76,6 +180,8 @@ impl<'a> TileRenderer<'a> {
 		config: &'a Config,
 		rt: &'a tokio::runtime::Runtime,
 		regions: &'a [TileCoords],
+		layer: usize,
+		textures: Option<&'a BlockTextures>,
 	) -> Self {
 		let region_cache = Mutex::new(LruCache::new(
 			NonZeroUsize::new(6 + 6 * config.num_threads).unwrap(),
@@ -86,6 +192,9 @@ impl<'a> TileRenderer<'a> {
 			rt,
 			regions,
 			region_set,
+			layer,
+			textures,
+			block_types: config.overrides.block_types(),
 			region_cache,
 		}
 	}
@@ -122,13 +231,16 @@ impl<'a> TileRenderer<'a> {
 			.await
 	}
 
-	/// Computes the color of a tile pixel
-	fn block_color_at(
+	/// Determines the weights of the biomes around a block coordinate for biome smoothing
+	///
+	/// The returned list contains the relative region coordinates and biome list
+	/// indices of the biomes together with their weights.
+	fn biome_weights(
+		blend: BiomeBlend,
 		region_group: &RegionGroup<RegionRef>,
-		chunk: &ProcessedChunk,
 		chunk_coords: ChunkCoords,
 		block_coords: LayerBlockCoords,
-	) -> Option<Colorf> {
+	) -> Vec<((i8, i8, u16), f32)> {
 		/// Helper for keys in the weight table
 		///
 		/// Hashing the value as a single u32 is more efficient than hashing
@@ -142,22 +254,20 @@ impl<'a> TileRenderer<'a> {
 		/// The kernel is mirrored in X und Z direction to build the full 5x5
 		/// smoothing kernel.
 		const SMOOTH: [[f32; 3]; 3] = [[41.0, 26.0, 7.0], [26.0, 16.0, 4.0], [7.0, 4.0, 1.0]];
-		/// Maximum X coordinate offset to take into account for biome smoothing
-		const X: isize = SMOOTH[0].len() as isize - 1;
-		/// Maximum Z coordinate offset to take into account for biome smoothing
-		const Z: isize = SMOOTH.len() as isize - 1;
 
-		let block = chunk.blocks[block_coords]?;
-		let depth = chunk.depths[block_coords]?;
-
-		if !needs_biome(block) {
-			return Some(block_color(block, None, depth.0 as f32));
-		}
+		// Maximum coordinate offset to take into account for biome smoothing
+		let radius = match blend {
+			BiomeBlend::Smooth => SMOOTH.len() as isize - 1,
+			BiomeBlend::Box(radius) => radius.into(),
+		};
 
 		let mut weights = rustc_hash::FxHashMap::<u32, ((i8, i8, u16), f32)>::default();
-		for dz in -Z..=Z {
-			for dx in -X..=X {
-				let w = SMOOTH[dz.unsigned_abs()][dx.unsigned_abs()];
+		for dz in -radius..=radius {
+			for dx in -radius..=radius {
+				let w = match blend {
+					BiomeBlend::Smooth => SMOOTH[dz.unsigned_abs()][dx.unsigned_abs()],
+					BiomeBlend::Box(_) => 1.0,
+				};
 				if w == 0.0 {
 					continue;
 				}
@@ -178,6 +288,22 @@ impl<'a> TileRenderer<'a> {
 			}
 		}
 
+		weights.into_values().collect()
+	}
+
+	/// Computes the color of a block, smoothing biome-dependent colors
+	/// using the passed biome weights
+	fn smoothed_block_color(
+		style: &ColorStyle,
+		region_group: &RegionGroup<RegionRef>,
+		weights: &[((i8, i8, u16), f32)],
+		block: BlockColor,
+		depth: BlockHeight,
+	) -> Option<Colorf> {
+		if !needs_biome(block) {
+			return Some(block_color(block, None, depth.0 as f32, style));
+		}
+
 		if weights.is_empty() {
 			return None;
 		}
@@ -185,19 +311,163 @@ impl<'a> TileRenderer<'a> {
 		let mut color = Colorf::ZERO;
 		let mut total = 0.0;
 
-		for ((region_x, region_z, index), w) in weights.into_values() {
+		for &((region_x, region_z, index), w) in weights {
 			let region = region_group.get(region_x, region_z)?;
 			let biome = region.biome_list.get_index(index.into())?;
 
 			total += w;
-			color += w * block_color(block, Some(biome), depth.0 as f32);
+			color += w * block_color(block, Some(&biome.biome), depth.0 as f32, style);
 		}
 
 		Some(color / total)
 	}
 
+	/// Computes the color and opacity of a tile pixel
+	///
+	/// The color of a translucent block is blended with the color of the
+	/// visible block below it.
+	fn block_color_at(
+		style: &ColorStyle,
+		blend: BiomeBlend,
+		region_group: &RegionGroup<RegionRef>,
+		chunk: &ProcessedChunk,
+		chunk_coords: ChunkCoords,
+		block_coords: LayerBlockCoords,
+	) -> Option<(Colorf, f32)> {
+		let block = chunk.blocks[block_coords].zip(chunk.depths[block_coords]);
+		let translucent = chunk.translucent_at(block_coords);
+
+		let biome_needed = block.is_some_and(|(block, _)| needs_biome(block))
+			|| translucent.is_some_and(|translucent| needs_biome(translucent.block));
+		let weights = if biome_needed {
+			Self::biome_weights(blend, region_group, chunk_coords, block_coords)
+		} else {
+			Vec::new()
+		};
+
+		let block_color = block.and_then(|(block, depth)| {
+			Self::smoothed_block_color(style, region_group, &weights, block, depth)
+		});
+		let translucent_color = translucent.and_then(|translucent| {
+			let color = Self::smoothed_block_color(
+				style,
+				region_group,
+				&weights,
+				translucent.block,
+				translucent.height,
+			)?;
+			Some((color, translucent.alpha()))
+		});
+
+		blend_translucent(block_color, translucent_color)
+	}
+
+	/// Returns the height of the visible surface of a column
+	///
+	/// The surface height of water columns is unknown unless translucent
+	/// blocks are blended, so [None] is returned for them.
+	fn surface_height(chunk: &ProcessedChunk, block_coords: LayerBlockCoords) -> Option<f32> {
+		if let Some(translucent) = chunk.translucent_at(block_coords) {
+			return Some(translucent.height.0 as f32);
+		}
+		if chunk.blocks[block_coords]?.is(BlockFlag::Water) {
+			return None;
+		}
+		Some(chunk.depths[block_coords]?.0 as f32)
+	}
+
+	/// Returns the chunk and block coordinates of a column at an offset from
+	/// the passed coordinates
+	///
+	/// The column may be part of an adjacent region of the region group.
+	fn column_at(
+		region_group: &RegionGroup<RegionRef>,
+		chunk: ChunkCoords,
+		block: LayerBlockCoords,
+		dx: i32,
+		dz: i32,
+	) -> Option<(&ProcessedChunk, LayerBlockCoords)> {
+		let (region_x, chunk_x, block_x) = coord_offset(chunk.x, block.x, dx);
+		let (region_z, chunk_z, block_z) = coord_offset(chunk.z, block.z, dz);
+		let chunk = ChunkCoords {
+			x: chunk_x,
+			z: chunk_z,
+		};
+		let block = LayerBlockCoords {
+			x: block_x,
+			z: block_z,
+		};
+		let region = region_group.get(region_x, region_z)?;
+		Some((region.chunks[chunk].as_deref()?, block))
+	}
+
+	/// Returns the surface height of a column at an offset from the passed coordinates
+	fn surface_height_at(
+		region_group: &RegionGroup<RegionRef>,
+		chunk: ChunkCoords,
+		block: LayerBlockCoords,
+		dx: i32,
+		dz: i32,
+	) -> Option<f32> {
+		let (chunk, block) = Self::column_at(region_group, chunk, block, dx, dz)?;
+		Self::surface_height(chunk, block)
+	}
+
+	/// Computes the relief shading factor of a column
+	///
+	/// The slope is determined from the surface heights of the neighboring
+	/// columns, which may be part of adjacent regions. Missing neighbors are
+	/// assumed to have the same height as the column itself.
+	fn shading_at(
+		hillshade: &Hillshade,
+		region_group: &RegionGroup<RegionRef>,
+		chunk: &ProcessedChunk,
+		chunk_coords: ChunkCoords,
+		block_coords: LayerBlockCoords,
+	) -> f32 {
+		let Some(height) = Self::surface_height(chunk, block_coords) else {
+			return 1.0;
+		};
+		let height_at = |dx, dz| {
+			Self::surface_height_at(region_group, chunk_coords, block_coords, dx, dz)
+				.unwrap_or(height)
+		};
+
+		let dx = (height_at(1, 0) - height_at(-1, 0)) / 2.0;
+		let dz = (height_at(0, 1) - height_at(0, -1)) / 2.0;
+
+		hillshade.factor(dx, dz)
+	}
+
+	/// Computes the color and opacity of a tile pixel, applying baked
+	/// relief shading if enabled
+	fn pixel_color(
+		style: &ColorStyle,
+		blend: BiomeBlend,
+		hillshade: Option<&Hillshade>,
+		region_group: &RegionGroup<RegionRef>,
+		chunk: &ProcessedChunk,
+		chunk_coords: ChunkCoords,
+		block_coords: LayerBlockCoords,
+	) -> Option<(Colorf, f32)> {
+		let (color, alpha) = Self::block_color_at(
+			style,
+			blend,
+			region_group,
+			chunk,
+			chunk_coords,
+			block_coords,
+		)?;
+		let Some(hillshade) = hillshade else {
+			return Some((color, alpha));
+		};
+		let factor = Self::shading_at(hillshade, region_group, chunk, chunk_coords, block_coords);
+		Some(((color * factor).min(Colorf::splat(255.0)), alpha))
+	}
+
 	/// Renders a chunk subtile into a region tile image
 	fn render_chunk(
+		&self,
 		image: &mut image::RgbaImage,
 		region_group: &RegionGroup<RegionRef>,
 		chunk: &ProcessedChunk,
@@ -206,35 +476,674 @@ impl<'a> TileRenderer<'a> {
 		/// Width/height of a chunk subtile
 		const N: u32 = BLOCKS_PER_CHUNK as u32;
 
+		let style = &self.config.color_style;
+		let blend = self.config.biome_blend;
+		let hillshade = self.config.baked_hillshade();
+
 		let chunk_image = image::RgbaImage::from_fn(N, N, |x, z| {
 			let block_coords = LayerBlockCoords {
 				x: BlockX::new(x),
 				z: BlockZ::new(z),
 			};
-			let color = Self::block_color_at(region_group, chunk, chunk_coords, block_coords);
+			let color = Self::pixel_color(
+				style,
+				blend,
+				hillshade,
+				region_group,
+				chunk,
+				chunk_coords,
+				block_coords,
+			);
 			image::Rgba(
 				color
-					.map(|c| [c[0] as u8, c[1] as u8, c[2] as u8, 255])
+					.map(|(c, a)| [c[0] as u8, c[1] as u8, c[2] as u8, (255.0 * a) as u8])
 					.unwrap_or_default(),
 			)
 		});
 		overlay_chunk(image, &chunk_image, chunk_coords);
 	}
 
+	/// Renders a night view subtile into a region tile image
+	///
+	/// The block colors are multiplied with the combined color of the sky
+	/// light at night and the block light of the blocks above the top layer.
+	fn render_chunk_night(
+		&self,
+		image: &mut image::RgbaImage,
+		region_group: &RegionGroup<RegionRef>,
+		chunk: &ProcessedChunk,
+		chunk_coords: ChunkCoords,
+	) {
+		/// Width/height of a chunk subtile
+		const N: u32 = BLOCKS_PER_CHUNK as u32;
+
+		let style = &self.config.color_style;
+		let blend = self.config.biome_blend;
+		let hillshade = self.config.baked_hillshade();
+
+		let chunk_image = image::RgbaImage::from_fn(N, N, |x, z| {
+			let block_coords = LayerBlockCoords {
+				x: BlockX::new(x),
+				z: BlockZ::new(z),
+			};
+			let Some((color, alpha)) = Self::pixel_color(
+				style,
+				blend,
+				hillshade,
+				region_group,
+				chunk,
+				chunk_coords,
+				block_coords,
+			) else {
+				return image::Rgba([0, 0, 0, 0]);
+			};
+
+			let sky_light = light_brightness(chunk.sky_light[block_coords]) * NIGHT_SKY_LIGHT;
+			let block_light = light_brightness(chunk.block_light[block_coords]) * BLOCK_LIGHT;
+			let color = color * sky_light.max(block_light);
+
+			image::Rgba([
+				color[0] as u8,
+				color[1] as u8,
+				color[2] as u8,
+				(255.0 * alpha) as u8,
+			])
+		});
+		overlay_chunk(image, &chunk_image, chunk_coords);
+	}
+
+	/// Determines the shade of a column on vanilla map tiles
+	///
+	/// Like on Minecraft map items, the shade of water depends on its depth
+	/// (with a checkerboard pattern blending the different shades), while
+	/// other blocks are brighter or darker than the block to their north
+	/// when they are higher or lower.
+	fn vanilla_map_brightness(
+		region_group: &RegionGroup<RegionRef>,
+		chunk: &ProcessedChunk,
+		chunk_coords: ChunkCoords,
+		block_coords: LayerBlockCoords,
+		map_color: MapColor,
+		height: i32,
+	) -> MapBrightness {
+		if map_color == MapColor::WATER {
+			let depth = chunk.depths[block_coords]
+				.map_or(f32::INFINITY, |ground| (height - ground.0) as f32);
+			let checker = (block_coords.x.0 + block_coords.z.0) & 1;
+			let f = depth * 0.1 + f32::from(checker) * 0.2;
+			return if f < 0.5 {
+				MapBrightness::High
+			} else if f > 0.9 {
+				MapBrightness::Low
+			} else {
+				MapBrightness::Normal
+			};
+		}
+
+		let Some(north) = Self::column_at(region_group, chunk_coords, block_coords, 0, -1)
+			.and_then(|(chunk, block)| chunk.heights[block])
+		else {
+			return MapBrightness::Normal;
+		};
+
+		match height.cmp(&north.0) {
+			std::cmp::Ordering::Greater => MapBrightness::High,
+			std::cmp::Ordering::Equal => MapBrightness::Normal,
+			std::cmp::Ordering::Less => MapBrightness::Low,
+		}
+	}
+
+	/// Renders a vanilla map subtile into a region tile image
+	///
+	/// *map_colors* contains the map color of each entry of the region's
+	/// block list. Blocks without a map color are left transparent.
+	fn render_chunk_vanilla_map(
+		map_colors: &[MapColor],
+		image: &mut image::RgbaImage,
+		region_group: &RegionGroup<RegionRef>,
+		chunk: &ProcessedChunk,
+		chunk_coords: ChunkCoords,
+	) {
+		/// Width/height of a chunk subtile
+		const N: u32 = BLOCKS_PER_CHUNK as u32;
+
+		let chunk_image = image::RgbaImage::from_fn(N, N, |x, z| {
+			let block_coords = LayerBlockCoords {
+				x: BlockX::new(x),
+				z: BlockZ::new(z),
+			};
+			let Some((map_color, height)) = chunk.block_ids[block_coords]
+				.and_then(|id| map_colors.get(usize::from(id.get() - 1)).copied())
+				.zip(chunk.heights[block_coords])
+			else {
+				return image::Rgba([0, 0, 0, 0]);
+			};
+			let brightness = Self::vanilla_map_brightness(
+				region_group,
+				chunk,
+				chunk_coords,
+				block_coords,
+				map_color,
+				height.0,
+			);
+			let Some(color) = map_color.color(brightness) else {
+				return image::Rgba([0, 0, 0, 0]);
+			};
+			let [r, g, b] = color.0;
+			image::Rgba([r, g, b, 255])
+		});
+		overlay_chunk(image, &chunk_image, chunk_coords);
+	}
+
+	/// Renders a relief shading overlay subtile into a region tile image
+	///
+	/// Surfaces facing away from the light source are darkened, surfaces
+	/// facing it are brightened.
+	fn render_chunk_shade(
+		hillshade: &Hillshade,
+		image: &mut image::GrayAlphaImage,
+		region_group: &RegionGroup<RegionRef>,
+		chunk: &ProcessedChunk,
+		chunk_coords: ChunkCoords,
+	) {
+		/// Width/height of a chunk subtile
+		const N: u32 = BLOCKS_PER_CHUNK as u32;
+
+		let chunk_image = image::GrayAlphaImage::from_fn(N, N, |x, z| {
+			let block_coords = LayerBlockCoords {
+				x: BlockX::new(x),
+				z: BlockZ::new(z),
+			};
+			let factor =
+				Self::shading_at(hillshade, region_group, chunk, chunk_coords, block_coords);
+			let alpha = (255.0 * (factor - 1.0).abs()).min(255.0) as u8;
+			if factor < 1.0 {
+				image::LumaA([0, alpha])
+			} else {
+				image::LumaA([255, alpha])
+			}
+		});
+		overlay_chunk(image, &chunk_image, chunk_coords);
+	}
+
+	/// Determines whether a column is part of a contour line
+	///
+	/// A column is on a contour line if one of its neighbors lies in a lower
+	/// contour band. Major lines are additionally drawn on the lower side,
+	/// making them two blocks wide. Returns true for major lines, false for
+	/// minor lines, and [None] if there is no line.
+	fn contour_at(
+		contours: &Contours,
+		region_group: &RegionGroup<RegionRef>,
+		chunk: &ProcessedChunk,
+		chunk_coords: ChunkCoords,
+		block_coords: LayerBlockCoords,
+	) -> Option<bool> {
+		let band = contours.band(chunk.depths[block_coords]?.0);
+
+		let mut line = None;
+		for (dx, dz) in [(-1, 0), (1, 0), (0, -1), (0, 1)] {
+			let Some(other) = Self::column_at(region_group, chunk_coords, block_coords, dx, dz)
+				.and_then(|(chunk, block)| chunk.depths[block])
+				.map(|depth| contours.band(depth.0))
+			else {
+				continue;
+			};
+
+			if other < band {
+				line = line.max(Some(contours.has_major(other, band)));
+			} else if other > band && contours.has_major(band, other) {
+				line = Some(true);
+			}
+		}
+
+		line
+	}
+
+	/// Renders a contour line overlay subtile into a region tile image
+	fn render_chunk_contours(
+		contours: &Contours,
+		image: &mut image::RgbaImage,
+		region_group: &RegionGroup<RegionRef>,
+		chunk: &ProcessedChunk,
+		chunk_coords: ChunkCoords,
+	) {
+		/// Width/height of a chunk subtile
+		const N: u32 = BLOCKS_PER_CHUNK as u32;
+
+		let [r, g, b] = CONTOUR_COLOR;
+
+		let chunk_image = image::RgbaImage::from_fn(N, N, |x, z| {
+			let block_coords = LayerBlockCoords {
+				x: BlockX::new(x),
+				z: BlockZ::new(z),
+			};
+			match Self::contour_at(contours, region_group, chunk, chunk_coords, block_coords) {
+				Some(true) => image::Rgba([r, g, b, CONTOUR_MAJOR_ALPHA]),
+				Some(false) => image::Rgba([r, g, b, CONTOUR_MINOR_ALPHA]),
+				None => image::Rgba([0, 0, 0, 0]),
+			}
+		});
+		overlay_chunk(image, &chunk_image, chunk_coords);
+	}
+
+	/// Renders a heightmap subtile into a region tile image
+	///
+	/// Heights are visualized using a color ramp.
+	fn render_chunk_heightmap(
+		image: &mut image::RgbaImage,
+		chunk: &ProcessedChunk,
+		chunk_coords: ChunkCoords,
+	) {
+		/// Width/height of a chunk subtile
+		const N: u32 = BLOCKS_PER_CHUNK as u32;
+
+		let chunk_image = image::RgbaImage::from_fn(N, N, |x, z| {
+			let block_coords = LayerBlockCoords {
+				x: BlockX::new(x),
+				z: BlockZ::new(z),
+			};
+			let Some(depth) = chunk.depths[block_coords] else {
+				return image::Rgba([0, 0, 0, 0]);
+			};
+			let color = height_ramp_color(depth.0 as f32);
+			image::Rgba([color[0] as u8, color[1] as u8, color[2] as u8, 255])
+		});
+		overlay_chunk(image, &chunk_image, chunk_coords);
+	}
+
+	/// Renders a heightmap data subtile into a region tile image
+	///
+	/// See [HEIGHT_DATA_OFFSET] for the encoding of the height values.
+	fn render_chunk_height_data(
+		image: &mut image::ImageBuffer<image::Luma<u16>, Vec<u16>>,
+		chunk: &ProcessedChunk,
+		chunk_coords: ChunkCoords,
+	) {
+		/// Width/height of a chunk subtile
+		const N: u32 = BLOCKS_PER_CHUNK as u32;
+
+		let chunk_image = image::ImageBuffer::from_fn(N, N, |x, z| {
+			let block_coords = LayerBlockCoords {
+				x: BlockX::new(x),
+				z: BlockZ::new(z),
+			};
+			let value = chunk.depths[block_coords].map_or(0, |depth| {
+				(depth.0 + HEIGHT_DATA_OFFSET).clamp(1, u16::MAX.into()) as u16
+			});
+			image::Luma([value])
+		});
+		overlay_chunk(image, &chunk_image, chunk_coords);
+	}
+
+	/// Renders a biome map subtile into a region tile image
+	///
+	/// *colors* contains the biome layer color for each entry of the
+	/// region's biome list.
+	fn render_chunk_biomes(
+		image: &mut image::RgbaImage,
+		colors: &[image::Rgba<u8>],
+		chunk: &ProcessedChunk,
+		chunk_coords: ChunkCoords,
+	) {
+		/// Width/height of a chunk subtile
+		const N: u32 = BLOCKS_PER_CHUNK as u32;
+
+		let chunk_image = image::RgbaImage::from_fn(N, N, |x, z| {
+			let block_coords = LayerBlockCoords {
+				x: BlockX::new(x),
+				z: BlockZ::new(z),
+			};
+			chunk.biomes[block_coords]
+				.and_then(|index| colors.get(usize::from(index.get() - 1)))
+				.copied()
+				.unwrap_or(image::Rgba([0, 0, 0, 0]))
+		});
+		overlay_chunk(image, &chunk_image, chunk_coords);
+	}
+
+	/// Renders a region biome map tile
+	fn render_region_biomes(image: &mut image::RgbaImage, region: &ProcessedRegion) {
+		let colors: Vec<_> = region
+			.biome_list
+			.iter()
+			.map(|biome| {
+				let [r, g, b] = biome_color(&biome.id).0;
+				image::Rgba([r, g, b, 255])
+			})
+			.collect();
+
+		for (coords, chunk) in region.chunks.iter() {
+			let Some(chunk) = chunk else {
+				continue;
+			};
+
+			Self::render_chunk_biomes(image, &colors, chunk, coords);
+		}
+	}
+
+	/// Renders a column data subtile into a region tile image
+	///
+	/// *block_ids* and *biome_ids* map the region's block and biome list
+	/// entries to the values stored in the tile (see [IdDictionary]).
+	fn render_chunk_column_data(
+		image: &mut image::ImageBuffer<image::Rgb<u16>, Vec<u16>>,
+		block_ids: &[u16],
+		biome_ids: &[u16],
+		chunk: &ProcessedChunk,
+		chunk_coords: ChunkCoords,
+	) {
+		/// Width/height of a chunk subtile
+		const N: u32 = BLOCKS_PER_CHUNK as u32;
+
+		let chunk_image = image::ImageBuffer::from_fn(N, N, |x, z| {
+			let block_coords = LayerBlockCoords {
+				x: BlockX::new(x),
+				z: BlockZ::new(z),
+			};
+			let lookup = |ids: &[u16], index: Option<NonZeroU16>| {
+				index
+					.and_then(|index| ids.get(usize::from(index.get() - 1)))
+					.copied()
+					.unwrap_or(0)
+			};
+			let block = lookup(block_ids, chunk.block_ids[block_coords]);
+			let biome = lookup(biome_ids, chunk.biomes[block_coords]);
+			let height = chunk.depths[block_coords].map_or(0, |depth| {
+				(depth.0 + HEIGHT_DATA_OFFSET).clamp(1, u16::MAX.into()) as u16
+			});
+			image::Rgb([block, biome, height])
+		});
+		overlay_chunk(image, &chunk_image, chunk_coords);
+	}
+
+	/// Renders a region column data tile
+	fn render_region_column_data(
+		dictionary: &IdDictionary,
+		image: &mut image::ImageBuffer<image::Rgb<u16>, Vec<u16>>,
+		region: &ProcessedRegion,
+	) {
+		/// Looks up the dictionary values of a list of IDs
+		fn dictionary_values<'i>(
+			dictionary: &IndexSet<String>,
+			ids: impl Iterator<Item = &'i str>,
+		) -> Vec<u16> {
+			ids.map(|id| {
+				dictionary
+					.get_index_of(id)
+					.and_then(|index| u16::try_from(index + 1).ok())
+					.unwrap_or(0)
+			})
+			.collect()
+		}
+
+		let block_ids = dictionary_values(
+			&dictionary.blocks,
+			region.block_list.iter().map(String::as_str),
+		);
+		let biome_ids = dictionary_values(
+			&dictionary.biomes,
+			region.biome_list.iter().map(|biome| biome.id.as_str()),
+		);
+
+		for (coords, chunk) in region.chunks.iter() {
+			let Some(chunk) = chunk else {
+				continue;
+			};
+
+			Self::render_chunk_column_data(image, &block_ids, &biome_ids, chunk, coords);
+		}
+	}
+
+	/// Renders a region heightmap tile
+	fn render_region_heightmap(image: &mut image::RgbaImage, region: &ProcessedRegion) {
+		for (coords, chunk) in region.chunks.iter() {
+			let Some(chunk) = chunk else {
+				continue;
+			};
+
+			Self::render_chunk_heightmap(image, chunk, coords);
+		}
+	}
+
+	/// Renders a region heightmap data tile
+	fn render_region_height_data(
+		image: &mut image::ImageBuffer<image::Luma<u16>, Vec<u16>>,
+		region: &ProcessedRegion,
+	) {
+		for (coords, chunk) in region.chunks.iter() {
+			let Some(chunk) = chunk else {
+				continue;
+			};
+
+			Self::render_chunk_height_data(image, chunk, coords);
+		}
+	}
+
 	/// Renders a region tile image
-	fn render_region(image: &mut image::RgbaImage, region_group: &RegionGroup<RegionRef>) {
+	fn render_region(&self, image: &mut image::RgbaImage, region_group: &RegionGroup<RegionRef>) {
 		for (coords, chunk) in region_group.center().chunks.iter() {
 			let Some(chunk) = chunk else {
 				continue;
 			};
 
-			Self::render_chunk(image, region_group, chunk, coords);
+			self.render_chunk(image, region_group, chunk, coords);
+		}
+	}
+
+	/// Applies the color transformation of a [Theme] to a rendered region
+	/// tile image
+	fn render_region_theme(theme: &Theme, image: &mut image::RgbaImage) {
+		for pixel in image.pixels_mut() {
+			if pixel[3] == 0 {
+				continue;
+			}
+
+			let color = theme.apply(Colorf::new(
+				pixel[0] as f32,
+				pixel[1] as f32,
+				pixel[2] as f32,
+			));
+			*pixel = image::Rgba([color[0] as u8, color[1] as u8, color[2] as u8, pixel[3]]);
+		}
+	}
+
+	/// Renders a region night view tile
+	fn render_region_night(
+		&self,
+		image: &mut image::RgbaImage,
+		region_group: &RegionGroup<RegionRef>,
+	) {
+		for (coords, chunk) in region_group.center().chunks.iter() {
+			let Some(chunk) = chunk else {
+				continue;
+			};
+
+			self.render_chunk_night(image, region_group, chunk, coords);
+		}
+	}
+
+	/// Renders a region vanilla map tile
+	fn render_region_vanilla_map(
+		&self,
+		image: &mut image::RgbaImage,
+		region_group: &RegionGroup<RegionRef>,
+	) {
+		let region = region_group.center();
+		let map_colors: Vec<_> = region
+			.block_list
+			.iter()
+			.map(|id| {
+				self.block_types
+					.get(id)
+					.map_or(MapColor::NONE, |block_type| block_type.map_color)
+			})
+			.collect();
+
+		for (coords, chunk) in region.chunks.iter() {
+			let Some(chunk) = chunk else {
+				continue;
+			};
+
+			Self::render_chunk_vanilla_map(&map_colors, image, region_group, chunk, coords);
+		}
+	}
+
+	/// Renders a region relief shading overlay tile
+	fn render_region_shade(
+		hillshade: &Hillshade,
+		image: &mut image::GrayAlphaImage,
+		region_group: &RegionGroup<RegionRef>,
+	) {
+		for (coords, chunk) in region_group.center().chunks.iter() {
+			let Some(chunk) = chunk else {
+				continue;
+			};
+
+			Self::render_chunk_shade(hillshade, image, region_group, chunk, coords);
+		}
+	}
+
+	/// Renders a region contour line overlay tile
+	fn render_region_contours(
+		contours: &Contours,
+		image: &mut image::RgbaImage,
+		region_group: &RegionGroup<RegionRef>,
+	) {
+		for (coords, chunk) in region_group.center().chunks.iter() {
+			let Some(chunk) = chunk else {
+				continue;
+			};
+
+			Self::render_chunk_contours(contours, image, region_group, chunk, coords);
+		}
+	}
+
+	/// Determines the colors and textures of all columns of a region for
+	/// texture tiles
+	///
+	/// The returned list is indexed by the Z and X coordinates of the
+	/// columns relative to the region.
+	fn textured_columns(
+		&self,
+		textures: &BlockTextures,
+		region_group: &RegionGroup<RegionRef>,
+	) -> Vec<Option<TexturedColumn>> {
+		/// Width/height of a region
+		const N: usize = BLOCKS_PER_CHUNK * CHUNKS_PER_REGION;
+
+		let style = &self.config.color_style;
+		let blend = self.config.biome_blend;
+		let hillshade = self.config.baked_hillshade();
+		let region = region_group.center();
+		let block_textures: Vec<_> = region
+			.block_list
+			.iter()
+			.map(|id| textures.get(id))
+			.collect();
+
+		let mut columns = Vec::new();
+		columns.resize_with(N * N, || None);
+
+		for (chunk_coords, chunk) in region.chunks.iter() {
+			let Some(chunk) = chunk else {
+				continue;
+			};
+
+			for z in 0..BLOCKS_PER_CHUNK as u8 {
+				for x in 0..BLOCKS_PER_CHUNK as u8 {
+					let block_coords = LayerBlockCoords {
+						x: BlockX::new(x),
+						z: BlockZ::new(z),
+					};
+					let Some((color, alpha)) = Self::pixel_color(
+						style,
+						blend,
+						hillshade,
+						region_group,
+						chunk,
+						chunk_coords,
+						block_coords,
+					) else {
+						continue;
+					};
+
+					// The block ID refers to the translucent block if there is one
+					let block = chunk
+						.translucent_at(block_coords)
+						.map(|translucent| translucent.block)
+						.or(chunk.blocks[block_coords]);
+					let tint = block.map_or(Colorf::ONE, |block| {
+						let [r, g, b] = block.color.0;
+						color / Colorf::new(r.into(), g.into(), b.into()).max(Colorf::ONE)
+					});
+					let texture = chunk.block_ids[block_coords].and_then(|index| {
+						block_textures
+							.get(usize::from(index.get() - 1))
+							.cloned()
+							.flatten()
+					});
+
+					let column_x = chunk_coords.x.0 as usize * BLOCKS_PER_CHUNK + x as usize;
+					let column_z = chunk_coords.z.0 as usize * BLOCKS_PER_CHUNK + z as usize;
+					columns[column_z * N + column_x] = Some(TexturedColumn {
+						color,
+						alpha,
+						tint,
+						texture,
+					});
+				}
+			}
 		}
+
+		columns
+	}
+
+	/// Renders a texture tile image
+	///
+	/// *tile* is the position of the texture tile in its region.
+	fn render_texture_tile(
+		columns: &[Option<TexturedColumn>],
+		level: usize,
+		(tile_x, tile_z): (u32, u32),
+	) -> image::RgbaImage {
+		/// Width/height of a tile image
+		const N: u32 = (BLOCKS_PER_CHUNK * CHUNKS_PER_REGION) as u32;
+
+		let size = 1 << level;
+		let blocks = N / size;
+
+		image::RgbaImage::from_fn(N, N, |x, z| {
+			let column_x = tile_x * blocks + x / size;
+			let column_z = tile_z * blocks + z / size;
+			let Some(column) = &columns[(column_z * N + column_x) as usize] else {
+				return image::Rgba([0, 0, 0, 0]);
+			};
+
+			let color = match &column.texture {
+				Some(texture) => {
+					let [r, g, b, a] = texture.level(level).get_pixel(x % size, z % size).0;
+					let texel = Colorf::new(r.into(), g.into(), b.into()) * column.tint;
+					column
+						.color
+						.lerp(texel, f32::from(a) / 255.0)
+						.min(Colorf::splat(255.0))
+				}
+				None => column.color,
+			};
+
+			image::Rgba([
+				color[0] as u8,
+				color[1] as u8,
+				color[2] as u8,
+				(255.0 * column.alpha) as u8,
+			])
+		})
 	}
 
 	/// Returns the filename of the processed data for a region and the time of its last modification
 	fn processed_source(&self, coords: TileCoords) -> Result<(PathBuf, SystemTime)> {
-		let path = self.config.processed_path(coords);
+		let path = self.config.processed_path(self.layer, coords);
 		let timestamp = fs::modified_timestamp(&path)?;
 		Ok((path, timestamp))
 	}
@@ -262,15 +1171,16 @@ impl<'a> TileRenderer<'a> {
 		Ok((paths, max_timestamp))
 	}
 
-	/// Renders and saves a region tile image
-	fn render_tile(&self, coords: TileCoords) -> Result<bool> {
-		/// Width/height of a tile image
-		const N: u32 = (BLOCKS_PER_CHUNK * CHUNKS_PER_REGION) as u32;
-
-		let (processed_paths, processed_timestamp) = self.processed_sources(coords)?;
-
-		let output_path = self.config.tile_path(TileKind::Map, 0, coords);
-		let output_timestamp = fs::read_timestamp(&output_path, MAP_FILE_META_VERSION);
+	/// Returns the path of a tile if it needs to be regenerated
+	fn outdated_tile(
+		&self,
+		kind: TileKind,
+		coords: TileCoords,
+		version: fs::FileMetaVersion,
+		processed_timestamp: SystemTime,
+	) -> Option<PathBuf> {
+		let output_path = self.config.tile_path(kind, 0, coords);
+		let output_timestamp = fs::read_timestamp(&output_path, version);
 
 		if Some(processed_timestamp) <= output_timestamp {
 			debug!(
@@ -280,7 +1190,7 @@ impl<'a> TileRenderer<'a> {
 					.expect("tile path must be in output directory")
 					.display(),
 			);
-			return Ok(false);
+			return None;
 		}
 
 		debug!(
@@ -291,32 +1201,257 @@ impl<'a> TileRenderer<'a> {
 				.display(),
 		);
 
+		Some(output_path)
+	}
+
+	/// Returns the texture tiles of a region that need to be regenerated
+	///
+	/// Each entry contains the texture level, the position of the tile in
+	/// the region and the output path.
+	fn outdated_texture_tiles(
+		&self,
+		coords: TileCoords,
+		version: fs::FileMetaVersion,
+		processed_timestamp: SystemTime,
+	) -> Vec<(usize, (u32, u32), PathBuf)> {
+		let mut outputs = Vec::new();
+		if self.textures().is_none() {
+			return outputs;
+		}
+
+		for level in 1..=TEXTURE_LEVELS {
+			let n = 1 << level;
+			for tile_z in 0..n {
+				for tile_x in 0..n {
+					let path = self.config.texture_tile_path(
+						level,
+						TileCoords {
+							x: coords.x * n as i32 + tile_x as i32,
+							z: coords.z * n as i32 + tile_z as i32,
+						},
+					);
+					if Some(processed_timestamp) <= fs::read_timestamp(&path, version) {
+						continue;
+					}
+					outputs.push((level, (tile_x, tile_z), path));
+				}
+			}
+		}
+
+		if outputs.is_empty() {
+			debug!("Skipping unchanged texture tiles of region {:?}", coords);
+		} else {
+			debug!("Rendering texture tiles of region {:?}", coords);
+		}
+
+		outputs
+	}
+
+	/// Returns the block textures if texture tiles are rendered by this TileRenderer
+	///
+	/// The texture tiles are generated from the main map layer.
+	fn textures(&self) -> Option<&BlockTextures> {
+		self.textures.filter(|_| self.layer == 0)
+	}
+
+	/// Returns the relief shading configuration if an overlay is rendered by this TileRenderer
+	///
+	/// The overlay is generated from the main map layer.
+	fn shade_overlay(&self) -> Option<&Hillshade> {
+		self.config.overlay_hillshade().filter(|_| self.layer == 0)
+	}
+
+	/// Returns the kinds of tiles generated by this TileRenderer
+	///
+	/// Additional tiles like relief shading and contour line overlays,
+	/// heightmaps, biome maps, night views, vanilla maps, color-graded maps
+	/// and column data are generated from the main map layer.
+	fn tile_kinds(&self) -> Vec<TileKind> {
+		let mut kinds = vec![TileKind::Map(self.layer)];
+
+		if self.shade_overlay().is_some() {
+			kinds.push(TileKind::Shade);
+		}
+		if self.config.contours.is_some() && self.layer == 0 {
+			kinds.push(TileKind::Contour);
+		}
+		if self.config.heightmap && self.layer == 0 {
+			kinds.push(TileKind::Heightmap);
+			kinds.push(TileKind::HeightData);
+		}
+		if self.config.biome_layer && self.layer == 0 {
+			kinds.push(TileKind::Biome);
+		}
+		if self.config.night && self.layer == 0 {
+			kinds.push(TileKind::Night);
+		}
+		if self.config.vanilla_map && self.layer == 0 {
+			kinds.push(TileKind::VanillaMap);
+		}
+		if self.layer == 0 {
+			kinds.extend((0..self.config.themes.len()).map(TileKind::Theme));
+		}
+		if self.config.column_data.is_some() && self.layer == 0 {
+			kinds.push(TileKind::ColumnData);
+		}
+
+		kinds
+	}
+
+	/// Saves a rendered tile image
+	fn save_tile<P: image::PixelWithColorType>(
+		path: &Path,
+		version: fs::FileMetaVersion,
+		timestamp: SystemTime,
+		image: &image::ImageBuffer<P, Vec<P::Subpixel>>,
+	) -> Result<()>
+	where
+		[P::Subpixel]: image::EncodableLayout,
+	{
+		fs::create_with_timestamp(path, version, timestamp, |file| {
+			image
+				.write_to(file, image::ImageFormat::Png)
+				.context("Failed to save image")
+		})
+	}
+
+	/// Renders and saves the region tile images of all tile kinds
+	fn render_tile(&self, coords: TileCoords) -> Result<bool> {
+		/// Width/height of a tile image
+		const N: u32 = (BLOCKS_PER_CHUNK * CHUNKS_PER_REGION) as u32;
+
+		let (processed_paths, processed_timestamp) = self.processed_sources(coords)?;
+
+		let outputs: Vec<_> = self
+			.tile_kinds()
+			.into_iter()
+			.filter_map(|kind| {
+				let version = self.config.tile_file_meta_version(kind);
+				let path = self.outdated_tile(kind, coords, version, processed_timestamp)?;
+				Some((kind, version, path))
+			})
+			.collect();
+
+		let texture_version = self.config.texture_file_meta_version();
+		let texture_outputs =
+			self.outdated_texture_tiles(coords, texture_version, processed_timestamp);
+
+		if outputs.is_empty() && texture_outputs.is_empty() {
+			return Ok(false);
+		}
+
 		let region_group = self
 			.rt
 			.block_on(self.load_region_group(processed_paths))
 			.with_context(|| format!("Region {:?} from previous step must be loadable", coords))?;
-		let mut image = image::RgbaImage::new(N, N);
-		Self::render_region(&mut image, &region_group);
-
-		fs::create_with_timestamp(
-			&output_path,
-			MAP_FILE_META_VERSION,
-			processed_timestamp,
-			|file| {
+
+		// The map colors are rendered once and shared by the map tile and
+		// the color-graded tiles
+		let map_image = std::cell::OnceCell::new();
+		let map_image = || {
+			map_image.get_or_init(|| {
+				let mut image = image::RgbaImage::new(N, N);
+				self.render_region(&mut image, &region_group);
 				image
-					.write_to(file, image::ImageFormat::Png)
-					.context("Failed to save image")
-			},
-		)?;
+			})
+		};
+
+		for (kind, version, path) in outputs {
+			match kind {
+				TileKind::Map(_) => {
+					Self::save_tile(&path, version, processed_timestamp, map_image())?;
+				}
+				TileKind::Theme(theme) => {
+					let theme = &self.config.themes[theme];
+					let mut image = map_image().clone();
+					Self::render_region_theme(theme, &mut image);
+					Self::save_tile(&path, version, processed_timestamp, &image)?;
+				}
+				TileKind::Shade => {
+					let hillshade = self.shade_overlay().expect("shade overlay must be enabled");
+					let mut image = image::GrayAlphaImage::new(N, N);
+					Self::render_region_shade(hillshade, &mut image, &region_group);
+					Self::save_tile(&path, version, processed_timestamp, &image)?;
+				}
+				TileKind::Contour => {
+					let contours = self
+						.config
+						.contours
+						.as_ref()
+						.expect("contour lines must be enabled");
+					let mut image = image::RgbaImage::new(N, N);
+					Self::render_region_contours(contours, &mut image, &region_group);
+					Self::save_tile(&path, version, processed_timestamp, &image)?;
+				}
+				TileKind::Heightmap => {
+					let mut image = image::RgbaImage::new(N, N);
+					Self::render_region_heightmap(&mut image, region_group.center());
+					Self::save_tile(&path, version, processed_timestamp, &image)?;
+				}
+				TileKind::HeightData => {
+					let mut image = image::ImageBuffer::<image::Luma<u16>, _>::new(N, N);
+					Self::render_region_height_data(&mut image, region_group.center());
+					Self::save_tile(&path, version, processed_timestamp, &image)?;
+				}
+				TileKind::Biome => {
+					let mut image = image::RgbaImage::new(N, N);
+					Self::render_region_biomes(&mut image, region_group.center());
+					Self::save_tile(&path, version, processed_timestamp, &image)?;
+				}
+				TileKind::Night => {
+					let mut image = image::RgbaImage::new(N, N);
+					self.render_region_night(&mut image, &region_group);
+					Self::save_tile(&path, version, processed_timestamp, &image)?;
+				}
+				TileKind::VanillaMap => {
+					let mut image = image::RgbaImage::new(N, N);
+					self.render_region_vanilla_map(&mut image, &region_group);
+					Self::save_tile(&path, version, processed_timestamp, &image)?;
+				}
+				TileKind::ColumnData => {
+					let dictionary = self
+						.config
+						.column_data
+						.as_ref()
+						.expect("column data must be enabled");
+					let mut image = image::ImageBuffer::<image::Rgb<u16>, _>::new(N, N);
+					Self::render_region_column_data(dictionary, &mut image, region_group.center());
+					Self::save_tile(&path, version, processed_timestamp, &image)?;
+				}
+				TileKind::Lightmap => {
+					unreachable!("lightmaps are generated by the RegionProcessor")
+				}
+				TileKind::Isometric => {
+					unreachable!("isometric tiles are generated by the IsometricRenderer")
+				}
+			}
+		}
+
+		if let Some(textures) = self.textures().filter(|_| !texture_outputs.is_empty()) {
+			let columns = self.textured_columns(textures, &region_group);
+			for (level, tile, path) in texture_outputs {
+				let image = Self::render_texture_tile(&columns, level, tile);
+				Self::save_tile(&path, texture_version, processed_timestamp, &image)?;
+			}
+		}
 
 		Ok(true)
 	}
 
 	/// Runs the tile generation
 	pub fn run(self) -> Result<()> {
-		fs::create_dir_all(&self.config.tile_dir(TileKind::Map, 0))?;
+		for kind in self.tile_kinds() {
+			fs::create_dir_all(&self.config.tile_dir(kind, 0))?;
+		}
+		if self.textures().is_some() {
+			for level in 1..=TEXTURE_LEVELS {
+				fs::create_dir_all(&self.config.texture_tile_dir(level))?;
+			}
+		}
+
+		let id = &self.config.layers[self.layer].id;
 
-		info!("Rendering map tiles...");
+		info!("Rendering {} tiles...", id);
 
 		// Use par_bridge to process items in order (for better use of region cache)
 		let processed = self
@@ -332,7 +1467,8 @@ impl<'a> TileRenderer<'a> {
 			.try_reduce(|| 0, |a, b| Ok(a + b))?;
 
 		info!(
-			"Rendered map tiles ({} processed, {} unchanged)",
+			"Rendered {} tiles ({} processed, {} unchanged)",
+			id,
 			processed,
 			self.regions.len() - processed,
 		);
@@ -340,3 +1476,29 @@ impl<'a> TileRenderer<'a> {
 		Ok(())
 	}
 }
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_blend_translucent() {
+		let block = Colorf::new(200.0, 100.0, 0.0);
+		let overlay = Colorf::new(0.0, 0.0, 100.0);
+
+		assert_eq!(blend_translucent(None, None), None);
+		assert_eq!(blend_translucent(Some(block), None), Some((block, 1.0)));
+		assert_eq!(
+			blend_translucent(None, Some((overlay, 0.5))),
+			Some((overlay, 0.5))
+		);
+		assert_eq!(
+			blend_translucent(Some(block), Some((overlay, 0.5))),
+			Some((Colorf::new(100.0, 50.0, 50.0), 1.0))
+		);
+		assert_eq!(
+			blend_translucent(Some(block), Some((overlay, 1.0))),
+			Some((overlay, 1.0))
+		);
+	}
+}
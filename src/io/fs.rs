@@ -2,6 +2,7 @@
 
 use std::{
 	fs::{self, File},
+	hash::{Hash, Hasher},
 	io::{BufReader, BufWriter, Read, Write},
 	path::{Path, PathBuf},
 	time::SystemTime,
@@ -16,6 +17,19 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 pub struct FileMetaVersion(pub u32);
 
+impl FileMetaVersion {
+	/// Derives a version number that additionally depends on configuration data
+	///
+	/// Generated files are considered outdated when the configuration options
+	/// affecting their contents change.
+	pub fn with_config<T: Serialize>(self, config: &T) -> Self {
+		let data = serde_json::to_vec(config).expect("configuration must be serializable");
+		let mut hasher = rustc_hash::FxHasher::default();
+		data.hash(&mut hasher);
+		FileMetaVersion(self.0 ^ (hasher.finish() as u32))
+	}
+}
+
 /// Metadata stored with generated files to track required incremental updates
 #[derive(Debug, Serialize, Deserialize)]
 struct FileMeta {
@@ -1,4 +1,5 @@
 //! Input/output functions
 
 pub mod fs;
+pub mod resource_pack;
 pub mod storage;
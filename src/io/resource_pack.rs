@@ -0,0 +1,155 @@
+//! Access to the files of Minecraft resource packs and datapacks
+
+use std::{
+	collections::BTreeSet,
+	fs::{self, File},
+	io::{self, BufReader, Read},
+	path::{Path, PathBuf},
+	sync::Mutex,
+};
+
+use anyhow::{Context, Result};
+use serde::de::DeserializeOwned;
+
+/// A source of resource pack files
+enum Source {
+	/// Unpacked resource pack directory
+	Directory(PathBuf),
+	/// Resource pack ZIP archive or Minecraft client JAR file
+	Archive(Mutex<zip::ZipArchive<BufReader<File>>>),
+}
+
+/// A stack of resource packs
+///
+/// Files are looked up in the sources in reverse order, so later sources
+/// override files of earlier ones, like resource packs loaded on top of
+/// the vanilla assets in Minecraft.
+pub struct ResourcePack {
+	/// Resource pack sources in order of increasing priority
+	sources: Vec<Source>,
+}
+
+impl ResourcePack {
+	/// Opens a list of resource pack directories and archives
+	pub fn open(paths: &[PathBuf]) -> Result<Self> {
+		let sources = paths
+			.iter()
+			.map(|path| {
+				Self::open_source(path)
+					.with_context(|| format!("Failed to open resource pack {}", path.display()))
+			})
+			.collect::<Result<_>>()?;
+
+		Ok(ResourcePack { sources })
+	}
+
+	/// Opens a single resource pack directory or archive
+	fn open_source(path: &Path) -> Result<Source> {
+		if path.is_dir() {
+			return Ok(Source::Directory(path.to_path_buf()));
+		}
+
+		let file = File::open(path)?;
+		let archive = zip::ZipArchive::new(BufReader::new(file))?;
+		Ok(Source::Archive(Mutex::new(archive)))
+	}
+
+	/// Reads a file from the resource pack
+	///
+	/// *name* is the path of the file relative to the root of the resource
+	/// pack, like `assets/minecraft/textures/block/stone.png`. [None] is
+	/// returned if no source contains the file.
+	pub fn read(&self, name: &str) -> Result<Option<Vec<u8>>> {
+		for source in self.sources.iter().rev() {
+			let data = match source {
+				Source::Directory(dir) => match fs::read(dir.join(name)) {
+					Ok(data) => data,
+					Err(err) if err.kind() == io::ErrorKind::NotFound => continue,
+					Err(err) => {
+						return Err(err).with_context(|| format!("Failed to read {}", name))
+					}
+				},
+				Source::Archive(archive) => {
+					let mut archive = archive.lock().unwrap();
+					let mut file = match archive.by_name(name) {
+						Ok(file) => file,
+						Err(zip::result::ZipError::FileNotFound) => continue,
+						Err(err) => {
+							return Err(err).with_context(|| format!("Failed to read {}", name))
+						}
+					};
+					let mut data = Vec::new();
+					file.read_to_end(&mut data)
+						.with_context(|| format!("Failed to read {}", name))?;
+					data
+				}
+			};
+
+			return Ok(Some(data));
+		}
+
+		Ok(None)
+	}
+
+	/// Lists the files of a directory of the resource pack recursively
+	///
+	/// *prefix* is the path of the directory relative to the root of the
+	/// resource pack, like `data/`. The returned paths are relative to the
+	/// root as well.
+	pub fn list(&self, prefix: &str) -> Result<BTreeSet<String>> {
+		let mut files = BTreeSet::new();
+
+		for source in &self.sources {
+			match source {
+				Source::Directory(dir) => {
+					Self::list_dir(dir, Path::new(prefix), &mut files)?;
+				}
+				Source::Archive(archive) => {
+					let archive = archive.lock().unwrap();
+					files.extend(
+						archive
+							.file_names()
+							.filter(|name| name.starts_with(prefix) && !name.ends_with('/'))
+							.map(String::from),
+					);
+				}
+			}
+		}
+
+		Ok(files)
+	}
+
+	/// Adds the files of a resource pack directory to a set recursively
+	fn list_dir(root: &Path, dir: &Path, files: &mut BTreeSet<String>) -> Result<()> {
+		let entries = match fs::read_dir(root.join(dir)) {
+			Ok(entries) => entries,
+			Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(()),
+			Err(err) => {
+				return Err(err).with_context(|| format!("Failed to list {}", dir.display()))
+			}
+		};
+
+		for entry in entries {
+			let entry = entry.with_context(|| format!("Failed to list {}", dir.display()))?;
+			let path = dir.join(entry.file_name());
+			if entry.file_type()?.is_dir() {
+				Self::list_dir(root, &path, files)?;
+			} else if let Some(path) = path.to_str() {
+				files.insert(path.replace(std::path::MAIN_SEPARATOR, "/"));
+			}
+		}
+
+		Ok(())
+	}
+
+	/// Reads and deserializes a JSON file from the resource pack
+	pub fn read_json<T: DeserializeOwned>(&self, name: &str) -> Result<Option<T>> {
+		let Some(data) = self.read(name)? else {
+			return Ok(None);
+		};
+
+		let value =
+			serde_json::from_slice(&data).with_context(|| format!("Failed to parse {}", name))?;
+		Ok(Some(value))
+	}
+}
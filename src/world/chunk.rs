@@ -23,7 +23,7 @@ pub enum ChunkInner<'a> {
 	/// Minecraft v1.18+ chunk with biome data moved into sections
 	V1_18 {
 		/// Section data
-		section_map: BTreeMap<SectionY, (SectionV1_13<'a>, BiomesV1_18<'a>, BlockLight<'a>)>,
+		section_map: BTreeMap<SectionY, (SectionV1_13<'a>, BiomesV1_18<'a>, SectionLight<'a>)>,
 	},
 	/// Minecraft v1.13+ chunk
 	///
@@ -33,7 +33,7 @@ pub enum ChunkInner<'a> {
 	/// block IDs
 	V1_13 {
 		/// Section data
-		section_map: BTreeMap<SectionY, (SectionV1_13<'a>, BlockLight<'a>)>,
+		section_map: BTreeMap<SectionY, (SectionV1_13<'a>, SectionLight<'a>)>,
 		/// Biome data
 		biomes: BiomesV0<'a>,
 	},
@@ -42,7 +42,7 @@ pub enum ChunkInner<'a> {
 	/// The original chunk format with fixed 8-bit numeric block IDs
 	V0 {
 		/// Section data
-		section_map: BTreeMap<SectionY, (SectionV0<'a>, BlockLight<'a>)>,
+		section_map: BTreeMap<SectionY, (SectionV0<'a>, SectionLight<'a>)>,
 		/// Biome data
 		biomes: BiomesV0<'a>,
 	},
@@ -50,6 +50,19 @@ pub enum ChunkInner<'a> {
 	Empty,
 }
 
+/// Unknown block and biome IDs found in a chunk
+///
+/// Each entry consists of the occurrences of an ID in a section and the Y
+/// coordinate of the section. Block types resolved using
+/// [BlockTypes::get_fallback] are included.
+#[derive(Debug, Default)]
+pub struct UnknownIds<'a> {
+	/// Unknown block IDs
+	pub blocks: Vec<(UnknownId<'a>, SectionY)>,
+	/// Unknown biome IDs
+	pub biomes: Vec<(UnknownId<'a>, SectionY)>,
+}
+
 /// Chunk data structure wrapping a [de::Chunk] for convenient access to
 /// block and biome data
 #[derive(Debug)]
@@ -66,13 +79,14 @@ impl<'a> Chunk<'a> {
 		data: &'a de::Chunk,
 		block_types: &'a BlockTypes,
 		biome_types: &'a BiomeTypes,
-	) -> Result<(Self, bool)> {
+	) -> Result<(Self, UnknownIds<'a>)> {
 		let data_version = data.data_version.unwrap_or_default();
 
-		let ((inner, has_unknown), block_entities) = match &data.chunk {
+		let ((inner, unknown), block_entities) = match &data.chunk {
 			de::ChunkVariant::V1_18 {
 				sections,
 				block_entities,
+				..
 			} => (
 				Self::new_v1_18(data_version, sections, block_types, biome_types)?,
 				block_entities,
@@ -88,7 +102,7 @@ impl<'a> Chunk<'a> {
 				inner,
 				block_entities,
 			},
-			has_unknown,
+			unknown,
 		))
 	}
 
@@ -98,9 +112,9 @@ impl<'a> Chunk<'a> {
 		sections: &'a Vec<de::SectionV1_18>,
 		block_types: &'a BlockTypes,
 		biome_types: &'a BiomeTypes,
-	) -> Result<(ChunkInner<'a>, bool)> {
+	) -> Result<(ChunkInner<'a>, UnknownIds<'a>)> {
 		let mut section_map = BTreeMap::new();
-		let mut has_unknown = false;
+		let mut unknown = UnknownIds::default();
 
 		for section in sections {
 			match &section.section {
@@ -108,6 +122,7 @@ impl<'a> Chunk<'a> {
 					block_states,
 					biomes,
 					block_light,
+					sky_light,
 				} => {
 					let (loaded_section, unknown_blocks) = SectionV1_13::new(
 						data_version,
@@ -116,23 +131,29 @@ impl<'a> Chunk<'a> {
 						block_types,
 					)
 					.with_context(|| format!("Failed to load section at Y={}", section.y))?;
-					has_unknown |= unknown_blocks;
+					let y = SectionY(section.y);
+					unknown
+						.blocks
+						.extend(unknown_blocks.into_iter().map(|entry| (entry, y)));
 
 					let (loaded_biomes, unknown_biomes) =
 						BiomesV1_18::new(biomes.data.as_deref(), &biomes.palette, biome_types)
 							.with_context(|| {
 								format!("Failed to load section biomes at Y={}", section.y)
 							})?;
-					has_unknown |= unknown_biomes;
+					unknown
+						.biomes
+						.extend(unknown_biomes.into_iter().map(|entry| (entry, y)));
 
 					section_map.insert(
-						SectionY(section.y),
+						y,
 						(
 							loaded_section,
 							loaded_biomes,
-							BlockLight::new(block_light.as_deref()).with_context(|| {
-								format!("Failed to load section block light at Y={}", section.y)
-							})?,
+							SectionLight::new(block_light.as_deref(), sky_light.as_deref())
+								.with_context(|| {
+									format!("Failed to load section light at Y={}", section.y)
+								})?,
 						),
 					);
 				}
@@ -141,7 +162,7 @@ impl<'a> Chunk<'a> {
 		}
 
 		let chunk = ChunkInner::V1_18 { section_map };
-		Ok((chunk, has_unknown))
+		Ok((chunk, unknown))
 	}
 
 	/// [Chunk::new] implementation for all pre-1.18 chunk variants
@@ -150,16 +171,15 @@ impl<'a> Chunk<'a> {
 		level: &'a de::LevelV0,
 		block_types: &'a BlockTypes,
 		biome_types: &'a BiomeTypes,
-	) -> Result<(ChunkInner<'a>, bool)> {
+	) -> Result<(ChunkInner<'a>, UnknownIds<'a>)> {
 		let mut section_map_v1_13 = BTreeMap::new();
 		let mut section_map_v0 = BTreeMap::new();
-		let mut has_unknown = false;
+		let mut unknown = UnknownIds::default();
 
 		for section in &level.sections {
-			let block_light =
-				BlockLight::new(section.block_light.as_deref()).with_context(|| {
-					format!("Failed to load section block light at Y={}", section.y)
-				})?;
+			let light =
+				SectionLight::new(section.block_light.as_deref(), section.sky_light.as_deref())
+					.with_context(|| format!("Failed to load section light at Y={}", section.y))?;
 			match &section.section {
 				de::SectionV0Variant::V1_13 {
 					block_states,
@@ -168,10 +188,12 @@ impl<'a> Chunk<'a> {
 					let (loaded_section, unknown_blocks) =
 						SectionV1_13::new(data_version, Some(block_states), palette, block_types)
 							.with_context(|| format!("Failed to load section at Y={}", section.y))?;
-					has_unknown |= unknown_blocks;
+					let y = SectionY(section.y.into());
+					unknown
+						.blocks
+						.extend(unknown_blocks.into_iter().map(|entry| (entry, y)));
 
-					section_map_v1_13
-						.insert(SectionY(section.y.into()), (loaded_section, block_light));
+					section_map_v1_13.insert(y, (loaded_section, light));
 				}
 				de::SectionV0Variant::V0 { blocks, data } => {
 					section_map_v0.insert(
@@ -180,7 +202,7 @@ impl<'a> Chunk<'a> {
 							SectionV0::new(blocks, data, block_types).with_context(|| {
 								format!("Failed to load section at Y={}", section.y)
 							})?,
-							block_light,
+							light,
 						),
 					);
 				}
@@ -204,7 +226,7 @@ impl<'a> Chunk<'a> {
 			}
 		};
 
-		Ok((chunk, has_unknown))
+		Ok((chunk, unknown))
 	}
 
 	/// Returns true if the chunk does not contain any sections
@@ -218,7 +240,7 @@ impl<'a> Chunk<'a> {
 	}
 
 	/// Returns an interator over the chunk's sections and their Y coordinates
-	pub fn sections(&self) -> SectionIter {
+	pub fn sections(&self) -> SectionIter<'_> {
 		use SectionIterInner::*;
 		SectionIter {
 			inner: match &self.inner {
@@ -244,6 +266,41 @@ impl<'a> Chunk<'a> {
 		}
 	}
 
+	/// Returns block, biome and light data of the section at a [SectionY] coordinate
+	pub fn section(&self, y: SectionY) -> Option<SectionIterItem<'_>> {
+		match &self.inner {
+			ChunkInner::V1_18 { section_map } => {
+				section_map
+					.get(&y)
+					.map(|(section, biomes, light)| SectionIterItem {
+						y,
+						section,
+						biomes,
+						light: *light,
+					})
+			}
+			ChunkInner::V1_13 {
+				section_map,
+				biomes,
+			} => section_map.get(&y).map(|(section, light)| SectionIterItem {
+				y,
+				section,
+				biomes,
+				light: *light,
+			}),
+			ChunkInner::V0 {
+				section_map,
+				biomes,
+			} => section_map.get(&y).map(|(section, light)| SectionIterItem {
+				y,
+				section,
+				biomes,
+				light: *light,
+			}),
+			ChunkInner::Empty => None,
+		}
+	}
+
 	/// Returns the section at a [SectionY] coordinate
 	fn section_at(&self, y: SectionY) -> Option<&dyn Section> {
 		match &self.inner {
@@ -299,7 +356,7 @@ impl<'a> Chunk<'a> {
 	}
 }
 
-/// Reference to block, biome and block light data of a section
+/// Reference to block, biome and light data of a section
 #[derive(Debug, Clone, Copy)]
 pub struct SectionIterItem<'a> {
 	/// The Y coordinate of the section
@@ -308,8 +365,8 @@ pub struct SectionIterItem<'a> {
 	pub section: &'a dyn Section,
 	/// Section biome data
 	pub biomes: &'a dyn Biomes,
-	/// Section block light data
-	pub block_light: BlockLight<'a>,
+	/// Section block light and sky light data
+	pub light: SectionLight<'a>,
 }
 
 /// Helper trait to specify section iterator trait bounds
@@ -332,19 +389,19 @@ enum SectionIterInner<'a> {
 	/// Iterator over sections of [ChunkInner::V1_18]
 	V1_18 {
 		/// Inner iterator into section map
-		iter: btree_map::Iter<'a, SectionY, (SectionV1_13<'a>, BiomesV1_18<'a>, BlockLight<'a>)>,
+		iter: btree_map::Iter<'a, SectionY, (SectionV1_13<'a>, BiomesV1_18<'a>, SectionLight<'a>)>,
 	},
 	/// Iterator over sections of [ChunkInner::V1_13]
 	V1_13 {
 		/// Inner iterator into section map
-		iter: btree_map::Iter<'a, SectionY, (SectionV1_13<'a>, BlockLight<'a>)>,
+		iter: btree_map::Iter<'a, SectionY, (SectionV1_13<'a>, SectionLight<'a>)>,
 		/// Chunk biome data
 		biomes: &'a BiomesV0<'a>,
 	},
 	/// Iterator over sections of [ChunkInner::V0]
 	V0 {
 		/// Inner iterator into section map
-		iter: btree_map::Iter<'a, SectionY, (SectionV0<'a>, BlockLight<'a>)>,
+		iter: btree_map::Iter<'a, SectionY, (SectionV0<'a>, SectionLight<'a>)>,
 		/// Chunk biome data
 		biomes: &'a BiomesV0<'a>,
 	},
@@ -367,29 +424,29 @@ impl<'a> SectionIter<'a> {
 	{
 		match &mut self.inner {
 			SectionIterInner::V1_18 { iter } => f(&mut iter.map(
-				|(&y, (section, biomes, block_light))| SectionIterItem {
+				|(&y, (section, biomes, light))| SectionIterItem {
 					y,
 					section,
 					biomes,
-					block_light: *block_light,
+					light: *light,
 				},
 			)),
-			SectionIterInner::V1_13 { iter, biomes } => f(&mut iter.map(
-				|(&y, (section, block_light))| SectionIterItem {
+			SectionIterInner::V1_13 { iter, biomes } => {
+				f(&mut iter.map(|(&y, (section, light))| SectionIterItem {
 					y,
 					section,
 					biomes: *biomes,
-					block_light: *block_light,
-				},
-			)),
-			SectionIterInner::V0 { iter, biomes } => f(&mut iter.map(
-				|(&y, (section, block_light))| SectionIterItem {
+					light: *light,
+				}))
+			}
+			SectionIterInner::V0 { iter, biomes } => {
+				f(&mut iter.map(|(&y, (section, light))| SectionIterItem {
 					y,
 					section,
 					biomes: *biomes,
-					block_light: *block_light,
-				},
-			)),
+					light: *light,
+				}))
+			}
 			SectionIterInner::Empty => f(&mut iter::empty()),
 		}
 	}
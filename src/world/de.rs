@@ -1,5 +1,7 @@
 //! Data structures used to deserialize Minecraft save data
 
+use std::collections::BTreeMap;
+
 use serde::Deserialize;
 
 use super::json_text::JSONText;
@@ -10,6 +12,9 @@ use super::json_text::JSONText;
 pub struct BlockStatePaletteEntry {
 	/// Block type ID
 	pub name: String,
+	/// Block state properties
+	#[serde(default)]
+	pub properties: BTreeMap<String, String>,
 }
 
 /// 1.18+ `block_states` element found in a [section](SectionV1_18)
@@ -43,6 +48,9 @@ pub enum SectionV1_18Variant {
 		/// Block light data
 		#[serde(rename = "BlockLight")]
 		block_light: Option<fastnbt::ByteArray>,
+		/// Sky light data
+		#[serde(rename = "SkyLight")]
+		sky_light: Option<fastnbt::ByteArray>,
 	},
 	/// Empty section
 	Empty {},
@@ -91,6 +99,8 @@ pub struct SectionV0 {
 	pub y: i8,
 	/// Block light data
 	pub block_light: Option<fastnbt::ByteArray>,
+	/// Sky light data
+	pub sky_light: Option<fastnbt::ByteArray>,
 	/// Version-specific data
 	#[serde(flatten)]
 	pub section: SectionV0Variant,
@@ -189,6 +199,11 @@ pub struct LevelV0 {
 	/// List of block entities
 	#[serde(default)]
 	pub tile_entities: Vec<BlockEntity>,
+	/// Set when the light data of the chunk has been computed (1.14+)
+	#[serde(rename = "isLightOn")]
+	pub is_light_on: Option<bool>,
+	/// Set when the light data of the chunk has been computed (pre-1.14)
+	pub light_populated: Option<bool>,
 }
 
 /// Version-specific part of a [Chunk] compound
@@ -202,6 +217,9 @@ pub enum ChunkVariant {
 		/// List of block entities
 		#[serde(default)]
 		block_entities: Vec<BlockEntity>,
+		/// Set when the light data of the chunk has been computed
+		#[serde(rename = "isLightOn")]
+		is_light_on: Option<bool>,
 	},
 	/// Pre-1.18 chunk data
 	#[serde(rename_all = "PascalCase")]
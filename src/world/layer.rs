@@ -1,14 +1,14 @@
 //! Functions to search the "top" layer of a chunk
 
-use std::num::NonZeroU16;
+use std::{num::NonZeroU16, str::FromStr};
 
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use indexmap::IndexSet;
 use serde::{Deserialize, Serialize};
 
 use super::chunk::{Chunk, SectionIterItem};
 use crate::{
-	resource::{Biome, BlockColor, BlockFlag},
+	resource::{BiomeType, BlockColor, BlockFlag, BlockType},
 	types::*,
 };
 
@@ -40,22 +40,272 @@ pub type BlockArray = LayerBlockArray<Option<BlockColor>>;
 /// Indices are stored incremented by 1 to allow using a [NonZeroU16].
 pub type BiomeArray = LayerBlockArray<Option<NonZeroU16>>;
 
+/// Array optionally storing a block ID index for each coordinate of a chunk
+///
+/// The entries refer to a block ID list generated with the top layer data.
+/// Indices are stored incremented by 1 to allow using a [NonZeroU16].
+pub type BlockIdArray = LayerBlockArray<Option<NonZeroU16>>;
+
 /// Array storing a block light value for each coordinate for a chunk
 pub type BlockLightArray = LayerBlockArray<u8>;
 
+/// Array storing a sky light value for each coordinate for a chunk
+pub type SkyLightArray = LayerBlockArray<u8>;
+
 /// Array optionally storing a depth value for each coordinate for a chunk
 pub type DepthArray = LayerBlockArray<Option<BlockHeight>>;
 
+/// Array optionally storing the surface height for each coordinate for a chunk
+///
+/// Unlike the depth value, the surface height includes water and other
+/// translucent blocks.
+pub type HeightArray = LayerBlockArray<Option<BlockHeight>>;
+
+/// Translucent block on top of a column
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TranslucentBlock {
+	/// Color of the topmost translucent block
+	pub block: BlockColor,
+	/// Height of the topmost translucent block
+	pub height: BlockHeight,
+	/// Number of translucent blocks of the same type above the visible block
+	///
+	/// Saturates at 255.
+	pub count: u8,
+}
+
+impl TranslucentBlock {
+	/// Returns the combined opacity of all counted blocks
+	pub fn alpha(&self) -> f32 {
+		let alpha = f32::from(self.block.alpha) / 255.0;
+		1.0 - (1.0 - alpha).powi(self.count.into())
+	}
+}
+
+/// Array optionally storing a [TranslucentBlock] for each coordinate of a chunk
+pub type TranslucentArray = LayerBlockArray<Option<TranslucentBlock>>;
+
+/// Predefined groups of block types for use in [VisibilityRule]s
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BlockTag {
+	/// All kinds of leaves
+	Leaves,
+	/// Water, bubble columns and underwater plants
+	Water,
+	/// Glass blocks and panes, including stained and tinted glass
+	Glass,
+	/// Snow layers
+	Snow,
+}
+
+impl BlockTag {
+	/// Checks whether a block type is part of the group
+	fn contains(self, block_type: &BlockType) -> bool {
+		let Some(name) = block_type.id.strip_prefix("minecraft:") else {
+			return false;
+		};
+
+		match self {
+			BlockTag::Leaves => name.ends_with("_leaves"),
+			BlockTag::Water => {
+				block_type.block_color.is(BlockFlag::Water)
+					|| matches!(name, "kelp" | "kelp_plant" | "seagrass" | "tall_seagrass")
+			}
+			BlockTag::Glass => name.ends_with("glass") || name.ends_with("glass_pane"),
+			BlockTag::Snow => name == "snow",
+		}
+	}
+}
+
+/// A rule selecting block types that are treated as transparent
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VisibilityRule {
+	/// Matches a single block type by its namespaced ID
+	Block(String),
+	/// Matches all block types of a [BlockTag]
+	Tag(BlockTag),
+}
+
+impl VisibilityRule {
+	/// Checks whether a block type is matched by the rule
+	fn matches(&self, block_type: &BlockType) -> bool {
+		match self {
+			VisibilityRule::Block(id) => block_type.id == *id,
+			VisibilityRule::Tag(tag) => tag.contains(block_type),
+		}
+	}
+}
+
+impl FromStr for VisibilityRule {
+	type Err = anyhow::Error;
+
+	/// Parses a block ID (namespace defaulting to `minecraft`) or a tag
+	/// prefixed with `#` (like `#leaves`)
+	fn from_str(s: &str) -> Result<Self> {
+		if let Some(tag) = s.strip_prefix('#') {
+			let tag = match tag {
+				"leaves" => BlockTag::Leaves,
+				"water" => BlockTag::Water,
+				"glass" => BlockTag::Glass,
+				"snow" => BlockTag::Snow,
+				_ => bail!("Unknown block tag '{}'", tag),
+			};
+			return Ok(VisibilityRule::Tag(tag));
+		}
+
+		if s.is_empty() {
+			bail!("Empty block ID");
+		}
+
+		Ok(VisibilityRule::Block(if s.contains(':') {
+			s.to_string()
+		} else {
+			format!("minecraft:{}", s)
+		}))
+	}
+}
+
+/// Options controlling the search for the top layer of a chunk
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct LayerOptions {
+	/// Skip the solid ceiling at the top of the level
+	///
+	/// When set, the topmost visible blocks of each column (like the bedrock
+	/// roof of the Nether) are skipped, and the first visible block below a gap
+	/// of invisible blocks under the ceiling is used as the top layer.
+	pub skip_ceiling: bool,
+	/// Lowest Y coordinate at which a skipped ceiling may start
+	///
+	/// Columns whose topmost visible block is below this height have no
+	/// ceiling (for example where the bedrock roof of the Nether has been
+	/// removed). Visible blocks above a gap in the ceiling are skipped as
+	/// well if the gap is at or above this height, so structures built on
+	/// top of the roof do not hide it. When unset, the topmost visible
+	/// blocks of each column are always skipped.
+	pub ceiling_min_y: Option<i32>,
+	/// Maximum Y coordinate of blocks to consider
+	///
+	/// Blocks above the given height are ignored, making the blocks below
+	/// visible.
+	pub max_y: Option<i32>,
+	/// Rules for block types that are treated as transparent
+	///
+	/// Matching blocks are ignored, so the blocks below them become visible.
+	pub transparent: Vec<VisibilityRule>,
+	/// Blend translucent blocks with the blocks below them
+	///
+	/// When set, blocks with the [BlockFlag::Translucent] flag are recorded
+	/// as [TranslucentBlock]s, and the search continues below them. Otherwise,
+	/// translucent blocks are handled like other opaque blocks.
+	pub blend_translucent: bool,
+	/// The dimension has sky light
+	///
+	/// Minecraft does not store sky light data for sections exposed to the
+	/// sky, and neither for dimensions without sky light like the Nether and
+	/// the End. When unset, the sky light of all columns is 0 instead of
+	/// treating the missing data as full sky light.
+	pub has_sky_light: bool,
+}
+
+impl LayerOptions {
+	/// Checks whether a block height is within the range of considered blocks
+	pub fn contains(&self, height: BlockHeight) -> bool {
+		match self.max_y {
+			Some(max_y) => height.0 <= max_y,
+			None => true,
+		}
+	}
+
+	/// Checks whether a block type is visible on the top layer
+	///
+	/// Only opaque blocks that are not matched by any of the transparency
+	/// rules are visible.
+	pub fn is_visible(&self, block_type: &BlockType) -> bool {
+		block_type.block_color.is(BlockFlag::Opaque)
+			&& !self.transparent.iter().any(|rule| rule.matches(block_type))
+	}
+}
+
+/// Progress of skipping the ceiling of a block column
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum CeilingState {
+	/// No opaque block has been encountered yet
+	#[default]
+	Above,
+	/// The scan is inside the solid ceiling
+	Inside,
+	/// The ceiling has been skipped (or ceiling skipping is disabled)
+	Below,
+}
+
+impl CeilingState {
+	/// Returns the state after scanning a block
+	///
+	/// *visible* is true for blocks visible on the top layer, *height* is
+	/// the Y coordinate of the block.
+	fn next(self, visible: bool, height: i32, ceiling_min_y: Option<i32>) -> Self {
+		use CeilingState::*;
+
+		let in_ceiling_range = ceiling_min_y.map_or(true, |min_y| height >= min_y);
+
+		match (self, visible) {
+			(Below, _) => Below,
+			// Visible blocks below the range of the ceiling are not part of a ceiling
+			(Above, true) if !in_ceiling_range => Below,
+			(_, true) => Inside,
+			// Further ceiling blocks may follow a gap in the range of the ceiling
+			(Inside, false) if ceiling_min_y.is_some() && in_ceiling_range => Above,
+			(Inside, false) => Below,
+			(Above, false) => Above,
+		}
+	}
+}
+
+/// Advances the ceiling skipping state of a block column
+///
+/// Returns true when the block at the passed coordinates is below the
+/// ceiling and should be considered for the top layer.
+fn skip_ceiling(
+	state: &mut CeilingState,
+	section: SectionIterItem,
+	coords: SectionBlockCoords,
+	options: &LayerOptions,
+) -> Result<bool> {
+	if *state == CeilingState::Below {
+		return Ok(true);
+	}
+
+	let visible = section
+		.section
+		.block_at(coords)?
+		.is_some_and(|block_type| options.is_visible(block_type));
+	let height = BlockHeight::new(section.y, coords.y)?;
+
+	*state = state.next(visible, height.0, options.ceiling_min_y);
+
+	Ok(*state == CeilingState::Below)
+}
+
 /// References to LayerData entries for a single coordinate pair
 struct LayerEntry<'a> {
 	/// The block type of the referenced entry
 	block: &'a mut Option<BlockColor>,
+	/// The block ID of the topmost visible block of the referenced entry
+	block_id: &'a mut Option<NonZeroU16>,
 	/// The biome type of the referenced entry
 	biome: &'a mut Option<NonZeroU16>,
 	/// The block light of the referenced entry
 	block_light: &'a mut u8,
+	/// The sky light of the referenced entry
+	sky_light: &'a mut u8,
 	/// The depth value of the referenced entry
 	depth: &'a mut Option<BlockHeight>,
+	/// The surface height of the referenced entry
+	height: &'a mut Option<BlockHeight>,
+	/// The translucent block of the referenced entry
+	translucent: &'a mut Option<TranslucentBlock>,
 }
 
 impl<'a> LayerEntry<'a> {
@@ -64,7 +314,81 @@ impl<'a> LayerEntry<'a> {
 	/// The depth value is filled separately when a non-water block is encountered after the block type
 	/// has already been filled.
 	fn is_empty(&self) -> bool {
-		self.block.is_none()
+		self.block.is_none() && self.translucent.is_none()
+	}
+
+	/// Fills in the block ID of the entry if it has not been set yet
+	fn fill_block_id(&mut self, block_list: &mut IndexSet<String>, block_type: &BlockType) {
+		if self.block_id.is_some() {
+			return;
+		}
+
+		let block_index = match block_list.get_index_of(block_type.id.as_str()) {
+			Some(index) => index,
+			None => block_list.insert_full(block_type.id.clone()).0,
+		};
+		*self.block_id = NonZeroU16::new(
+			(block_index + 1)
+				.try_into()
+				.expect("block index not in range"),
+		);
+	}
+
+	/// Fills in the biome of the entry
+	fn fill_biome(
+		&mut self,
+		biome_list: &mut IndexSet<BiomeType>,
+		section: SectionIterItem,
+		coords: SectionBlockCoords,
+	) -> Result<()> {
+		if let Some(biome) = section.biomes.biome_at(section.y, coords)? {
+			let biome_index = match biome_list.get_index_of(biome) {
+				Some(index) => index,
+				None => biome_list.insert_full(biome.clone()).0,
+			};
+			*self.biome = NonZeroU16::new(
+				(biome_index + 1)
+					.try_into()
+					.expect("biome index not in range"),
+			);
+		}
+
+		Ok(())
+	}
+
+	/// Records a translucent block
+	///
+	/// Only the topmost translucent block type of a column is recorded;
+	/// further blocks of the same type increase its count, while other
+	/// translucent types below it are ignored.
+	fn fill_translucent(
+		&mut self,
+		block_list: &mut IndexSet<String>,
+		biome_list: &mut IndexSet<BiomeType>,
+		section: SectionIterItem,
+		coords: SectionBlockCoords,
+		block_type: &BlockType,
+	) -> Result<()> {
+		if self.block.is_some() {
+			return Ok(());
+		}
+
+		let block = block_type.block_color;
+		self.fill_block_id(block_list, block_type);
+
+		if let Some(translucent) = self.translucent.as_mut() {
+			if translucent.block == block {
+				translucent.count = translucent.count.saturating_add(1);
+			}
+			return Ok(());
+		}
+
+		*self.translucent = Some(TranslucentBlock {
+			block,
+			height: BlockHeight::new(section.y, coords.y)?,
+			count: 1,
+		});
+		self.fill_biome(biome_list, section, coords)
 	}
 
 	/// Returns true if the entry has been filled including its depth (an opaque non-water block has been
@@ -75,35 +399,46 @@ impl<'a> LayerEntry<'a> {
 
 	/// Fills in the LayerEntry
 	///
-	/// Checks whether the passed coordinates point at an opaque or non-water block and
+	/// Checks whether the passed coordinates point at a visible or non-water block and
 	/// fills in the entry accordingly. Returns true when the block has been filled including its depth.
 	fn fill(
 		&mut self,
-		biome_list: &mut IndexSet<Biome>,
+		block_list: &mut IndexSet<String>,
+		biome_list: &mut IndexSet<BiomeType>,
 		section: SectionIterItem,
 		coords: SectionBlockCoords,
+		options: &LayerOptions,
 	) -> Result<bool> {
 		let Some(block_type) = section
 			.section
 			.block_at(coords)?
-			.filter(|block_type| block_type.block_color.is(BlockFlag::Opaque))
+			.filter(|block_type| options.is_visible(block_type))
 		else {
 			if self.is_empty() {
-				*self.block_light = section.block_light.block_light_at(coords);
+				*self.block_light = section.light.block_light_at(coords);
+				*self.sky_light = if options.has_sky_light {
+					section.light.sky_light_at(coords)
+				} else {
+					0
+				};
 			}
 
 			return Ok(false);
 		};
 
-		if self.is_empty() {
+		let height = BlockHeight::new(section.y, coords.y)?;
+		self.height.get_or_insert(height);
+
+		if options.blend_translucent && block_type.block_color.is(BlockFlag::Translucent) {
+			self.fill_translucent(block_list, biome_list, section, coords, block_type)?;
+			return Ok(false);
+		}
+
+		if self.block.is_none() {
 			*self.block = Some(block_type.block_color);
-			if let Some(biome) = section.biomes.biome_at(section.y, coords)? {
-				let (biome_index, _) = biome_list.insert_full(*biome);
-				*self.biome = NonZeroU16::new(
-					(biome_index + 1)
-						.try_into()
-						.expect("biome index not in range"),
-				);
+			self.fill_block_id(block_list, block_type);
+			if self.biome.is_none() {
+				self.fill_biome(biome_list, section, coords)?;
 			}
 		}
 
@@ -111,7 +446,6 @@ impl<'a> LayerEntry<'a> {
 			return Ok(false);
 		}
 
-		let height = BlockHeight::new(section.y, coords.y)?;
 		*self.depth = Some(height);
 
 		Ok(true)
@@ -120,28 +454,40 @@ impl<'a> LayerEntry<'a> {
 
 /// Top layer data
 ///
-/// A LayerData stores block type, biome, block light and depth data for
+/// A LayerData stores block type, biome, light, depth and height data for
 /// each coordinate of a chunk.
 #[derive(Debug, Default)]
 pub struct LayerData {
 	/// Block type data
 	pub blocks: Box<BlockArray>,
+	/// Block ID data
+	pub block_ids: Box<BlockIdArray>,
 	/// Biome data
 	pub biomes: Box<BiomeArray>,
 	/// Block light data
 	pub block_light: Box<BlockLightArray>,
+	/// Sky light data
+	pub sky_light: Box<SkyLightArray>,
 	/// Depth data
 	pub depths: Box<DepthArray>,
+	/// Surface height data
+	pub heights: Box<HeightArray>,
+	/// Translucent block data
+	pub translucent: Box<TranslucentArray>,
 }
 
 impl LayerData {
 	/// Builds a [LayerEntry] referencing the LayerData at a given coordinate pair
-	fn entry(&mut self, coords: LayerBlockCoords) -> LayerEntry {
+	fn entry(&mut self, coords: LayerBlockCoords) -> LayerEntry<'_> {
 		LayerEntry {
 			block: &mut self.blocks[coords],
+			block_id: &mut self.block_ids[coords],
 			biome: &mut self.biomes[coords],
 			block_light: &mut self.block_light[coords],
+			sky_light: &mut self.sky_light[coords],
 			depth: &mut self.depths[coords],
+			height: &mut self.heights[coords],
+			translucent: &mut self.translucent[coords],
 		}
 	}
 }
@@ -154,7 +500,14 @@ impl LayerData {
 /// map. For water blocks, the height of the first non-water block
 /// is additionally filled in as the water depth (the block height is
 /// used as depth otherwise).
-pub fn top_layer(biome_list: &mut IndexSet<Biome>, chunk: &Chunk) -> Result<Option<LayerData>> {
+///
+/// See [LayerOptions] for ways to modify which blocks are considered.
+pub fn top_layer(
+	block_list: &mut IndexSet<String>,
+	biome_list: &mut IndexSet<BiomeType>,
+	chunk: &Chunk,
+	options: &LayerOptions,
+) -> Result<Option<LayerData>> {
 	use BLOCKS_PER_CHUNK as N;
 
 	if chunk.is_empty() {
@@ -162,10 +515,26 @@ pub fn top_layer(biome_list: &mut IndexSet<Biome>, chunk: &Chunk) -> Result<Opti
 	}
 
 	let mut done = 0;
-	let mut ret = LayerData::default();
+	// Columns without blocks above the top layer are exposed to the sky
+	let sky_light = if options.has_sky_light { 15 } else { 0 };
+	let mut ret = LayerData {
+		sky_light: Box::new(LayerBlockArray([[sky_light; N]; N])),
+		..Default::default()
+	};
+
+	let initial_ceiling_state = if options.skip_ceiling {
+		CeilingState::Above
+	} else {
+		CeilingState::Below
+	};
+	let mut ceiling = LayerBlockArray([[initial_ceiling_state; N]; N]);
 
 	for section in chunk.sections().rev() {
 		for y in BlockY::iter().rev() {
+			if !options.contains(BlockHeight::new(section.y, y)?) {
+				continue;
+			}
+
 			for z in BlockZ::iter() {
 				for x in BlockX::iter() {
 					let xz = LayerBlockCoords { x, z };
@@ -176,7 +545,10 @@ pub fn top_layer(biome_list: &mut IndexSet<Biome>, chunk: &Chunk) -> Result<Opti
 					}
 
 					let coords = SectionBlockCoords { xz, y };
-					if !entry.fill(biome_list, section, coords)? {
+					if !skip_ceiling(&mut ceiling[xz], section, coords, options)? {
+						continue;
+					}
+					if !entry.fill(block_list, biome_list, section, coords, options)? {
 						continue;
 					}
 
@@ -192,3 +564,177 @@ pub fn top_layer(biome_list: &mut IndexSet<Biome>, chunk: &Chunk) -> Result<Opti
 
 	Ok(Some(ret))
 }
+
+/// Determines the height of the topmost visible block of each column of a chunk
+///
+/// Like [top_layer], the search honors the [LayerOptions], but translucent
+/// blocks are handled like other visible blocks. All blocks above the
+/// returned heights are either not visible or part of a skipped ceiling.
+pub fn column_tops(
+	chunk: &Chunk,
+	options: &LayerOptions,
+) -> Result<LayerBlockArray<Option<BlockHeight>>> {
+	use BLOCKS_PER_CHUNK as N;
+
+	let mut ret = LayerBlockArray::<Option<BlockHeight>>::default();
+	let mut done = 0;
+
+	let initial_ceiling_state = if options.skip_ceiling {
+		CeilingState::Above
+	} else {
+		CeilingState::Below
+	};
+	let mut ceiling = LayerBlockArray([[initial_ceiling_state; N]; N]);
+
+	for section in chunk.sections().rev() {
+		for y in BlockY::iter().rev() {
+			let height = BlockHeight::new(section.y, y)?;
+			if !options.contains(height) {
+				continue;
+			}
+
+			for z in BlockZ::iter() {
+				for x in BlockX::iter() {
+					let xz = LayerBlockCoords { x, z };
+					if ret[xz].is_some() {
+						continue;
+					}
+
+					let coords = SectionBlockCoords { xz, y };
+					if !skip_ceiling(&mut ceiling[xz], section, coords, options)? {
+						continue;
+					}
+					if !section
+						.section
+						.block_at(coords)?
+						.is_some_and(|block_type| options.is_visible(block_type))
+					{
+						continue;
+					}
+
+					ret[xz] = Some(height);
+					done += 1;
+					if done == N * N {
+						return Ok(ret);
+					}
+				}
+			}
+		}
+	}
+
+	Ok(ret)
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::{
+		resource::{BiomeTypes, BlockTypes},
+		world::de,
+	};
+
+	/// Returns a section of a single block type without light data
+	fn section(y: i32, block: &str) -> de::SectionV1_18 {
+		de::SectionV1_18 {
+			y,
+			section: de::SectionV1_18Variant::V1_18 {
+				block_states: de::BlockStatesV1_18 {
+					palette: vec![de::BlockStatePaletteEntry {
+						name: block.to_string(),
+						properties: Default::default(),
+					}],
+					data: None,
+				},
+				biomes: de::BiomesV1_18 {
+					palette: vec!["minecraft:plains".to_string()],
+					data: None,
+				},
+				block_light: None,
+				sky_light: None,
+			},
+		}
+	}
+
+	#[test]
+	fn test_sky_light() {
+		let data = de::Chunk {
+			data_version: Some(3700),
+			chunk: de::ChunkVariant::V1_18 {
+				sections: vec![section(3, "minecraft:stone"), section(4, "minecraft:air")],
+				block_entities: Vec::new(),
+				is_light_on: None,
+			},
+		};
+		let block_types = BlockTypes::default();
+		let biome_types = BiomeTypes::default();
+		let (chunk, _) = Chunk::new(&data, &block_types, &biome_types).unwrap();
+
+		let sky_light = |has_sky_light| {
+			let options = LayerOptions {
+				has_sky_light,
+				..Default::default()
+			};
+			let layer = top_layer(&mut IndexSet::new(), &mut IndexSet::new(), &chunk, &options)
+				.unwrap()
+				.unwrap();
+			assert_eq!(layer.heights.0[0][0], Some(BlockHeight(63)));
+			layer.sky_light.0[0][0]
+		};
+
+		// Missing sky light data means full sky light...
+		assert_eq!(sky_light(true), 15);
+		// ...unless the dimension has no sky light
+		assert_eq!(sky_light(false), 0);
+	}
+
+	/// Returns the height of the first block below the ceiling of a column
+	///
+	/// The column is given from top to bottom, starting at Y=*top*, with `#`
+	/// marking visible blocks.
+	fn first_visible(column: &str, top: i32, ceiling_min_y: Option<i32>) -> Option<i32> {
+		let mut state = CeilingState::Above;
+		for (i, block) in column.chars().enumerate() {
+			let height = top - i as i32;
+			let visible = block == '#';
+			state = state.next(visible, height, ceiling_min_y);
+			if state == CeilingState::Below && visible {
+				return Some(height);
+			}
+		}
+		None
+	}
+
+	#[test]
+	fn test_translucent_alpha() {
+		let block = |alpha, count| TranslucentBlock {
+			block: BlockColor {
+				flags: BlockFlag::Opaque | BlockFlag::Translucent,
+				color: crate::resource::Color([0, 0, 255]),
+				alpha,
+			},
+			height: BlockHeight(62),
+			count,
+		};
+
+		assert_eq!(block(255, 1).alpha(), 1.0);
+		assert_eq!(block(0, 10).alpha(), 0.0);
+		assert!((block(128, 1).alpha() - 128.0 / 255.0).abs() < 1e-6);
+		// Stacked blocks become more opaque
+		assert!((block(51, 2).alpha() - 0.36).abs() < 1e-6);
+		assert!(block(128, 3).alpha() > block(128, 2).alpha());
+	}
+
+	#[test]
+	fn test_ceiling_state() {
+		// Intact roof
+		assert_eq!(first_visible("..#####..#", 129, Some(123)), Some(120));
+		assert_eq!(first_visible("..#####..#", 129, None), Some(120));
+		// Structure on top of the roof
+		assert_eq!(first_visible("#..###..#", 130, Some(123)), Some(122));
+		// Missing roof
+		assert_eq!(first_visible("..........#..#", 129, Some(123)), Some(119));
+		assert_eq!(first_visible("..........#..#", 129, None), Some(116));
+		// No visible blocks
+		assert_eq!(first_visible("......", 129, Some(123)), None);
+	}
+}
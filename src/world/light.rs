@@ -0,0 +1,544 @@
+//! Computation of block light for chunks without stored light data
+//!
+//! Minecraft only stores light data for chunks that have been processed by
+//! its light engine. Chunks generated by old versions or marked for light
+//! recalculation after an upgrade lack block light data, which would make
+//! them appear completely dark on the lightmap. For such chunks, an
+//! approximation of the block light is computed by propagating the light of
+//! light-emitting blocks.
+//!
+//! Each region is processed on its own, so light is only propagated between
+//! chunks of the same region. Light sources in neighboring regions are
+//! ignored, and light does not cross region borders. Taking neighboring
+//! regions into account would require reading their region files, and
+//! changes to them would need to trigger reprocessing of the region.
+
+use std::collections::{BTreeMap, VecDeque};
+
+use anyhow::{Context, Result};
+
+use super::{chunk::Chunk, de};
+use crate::{
+	resource::{BiomeTypes, BlockFlag, BlockType, BlockTypes},
+	types::*,
+	util::ShiftMask,
+};
+
+/// Unprocessed chunk data of a region
+pub type RegionData = ChunkArray<Option<Box<de::Chunk>>>;
+
+/// Computed block light data of the sections of a chunk
+type ChunkLight = BTreeMap<SectionY, Vec<i8>>;
+
+/// A light-emitting block
+///
+/// Stores the region-relative X, Y and Z block coordinates and the
+/// emitted light level.
+type LightSource = (i32, i32, i32, u8);
+
+/// Width of the volume in which light is propagated for a single chunk
+///
+/// Light can travel at most 14 blocks from its source, so only sources in
+/// the chunk itself and its 8 neighbors need to be considered.
+const W: i32 = 3 * BLOCKS_PER_CHUNK as i32;
+
+/// Offsets to the 6 neighbors of a block
+const DIRECTIONS: [(i32, i32, i32); 6] = [
+	(-1, 0, 0),
+	(1, 0, 0),
+	(0, -1, 0),
+	(0, 1, 0),
+	(0, 0, -1),
+	(0, 0, 1),
+];
+
+/// Returns true if the block light of a chunk has not been computed by Minecraft
+///
+/// The `isLightOn` and `LightPopulated` flags are used when they exist.
+/// Otherwise, chunks without any stored block light data are assumed to be
+/// unlit.
+pub fn needs_block_light(data: &de::Chunk) -> bool {
+	match &data.chunk {
+		de::ChunkVariant::V1_18 {
+			sections,
+			is_light_on,
+			..
+		} => match is_light_on {
+			Some(is_light_on) => !is_light_on,
+			None => !sections.iter().any(|section| {
+				matches!(
+					section.section,
+					de::SectionV1_18Variant::V1_18 {
+						block_light: Some(_),
+						..
+					}
+				)
+			}),
+		},
+		de::ChunkVariant::V0 { level } => match level.is_light_on.or(level.light_populated) {
+			Some(is_light_on) => !is_light_on,
+			None => !level
+				.sections
+				.iter()
+				.any(|section| section.block_light.is_some()),
+		},
+	}
+}
+
+/// Returns true if light does not pass through a block type
+///
+/// The shapes of blocks are unknown to MinedMap, so all visible block types
+/// except for translucent blocks, water and leaves are assumed to block
+/// light.
+fn blocks_light(block_type: &BlockType) -> bool {
+	let color = &block_type.block_color;
+	color.is(BlockFlag::Opaque)
+		&& ![
+			BlockFlag::Translucent,
+			BlockFlag::Water,
+			BlockFlag::Foliage,
+			BlockFlag::Birch,
+			BlockFlag::Spruce,
+		]
+		.into_iter()
+		.any(|flag| color.is(flag))
+}
+
+/// Returns the chunk containing a region-relative block X/Z coordinate pair
+///
+/// The block coordinates inside the chunk are returned as well. [None] is
+/// returned for coordinates outside of the region.
+fn chunk_at(x: i32, z: i32) -> Option<(ChunkCoords, LayerBlockCoords)> {
+	let (chunk_x, block_x) = x.shift_mask(BLOCK_BITS);
+	let (chunk_z, block_z) = z.shift_mask(BLOCK_BITS);
+	let range = 0..CHUNKS_PER_REGION as i32;
+	if !range.contains(&chunk_x) || !range.contains(&chunk_z) {
+		return None;
+	}
+
+	Some((
+		ChunkCoords {
+			x: ChunkX::new(chunk_x),
+			z: ChunkZ::new(chunk_z),
+		},
+		LayerBlockCoords {
+			x: BlockX::new(block_x),
+			z: BlockZ::new(block_z),
+		},
+	))
+}
+
+/// Returns the block type at region-relative block coordinates
+///
+/// Blocks of missing chunks and sections are returned as [None].
+fn block_at<'a>(
+	chunks: &'a ChunkArray<Option<Chunk<'a>>>,
+	x: i32,
+	y: i32,
+	z: i32,
+) -> Result<Option<&'a BlockType>> {
+	let Some((chunk_coords, xz)) = chunk_at(x, z) else {
+		return Ok(None);
+	};
+	let Some(chunk) = &chunks[chunk_coords] else {
+		return Ok(None);
+	};
+
+	let (section_y, block_y) = y.shift_mask(BLOCK_BITS);
+	let Some(section) = chunk.section(SectionY(section_y)) else {
+		return Ok(None);
+	};
+
+	section.section.block_at(SectionBlockCoords {
+		xz,
+		y: BlockY::new(block_y),
+	})
+}
+
+/// Collects the light-emitting blocks of a chunk
+fn light_sources(chunk: &Chunk, chunk_coords: ChunkCoords) -> Result<Vec<LightSource>> {
+	let mut sources = Vec::new();
+
+	let base_x = chunk_coords.x.0 as i32 * BLOCKS_PER_CHUNK as i32;
+	let base_z = chunk_coords.z.0 as i32 * BLOCKS_PER_CHUNK as i32;
+
+	for section in chunk.sections() {
+		if !section.section.may_emit_light() {
+			continue;
+		}
+
+		let base_y = section.y.0 * BLOCKS_PER_CHUNK as i32;
+
+		for y in BlockY::iter() {
+			for z in BlockZ::iter() {
+				for x in BlockX::iter() {
+					let coords = SectionBlockCoords {
+						xz: LayerBlockCoords { x, z },
+						y,
+					};
+					let Some(block_type) = section.section.block_at(coords)? else {
+						continue;
+					};
+					if block_type.light_emission == 0 {
+						continue;
+					}
+
+					sources.push((
+						base_x + x.0 as i32,
+						base_y + y.0 as i32,
+						base_z + z.0 as i32,
+						block_type.light_emission,
+					));
+				}
+			}
+		}
+	}
+
+	Ok(sources)
+}
+
+/// Returns the range of section Y coordinates of a chunk
+fn section_range(chunk: &Chunk) -> Option<(i32, i32)> {
+	let mut sections = chunk.sections();
+	let min = sections.next()?.y.0;
+	let max = sections.next_back().map_or(min, |section| section.y.0);
+	Some((min, max))
+}
+
+/// Computes the block light of the sections of a chunk
+///
+/// Light is propagated in a volume spanning the chunk and its neighbors,
+/// decreasing by one level per block, until it reaches a block that is
+/// assumed to block light.
+fn compute_block_light(
+	chunks: &ChunkArray<Option<Chunk>>,
+	sources: &ChunkArray<Vec<LightSource>>,
+	chunk_coords: ChunkCoords,
+) -> Result<ChunkLight> {
+	use BLOCKS_PER_CHUNK as N;
+
+	let mut ret = ChunkLight::new();
+
+	let Some(chunk) = &chunks[chunk_coords] else {
+		return Ok(ret);
+	};
+	if section_range(chunk).is_none() {
+		return Ok(ret);
+	}
+
+	let center_x = chunk_coords.x.0 as i32;
+	let center_z = chunk_coords.z.0 as i32;
+
+	let neighbors: Vec<_> = (-1..=1)
+		.flat_map(|dz| (-1..=1).map(move |dx| (center_x + dx, center_z + dz)))
+		.filter_map(|(x, z)| chunk_at(x * N as i32, z * N as i32))
+		.map(|(coords, _)| coords)
+		.collect();
+
+	let Some((min_section, max_section)) = neighbors
+		.iter()
+		.filter_map(|&coords| chunks[coords].as_ref().and_then(section_range))
+		.reduce(|(min_a, max_a), (min_b, max_b)| (min_a.min(min_b), max_a.max(max_b)))
+	else {
+		return Ok(ret);
+	};
+
+	let origin_x = (center_x - 1) * N as i32;
+	let origin_z = (center_z - 1) * N as i32;
+	let min_y = min_section * N as i32;
+	let height = (max_section - min_section + 1) * N as i32;
+
+	let index = |x: i32, y: i32, z: i32| ((y * W + z) * W + x) as usize;
+	let size = index(0, height, 0);
+
+	let mut levels = vec![0u8; size];
+	let mut blocked = vec![None; size];
+	let mut queue = VecDeque::new();
+
+	for &coords in &neighbors {
+		for &(x, y, z, level) in &sources[coords] {
+			let pos = (x - origin_x, y - min_y, z - origin_z);
+			let i = index(pos.0, pos.1, pos.2);
+			if level > levels[i] {
+				levels[i] = level;
+				queue.push_back(pos);
+			}
+		}
+	}
+
+	while let Some((x, y, z)) = queue.pop_front() {
+		let level = levels[index(x, y, z)];
+		if level <= 1 {
+			continue;
+		}
+
+		for (dx, dy, dz) in DIRECTIONS {
+			let (nx, ny, nz) = (x + dx, y + dy, z + dz);
+			if !(0..W).contains(&nx) || !(0..height).contains(&ny) || !(0..W).contains(&nz) {
+				continue;
+			}
+
+			let i = index(nx, ny, nz);
+			if levels[i] >= level - 1 {
+				continue;
+			}
+
+			let is_blocked = match blocked[i] {
+				Some(is_blocked) => is_blocked,
+				None => {
+					let is_blocked = block_at(chunks, origin_x + nx, min_y + ny, origin_z + nz)?
+						.is_some_and(blocks_light);
+					blocked[i] = Some(is_blocked);
+					is_blocked
+				}
+			};
+			if is_blocked {
+				continue;
+			}
+
+			levels[i] = level - 1;
+			queue.push_back((nx, ny, nz));
+		}
+	}
+
+	for section in chunk.sections() {
+		let mut data = vec![0i8; N * N * N / 2];
+
+		for y in BlockY::iter() {
+			for z in BlockZ::iter() {
+				for x in BlockX::iter() {
+					let coords = SectionBlockCoords {
+						xz: LayerBlockCoords { x, z },
+						y,
+					};
+					let level = levels[index(
+						N as i32 + x.0 as i32,
+						(section.y.0 - min_section) * N as i32 + y.0 as i32,
+						N as i32 + z.0 as i32,
+					)];
+
+					let offset = coords.offset();
+					data[offset / 2] |= (level << (4 * (offset % 2))) as i8;
+				}
+			}
+		}
+
+		ret.insert(section.y, data);
+	}
+
+	Ok(ret)
+}
+
+/// Stores computed block light data in the sections of a chunk
+fn set_block_light(data: &mut de::Chunk, mut light: ChunkLight) {
+	match &mut data.chunk {
+		de::ChunkVariant::V1_18 { sections, .. } => {
+			for section in sections {
+				let de::SectionV1_18Variant::V1_18 { block_light, .. } = &mut section.section
+				else {
+					continue;
+				};
+				if let Some(data) = light.remove(&SectionY(section.y)) {
+					*block_light = Some(fastnbt::ByteArray::new(data));
+				}
+			}
+		}
+		de::ChunkVariant::V0 { level } => {
+			for section in &mut level.sections {
+				if let Some(data) = light.remove(&SectionY(section.y.into())) {
+					section.block_light = Some(fastnbt::ByteArray::new(data));
+				}
+			}
+		}
+	}
+}
+
+/// Computes the block light of the chunks of a region without light data
+///
+/// Light is only computed for chunks selected by *dirty*; the other chunks
+/// are only used as neighbors. The computed light is stored in the section
+/// data of the chunks. Light emitted by blocks in neighboring regions is not
+/// taken into account.
+pub fn fill_missing_block_light(
+	chunks: &mut RegionData,
+	dirty: &ChunkArray<bool>,
+	block_types: &BlockTypes,
+	biome_types: &BiomeTypes,
+) -> Result<()> {
+	let unlit: Vec<_> = chunks
+		.iter()
+		.filter(|&(chunk_coords, data)| {
+			dirty[chunk_coords] && data.as_deref().is_some_and(needs_block_light)
+		})
+		.map(|(chunk_coords, _)| chunk_coords)
+		.collect();
+	if unlit.is_empty() {
+		return Ok(());
+	}
+
+	let light = {
+		let mut decoded = Box::<ChunkArray<Option<Chunk>>>::default();
+		let mut sources = Box::<ChunkArray<Vec<LightSource>>>::default();
+
+		for (chunk_coords, data) in chunks.iter() {
+			let Some(data) = data else {
+				continue;
+			};
+
+			let (chunk, _) = Chunk::new(data, block_types, biome_types)
+				.with_context(|| format!("Failed to decode chunk {:?}", chunk_coords))?;
+			sources[chunk_coords] = light_sources(&chunk, chunk_coords)
+				.with_context(|| format!("Failed to process chunk {:?}", chunk_coords))?;
+			decoded[chunk_coords] = Some(chunk);
+		}
+
+		unlit
+			.into_iter()
+			.map(|chunk_coords| {
+				let light =
+					compute_block_light(&decoded, &sources, chunk_coords).with_context(|| {
+						format!("Failed to compute block light for chunk {:?}", chunk_coords)
+					})?;
+				Ok((chunk_coords, light))
+			})
+			.collect::<Result<Vec<_>>>()?
+	};
+
+	for (chunk_coords, light) in light {
+		if let Some(data) = &mut chunks[chunk_coords] {
+			set_block_light(data, light);
+		}
+	}
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	/// Returns an unlit chunk with a section of air at Y=64..80
+	///
+	/// When *light* is set, a glowstone block is placed at the given
+	/// chunk-relative X and Z coordinates at Y=64.
+	fn chunk(light: Option<(u8, u8)>) -> Box<de::Chunk> {
+		let palette = ["minecraft:air", "minecraft:glowstone"]
+			.into_iter()
+			.map(|name| de::BlockStatePaletteEntry {
+				name: name.to_string(),
+				properties: Default::default(),
+			})
+			.collect();
+
+		// 4 bits per block, 16 blocks per word
+		let mut data = vec![0i64; 256];
+		if let Some((x, z)) = light {
+			let offset = BLOCKS_PER_CHUNK * usize::from(z) + usize::from(x);
+			data[offset / 16] |= 1 << (4 * (offset % 16));
+		}
+
+		Box::new(de::Chunk {
+			data_version: Some(3700),
+			chunk: de::ChunkVariant::V1_18 {
+				sections: vec![de::SectionV1_18 {
+					y: 4,
+					section: de::SectionV1_18Variant::V1_18 {
+						block_states: de::BlockStatesV1_18 {
+							palette,
+							data: Some(fastnbt::LongArray::new(data)),
+						},
+						biomes: de::BiomesV1_18 {
+							palette: vec!["minecraft:plains".to_string()],
+							data: None,
+						},
+						block_light: None,
+						sky_light: None,
+					},
+				}],
+				block_entities: Vec::new(),
+				is_light_on: Some(false),
+			},
+		})
+	}
+
+	/// Returns the block light at Y=64 of a chunk after light computation
+	fn light_at(chunks: &RegionData, chunk_coords: ChunkCoords, x: u8, z: u8) -> u8 {
+		let block_types = BlockTypes::default();
+		let biome_types = BiomeTypes::default();
+		let data = chunks[chunk_coords].as_deref().unwrap();
+
+		let (chunk, _) = Chunk::new(data, &block_types, &biome_types).unwrap();
+		chunk
+			.section(SectionY(4))
+			.unwrap()
+			.light
+			.block_light_at(SectionBlockCoords {
+				xz: LayerBlockCoords {
+					x: BlockX::new(x),
+					z: BlockZ::new(z),
+				},
+				y: BlockY::new(0),
+			})
+	}
+
+	#[test]
+	fn test_fill_missing_block_light() {
+		let chunk_coords = |x: u8, z: u8| ChunkCoords {
+			x: ChunkX::new(x),
+			z: ChunkZ::new(z),
+		};
+
+		let mut chunks = RegionData::default();
+		chunks[chunk_coords(0, 0)] = Some(chunk(Some((15, 8))));
+		chunks[chunk_coords(1, 0)] = Some(chunk(None));
+		chunks[chunk_coords(31, 0)] = Some(chunk(Some((15, 8))));
+
+		let dirty = ChunkArray([[true; CHUNKS_PER_REGION]; CHUNKS_PER_REGION]);
+		fill_missing_block_light(
+			&mut chunks,
+			&dirty,
+			&BlockTypes::default(),
+			&BiomeTypes::default(),
+		)
+		.unwrap();
+
+		// Light decreases by one level per block...
+		assert_eq!(light_at(&chunks, chunk_coords(0, 0), 15, 8), 15);
+		assert_eq!(light_at(&chunks, chunk_coords(0, 0), 13, 8), 13);
+		assert_eq!(light_at(&chunks, chunk_coords(0, 0), 15, 10), 13);
+		// ...and passes into neighboring chunks of the same region
+		assert_eq!(light_at(&chunks, chunk_coords(1, 0), 0, 8), 14);
+		assert_eq!(light_at(&chunks, chunk_coords(1, 0), 13, 8), 1);
+		assert_eq!(light_at(&chunks, chunk_coords(1, 0), 14, 8), 0);
+		// Chunks at the edge of the region are lit by their own light sources
+		assert_eq!(light_at(&chunks, chunk_coords(31, 0), 15, 8), 15);
+		assert_eq!(light_at(&chunks, chunk_coords(31, 0), 10, 8), 10);
+	}
+
+	#[test]
+	fn test_fill_dirty_only() {
+		let chunk_coords = |x: u8, z: u8| ChunkCoords {
+			x: ChunkX::new(x),
+			z: ChunkZ::new(z),
+		};
+
+		let mut chunks = RegionData::default();
+		chunks[chunk_coords(0, 0)] = Some(chunk(Some((15, 8))));
+		chunks[chunk_coords(1, 0)] = Some(chunk(None));
+
+		let mut dirty = ChunkArray::<bool>::default();
+		dirty[chunk_coords(1, 0)] = true;
+		fill_missing_block_light(
+			&mut chunks,
+			&dirty,
+			&BlockTypes::default(),
+			&BiomeTypes::default(),
+		)
+		.unwrap();
+
+		// Light sources of clean neighbors are taken into account...
+		assert_eq!(light_at(&chunks, chunk_coords(1, 0), 0, 8), 14);
+		// ...but their own light is not computed
+		assert_eq!(light_at(&chunks, chunk_coords(0, 0), 15, 8), 0);
+	}
+}
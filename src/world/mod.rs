@@ -5,5 +5,6 @@ pub mod chunk;
 pub mod de;
 pub mod json_text;
 pub mod layer;
+pub mod light;
 pub mod section;
 pub mod sign;
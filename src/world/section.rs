@@ -3,7 +3,7 @@
 //! The data types in this module attempt to provide interfaces abstracting
 //! over different data versions as much as possible.
 
-use std::fmt::Debug;
+use std::{collections::BTreeMap, fmt::Debug};
 
 use anyhow::{bail, Context, Result};
 use num_integer::div_rem;
@@ -11,7 +11,7 @@ use tracing::debug;
 
 use super::de;
 use crate::{
-	resource::{Biome, BiomeTypes, BlockType, BlockTypes},
+	resource::{BiomeType, BiomeTypes, BlockType, BlockTypes},
 	types::*,
 };
 
@@ -41,10 +41,66 @@ fn palette_bits(len: usize, min: u8, max: u8) -> Option<u8> {
 	Some(bits)
 }
 
+/// Occurrences of an unknown block or biome ID in a section
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnknownId<'a> {
+	/// Namespaced ID
+	pub id: &'a str,
+	/// Number of blocks with the ID
+	pub count: usize,
+	/// Lowest X, Y and Z coordinates of a block with the ID
+	pub example: [u8; 3],
+}
+
+/// Counts the occurrences of unknown palette entries in a section
+///
+/// *unknown* lists the palette indices and IDs of the unknown entries.
+/// *step* is the edge length of the cubes sharing a palette entry (1 for block
+/// data, 4 for biome data). Only IDs that actually occur in the section are
+/// returned.
+fn count_unknown<'a>(
+	unknown: &[(usize, &'a str)],
+	step: u8,
+	index_at: impl Fn(SectionBlockCoords) -> usize,
+) -> Vec<UnknownId<'a>> {
+	if unknown.is_empty() {
+		return Vec::new();
+	}
+
+	let mut ret = BTreeMap::<&str, UnknownId>::new();
+
+	// Iterating in X-Y-Z order finds the lowest coordinates first
+	for x in BlockX::iter().step_by(step.into()) {
+		for y in BlockY::iter().step_by(step.into()) {
+			for z in BlockZ::iter().step_by(step.into()) {
+				let index = index_at(SectionBlockCoords {
+					xz: LayerBlockCoords { x, z },
+					y,
+				});
+				let Some(&(_, id)) = unknown.iter().find(|&&(i, _)| i == index) else {
+					continue;
+				};
+				ret.entry(id)
+					.or_insert(UnknownId {
+						id,
+						count: 0,
+						example: [x.0, y.0, z.0],
+					})
+					.count += usize::from(step).pow(3);
+			}
+		}
+	}
+
+	ret.into_values().collect()
+}
+
 /// Trait for common functions of [SectionV1_13] and [SectionV0]
 pub trait Section: Debug {
 	/// Returns the [BlockType] at a coordinate tuple inside the section
 	fn block_at(&self, coords: SectionBlockCoords) -> Result<Option<&BlockType>>;
+
+	/// Returns false if the section is known not to contain light-emitting blocks
+	fn may_emit_light(&self) -> bool;
 }
 
 /// Minecraft v1.13+ section block data
@@ -67,13 +123,14 @@ impl<'a> SectionV1_13<'a> {
 	/// Constructs a new [SectionV1_13] from deserialized data structures
 	///
 	/// The block IDs in the section's palette are resolved to their [BlockType]s
-	/// to allow for faster lookup later.
+	/// to allow for faster lookup later. The occurrences of unknown block types
+	/// are returned, including types resolved using [BlockTypes::get_fallback].
 	pub fn new(
 		data_version: u32,
 		block_states: Option<&'a [i64]>,
 		palette: &'a [de::BlockStatePaletteEntry],
 		block_types: &'a BlockTypes,
-	) -> Result<(Self, bool)> {
+	) -> Result<(Self, Vec<UnknownId<'a>>)> {
 		let aligned_blocks = data_version >= 2529;
 
 		let bits = palette_bits(palette.len(), 4, 12).context("Unsupported block palette size")?;
@@ -90,29 +147,30 @@ impl<'a> SectionV1_13<'a> {
 			}
 		}
 
-		let mut has_unknown = false;
+		let mut unknown = Vec::new();
 
 		let palette_types = palette
 			.iter()
-			.map(|entry| {
-				let block_type = block_types.get(&entry.name);
-				if block_type.is_none() {
-					debug!("Unknown block type: {}", entry.name);
-					has_unknown = true;
+			.enumerate()
+			.map(|(index, entry)| {
+				if let Some(block_type) = block_types.get_state(&entry.name, &entry.properties) {
+					return Some(block_type);
 				}
-				block_type
+				debug!("Unknown block type: {}", entry.name);
+				unknown.push((index, entry.name.as_str()));
+				block_types.get_fallback(&entry.name)
 			})
 			.collect();
 
-		Ok((
-			Self {
-				block_states,
-				palette: palette_types,
-				bits,
-				aligned_blocks,
-			},
-			has_unknown,
-		))
+		let section = Self {
+			block_states,
+			palette: palette_types,
+			bits,
+			aligned_blocks,
+		};
+		let unknown = count_unknown(&unknown, 1, |coords| section.palette_index_at(coords));
+
+		Ok((section, unknown))
 	}
 
 	/// Looks up the block type palette index at the given coordinates
@@ -153,6 +211,13 @@ impl<'a> Section for SectionV1_13<'a> {
 			.get(index)
 			.context("Palette index out of bounds")?)
 	}
+
+	fn may_emit_light(&self) -> bool {
+		self.palette
+			.iter()
+			.flatten()
+			.any(|block_type| block_type.light_emission > 0)
+	}
 }
 
 /// Pre-1.13 section block data
@@ -203,12 +268,17 @@ impl<'a> Section for SectionV0<'a> {
 
 		Ok(self.block_types.get_legacy(block, data))
 	}
+
+	fn may_emit_light(&self) -> bool {
+		true
+	}
 }
 
 /// Trait for common functions of [BiomesV1_18] and [BiomesV0]
 pub trait Biomes: Debug {
-	/// Returns the [Biome] at a coordinate tuple inside the chunk
-	fn biome_at(&self, section: SectionY, coords: SectionBlockCoords) -> Result<Option<&Biome>>;
+	/// Returns the [BiomeType] at a coordinate tuple inside the chunk
+	fn biome_at(&self, section: SectionY, coords: SectionBlockCoords)
+		-> Result<Option<&BiomeType>>;
 }
 
 /// Minecraft v1.18+ section biome data
@@ -226,18 +296,20 @@ pub struct BiomesV1_18<'a> {
 	/// to whole i64 values.
 	biomes: Option<&'a [i64]>,
 	/// Biome palette indexed by entries encoded in *biomes*
-	palette: Vec<Option<&'a Biome>>,
+	palette: Vec<Option<&'a BiomeType>>,
 	/// Number of bits used for each entry in *biomes*
 	bits: u8,
 }
 
 impl<'a> BiomesV1_18<'a> {
 	/// Constructs a new [BiomesV1_18] from deserialized data structures
+	///
+	/// The occurrences of unknown biome types are returned.
 	pub fn new(
 		biomes: Option<&'a [i64]>,
 		palette: &'a [String],
 		biome_types: &'a BiomeTypes,
-	) -> Result<(Self, bool)> {
+	) -> Result<(Self, Vec<UnknownId<'a>>)> {
 		let bits = palette_bits(palette.len(), 1, 6).context("Unsupported block palette size")?;
 
 		if let Some(biomes) = biomes {
@@ -248,28 +320,29 @@ impl<'a> BiomesV1_18<'a> {
 			}
 		}
 
-		let mut has_unknown = false;
+		let mut unknown = Vec::new();
 
 		let palette_types = palette
 			.iter()
-			.map(|entry| {
+			.enumerate()
+			.map(|(index, entry)| {
 				let biome_type = biome_types.get(entry);
 				if biome_type.is_none() {
 					debug!("Unknown biome type: {}", entry);
-					has_unknown = true;
+					unknown.push((index, entry.as_str()));
 				}
 				biome_type
 			})
 			.collect();
 
-		Ok((
-			BiomesV1_18 {
-				biomes,
-				palette: palette_types,
-				bits,
-			},
-			has_unknown,
-		))
+		let section = BiomesV1_18 {
+			biomes,
+			palette: palette_types,
+			bits,
+		};
+		let unknown = count_unknown(&unknown, 4, |coords| section.palette_index_at(coords));
+
+		Ok((section, unknown))
 	}
 
 	/// Looks up the block type palette index at the given coordinates
@@ -295,7 +368,11 @@ impl<'a> BiomesV1_18<'a> {
 }
 
 impl<'a> Biomes for BiomesV1_18<'a> {
-	fn biome_at(&self, _section: SectionY, coords: SectionBlockCoords) -> Result<Option<&Biome>> {
+	fn biome_at(
+		&self,
+		_section: SectionY,
+		coords: SectionBlockCoords,
+	) -> Result<Option<&BiomeType>> {
 		let index = self.palette_index_at(coords);
 		Ok(*self
 			.palette
@@ -350,7 +427,11 @@ impl<'a> BiomesV0<'a> {
 }
 
 impl<'a> Biomes for BiomesV0<'a> {
-	fn biome_at(&self, section: SectionY, coords: SectionBlockCoords) -> Result<Option<&Biome>> {
+	fn biome_at(
+		&self,
+		section: SectionY,
+		coords: SectionBlockCoords,
+	) -> Result<Option<&BiomeType>> {
 		let id = match self.data {
 			BiomesV0Data::IntArrayV15(data) => {
 				let LayerBlockCoords { x, z } = coords.xz;
@@ -374,29 +455,38 @@ impl<'a> Biomes for BiomesV0<'a> {
 	}
 }
 
-/// Wrapper around chunk block light data array
+/// Wrapper around chunk block light and sky light data arrays
 #[derive(Debug, Clone, Copy)]
-pub struct BlockLight<'a>(Option<&'a [i8]>);
+pub struct SectionLight<'a> {
+	/// Block light data
+	block_light: Option<&'a [i8]>,
+	/// Sky light data
+	sky_light: Option<&'a [i8]>,
+}
 
-impl<'a> BlockLight<'a> {
-	/// Creates a new [BlockLight], checking validity
-	pub fn new(block_light: Option<&'a [i8]>) -> Result<Self> {
+impl<'a> SectionLight<'a> {
+	/// Creates a new [SectionLight], checking validity
+	pub fn new(block_light: Option<&'a [i8]>, sky_light: Option<&'a [i8]>) -> Result<Self> {
 		if let Some(block_light) = block_light {
 			if block_light.len() != N * N * N / 2 {
 				bail!("Invalid section block light data");
 			}
 		}
-		Ok(BlockLight(block_light))
+		if let Some(sky_light) = sky_light {
+			if sky_light.len() != N * N * N / 2 {
+				bail!("Invalid section sky light data");
+			}
+		}
+		Ok(SectionLight {
+			block_light,
+			sky_light,
+		})
 	}
 
-	/// Returns the block light value at the given coordinates
-	pub fn block_light_at(&self, coords: SectionBlockCoords) -> u8 {
-		let Some(block_light) = self.0 else {
-			return 0;
-		};
-
+	/// Returns the value of a light data array at the given coordinates
+	fn light_at(data: &[i8], coords: SectionBlockCoords) -> u8 {
 		let (offset, nibble) = div_rem(coords.offset(), 2);
-		let byte = block_light[offset] as u8;
+		let byte = data[offset] as u8;
 
 		if nibble == 1 {
 			byte >> 4
@@ -404,4 +494,133 @@ impl<'a> BlockLight<'a> {
 			byte & 0xf
 		}
 	}
+
+	/// Returns the block light value at the given coordinates
+	pub fn block_light_at(&self, coords: SectionBlockCoords) -> u8 {
+		let Some(block_light) = self.block_light else {
+			return 0;
+		};
+
+		Self::light_at(block_light, coords)
+	}
+
+	/// Returns the sky light value at the given coordinates
+	///
+	/// Minecraft does not store sky light data for sections that are
+	/// completely exposed to the sky, so full sky light is returned when the
+	/// data is missing. Dimensions without sky light are not distinguished;
+	/// this is handled by [LayerOptions::has_sky_light](super::layer::LayerOptions::has_sky_light).
+	pub fn sky_light_at(&self, coords: SectionBlockCoords) -> u8 {
+		let Some(sky_light) = self.sky_light else {
+			return 15;
+		};
+
+		Self::light_at(sky_light, coords)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	/// Returns the coordinates of a block in a section
+	fn coords(x: u8, y: u8, z: u8) -> SectionBlockCoords {
+		SectionBlockCoords {
+			xz: LayerBlockCoords {
+				x: BlockX::new(x),
+				z: BlockZ::new(z),
+			},
+			y: BlockY::new(y),
+		}
+	}
+
+	#[test]
+	fn test_unknown_blocks() {
+		let block_types = BlockTypes::default();
+		let palette: Vec<_> = ["minecraft:stone", "mymod:foo", "mymod:unused", "mymod:foo"]
+			.into_iter()
+			.map(|name| de::BlockStatePaletteEntry {
+				name: name.to_string(),
+				properties: Default::default(),
+			})
+			.collect();
+
+		// 4 bits per block, 16 blocks per word
+		let mut block_states = vec![0i64; 256];
+		for (index, coords) in [
+			(1, coords(3, 5, 0)),
+			(3, coords(5, 0, 0)),
+			(1, coords(3, 2, 1)),
+		] {
+			let offset = coords.offset();
+			block_states[offset / 16] |= index << (4 * (offset % 16));
+		}
+
+		let (_, unknown) =
+			SectionV1_13::new(3000, Some(&block_states), &palette, &block_types).unwrap();
+		assert_eq!(
+			unknown,
+			[UnknownId {
+				id: "mymod:foo",
+				count: 3,
+				example: [3, 2, 1],
+			}],
+		);
+
+		let (_, unknown) = SectionV1_13::new(3000, None, &palette[1..], &block_types).unwrap();
+		assert_eq!(
+			unknown,
+			[UnknownId {
+				id: "mymod:foo",
+				count: 4096,
+				example: [0, 0, 0],
+			}],
+		);
+	}
+
+	#[test]
+	fn test_unknown_fallback() {
+		let block_types = BlockTypes::default();
+		let palette = [de::BlockStatePaletteEntry {
+			name: "mymod:red_wool_slab".to_string(),
+			properties: Default::default(),
+		}];
+
+		// Block types resolved using a fallback are reported as unknown
+		let (section, unknown) = SectionV1_13::new(3000, None, &palette, &block_types).unwrap();
+		assert_eq!(
+			section
+				.block_at(coords(0, 0, 0))
+				.unwrap()
+				.map(|block_type| block_type.id.as_str()),
+			Some("minecraft:red_wool"),
+		);
+		assert_eq!(
+			unknown,
+			[UnknownId {
+				id: "mymod:red_wool_slab",
+				count: 4096,
+				example: [0, 0, 0],
+			}],
+		);
+	}
+
+	#[test]
+	fn test_unknown_biomes() {
+		let biome_types = BiomeTypes::default();
+		let palette = ["minecraft:plains".to_string(), "mymod:biome".to_string()];
+
+		// 1 bit per 4x4x4 area, areas ordered by Y, Z and X
+		let biomes = [1i64 << (16 + 4 * 2 + 1)];
+
+		let (_, unknown) = BiomesV1_18::new(Some(&biomes), &palette, &biome_types).unwrap();
+		assert_eq!(
+			unknown,
+			[UnknownId {
+				id: "mymod:biome",
+				count: 64,
+				example: [4, 4, 8],
+			}],
+		);
+	}
 }
@@ -36,12 +36,68 @@ const signKinds = {
 	},
 }
 
+const dimensionNames = {
+	'minecraft:overworld': 'Overworld',
+	'minecraft:the_nether': 'The Nether',
+	'minecraft:the_end': 'The End',
+};
+
+// Number of texture zoom levels above zoom level 0
+const textureLevels = 4;
+
 const params = {};
 const signIcons = {};
 const markers = {};
 
 let updateHash = () => {};
 
+// Conversion between world coordinates and map coordinates of the top-down
+// map layers
+const topDownProjection = {
+	toLatLng: (x, z) => L.latLng(-z, x),
+	fromLatLng: (latlng) => [latlng.lng, -latlng.lat],
+};
+
+// Conversion between world coordinates and map coordinates of the isometric
+// map layer
+//
+// Map coordinates are given in units of half the width of a block's top
+// face. World coordinates without Y coordinate refer to the base height.
+function isometricProjection(isometric) {
+	const [sx, sz] = {
+		'north-east': [1, -1],
+		'south-east': [1, 1],
+		'south-west': [-1, 1],
+		'north-west': [-1, -1],
+	}[isometric.direction];
+	const baseY = isometric.baseY;
+
+	return {
+		toLatLng: (x, z, y = baseY) => {
+			const a = sx*x, b = sz*z;
+			return L.latLng(y - baseY - (a+b)/2, sx*sz*(a-b));
+		},
+		fromLatLng: (latlng) => {
+			const p = sx*sz*latlng.lng, q = -2*latlng.lat;
+			return [sx*(q+p)/2, sz*(q-p)/2];
+		},
+	};
+}
+
+let projection = topDownProjection;
+
+function layerName(layer, index) {
+	if (layer.name)
+		return layer.name;
+
+	const name = (index === 0) ? 'Surface' : 'Slice';
+
+	if (layer.maxY === null || layer.maxY === undefined)
+		return name;
+
+	return `${name} (Y ≤ ${layer.maxY})`;
+}
+
 function coordKey(coords) {
 	if (!coords)
 		return null;
@@ -73,19 +129,22 @@ function signIcon(material, kind) {
 }
 
 const MinedMapLayer = L.TileLayer.extend({
-	initialize: function (mipmaps, layer) {
+	// zoom is the map zoom level at which the level 0 tiles are shown in
+	// their original size
+	initialize: function (dataPath, mipmaps, layer, zoom = 0) {
 		L.TileLayer.prototype.initialize.call(this, '', {
 			detectRetina: true,
 			tileSize: 512,
 			zoomReverse: true,
-			minZoom: -(mipmaps.length-1),
-			maxZoom: 0,
+			minZoom: zoom-(mipmaps.length-1),
+			maxZoom: zoom,
 			attribution: 'Generated by <a href="https://github.com/neocturne/MinedMap">MinedMap</a>',
 		});
 
 		this.options.maxNativeZoom = this.options.maxZoom;
 		this.options.maxZoom = undefined;
 
+		this.dataPath = dataPath;
 		this.mipmaps = mipmaps;
 		this.layer = layer;
 	},
@@ -93,14 +152,14 @@ const MinedMapLayer = L.TileLayer.extend({
 	createTile: function (coords, done) {
 		const tile = L.TileLayer.prototype.createTile.call(this, coords, done);
 
-		if (coords.z - this.options.zoomOffset >= 0)
+		if (coords.z - this.options.zoomOffset >= this.options.maxNativeZoom)
 			L.DomUtil.addClass(tile, 'overzoomed');
 
 		return tile;
 	},
 
 	getTileUrl: function (coords) {
-		let z = -coords.z + this.options.zoomOffset;
+		let z = this.options.maxNativeZoom - coords.z + this.options.zoomOffset;
 		if (z < 0)
 			z = 0;
 
@@ -112,11 +171,192 @@ const MinedMapLayer = L.TileLayer.extend({
 			return L.Util.emptyImageUrl;
 
 
-		return 'data/'+this.layer+'/'+z+'/r.'+coords.x+'.'+coords.y+'.png';
+		return this.dataPath+this.layer+'/'+z+'/r.'+coords.x+'.'+coords.y+'.png';
+	},
+});
+
+// Shows block textures at zoom levels above 0
+const TextureLayer = L.TileLayer.extend({
+	initialize: function (dataPath, mipmap) {
+		L.TileLayer.prototype.initialize.call(this, '', {
+			tileSize: 512,
+			minZoom: 1,
+			maxNativeZoom: textureLevels,
+		});
+
+		this.dataPath = dataPath;
+		this.mipmap = mipmap;
+	},
+
+	createTile: function (coords, done) {
+		const tile = L.TileLayer.prototype.createTile.call(this, coords, done);
+		L.DomUtil.addClass(tile, 'overzoomed');
+		return tile;
+	},
+
+	getTileUrl: function (coords) {
+		// Each region is split into 2^z x 2^z texture tiles
+		const regionX = Math.floor(coords.x / 2**coords.z);
+		const regionZ = Math.floor(coords.y / 2**coords.z);
+
+		const mipmap = this.mipmap;
+		if (regionX < mipmap.bounds.minX || regionX > mipmap.bounds.maxX ||
+		    regionZ < mipmap.bounds.minZ || regionZ > mipmap.bounds.maxZ ||
+		    !contains(mipmap.regions[regionZ] || [], regionX))
+			return L.Util.emptyImageUrl;
+
+		return this.dataPath+'texture/'+coords.z+'/r.'+coords.x+'.'+coords.y+'.png';
 	},
 });
 
 
+// Offset of height values in heightmap and column data tiles
+const heightDataOffset = 32768;
+
+// Decodes a 16-bit grayscale or RGB PNG data tile
+async function decodeDataTile(buffer) {
+	const data = new Uint8Array(buffer);
+	const view = new DataView(buffer);
+
+	let pos = 8, width = 0, height = 0, channels = 1;
+	const idat = [];
+
+	while (pos + 8 <= data.length) {
+		const length = view.getUint32(pos);
+		const type = String.fromCharCode(...data.subarray(pos+4, pos+8));
+
+		if (type === 'IHDR') {
+			width = view.getUint32(pos+8);
+			height = view.getUint32(pos+12);
+			channels = (data[pos+17] === 2) ? 3 : 1;
+		} else if (type === 'IDAT') {
+			idat.push(data.subarray(pos+8, pos+8+length));
+		} else if (type === 'IEND') {
+			break;
+		}
+
+		pos += length + 12;
+	}
+
+	const stream = new Blob(idat).stream().pipeThrough(new DecompressionStream('deflate'));
+	const raw = new Uint8Array(await new Response(stream).arrayBuffer());
+
+	// Reverse PNG scanline filters (2 bytes per channel)
+	const bpp = 2 * channels, stride = width * bpp;
+	const pixels = new Uint8Array(stride * height);
+	const empty = new Uint8Array(stride);
+
+	for (let y = 0; y < height; y++) {
+		const filter = raw[y*(stride+1)];
+		const line = raw.subarray(y*(stride+1) + 1, (y+1)*(stride+1));
+		const cur = pixels.subarray(y*stride, (y+1)*stride);
+		const prev = (y > 0) ? pixels.subarray((y-1)*stride, y*stride) : empty;
+
+		for (let i = 0; i < stride; i++) {
+			const a = (i >= bpp) ? cur[i-bpp] : 0;
+			const b = prev[i];
+			const c = (i >= bpp) ? prev[i-bpp] : 0;
+
+			let v = line[i];
+			switch (filter) {
+			case 1:
+				v += a;
+				break;
+			case 2:
+				v += b;
+				break;
+			case 3:
+				v += (a + b) >> 1;
+				break;
+			case 4: {
+				const p = a + b - c;
+				const pa = Math.abs(p - a), pb = Math.abs(p - b), pc = Math.abs(p - c);
+				v += (pa <= pb && pa <= pc) ? a : (pb <= pc) ? b : c;
+				break;
+			}
+			}
+
+			cur[i] = v;
+		}
+	}
+
+	return {width, channels, pixels};
+}
+
+// Loads values of full-resolution data tiles (like heightmap data tiles)
+function DataTiles(dataPath, mipmap, kind) {
+	const tiles = {};
+
+	this.valuesAt = async function (x, z) {
+		const tileX = Math.floor(x / 512), tileZ = Math.floor(z / 512);
+
+		if (!contains(mipmap.regions[tileZ] || [], tileX))
+			return null;
+
+		const key = coordKey([tileX, tileZ]);
+		tiles[key] ??= (async () => {
+			const response = await fetch(dataPath+kind+'/0/r.'+tileX+'.'+tileZ+'.png');
+			if (!response.ok)
+				return null;
+			return decodeDataTile(await response.arrayBuffer());
+		})().catch(() => null);
+
+		const tile = await tiles[key];
+		if (!tile)
+			return null;
+
+		const offset = 2 * tile.channels * ((z - 512*tileZ) * tile.width + (x - 512*tileX));
+		const values = [];
+		for (let i = 0; i < tile.channels; i++)
+			values.push((tile.pixels[offset+2*i] << 8) | tile.pixels[offset+2*i+1]);
+
+		return values;
+	};
+}
+
+// Decodes a height value from a data tile
+const decodeHeight = (value) => (value === 0) ? null : value - heightDataOffset;
+
+// Turns a namespaced ID into a short display name
+const shortId = (id) => id.replace(/^minecraft:/, '');
+
+// Looks up the column information under the cursor from the best available data tiles
+function ColumnInfo(dataPath, mipmap, features) {
+	if (features.columns) {
+		const tiles = new DataTiles(dataPath, mipmap, 'columns');
+		const ids = fetch(dataPath+'ids.json', {cache: 'no-store'})
+			.then((response) => response.json())
+			.catch(() => ({blocks: [], biomes: []}));
+
+		this.at = async function (x, z) {
+			const values = await tiles.valuesAt(x, z);
+			if (!values)
+				return null;
+
+			const {blocks, biomes} = await ids;
+			const [block, biome, height] = values;
+			return {
+				y: decodeHeight(height),
+				block: block ? blocks[block-1] : null,
+				biome: biome ? biomes[biome-1] : null,
+			};
+		};
+	} else if (features.heightmap) {
+		const tiles = new DataTiles(dataPath, mipmap, 'height-data');
+
+		this.at = async function (x, z) {
+			const values = await tiles.valuesAt(x, z);
+			if (!values)
+				return null;
+
+			return {y: decodeHeight(values[0])};
+		};
+	} else {
+		this.at = null;
+	}
+}
+
+
 const CoordControl = L.Control.extend({
 	initialize: function () {
 		this.options.position = 'bottomleft';
@@ -128,14 +368,81 @@ const CoordControl = L.Control.extend({
 		return this._container;
 	},
 
-	update: function (x, z) {
+	update: function (x, z, info) {
 		if (!this._map) { return; }
 
-		this._container.innerHTML = 'X: ' + x + '&nbsp;&nbsp;&nbsp;Z: ' + z;
+		let text = 'X: ' + x + '&nbsp;&nbsp;&nbsp;Z: ' + z;
+		if (info?.y !== null && info?.y !== undefined)
+			text += '&nbsp;&nbsp;&nbsp;Y: ' + info.y;
+
+		const names = [info?.block, info?.biome].filter((id) => id).map(shortId);
+		if (names.length)
+			text += '&nbsp;&nbsp;&nbsp;' + names.join(', ');
+
+		this._container.innerHTML = text;
 	}
 });
 
 
+// Turns a biome ID like 'minecraft:dark_forest' into a display name
+const biomeName = function (id) {
+	const name = id.replace(/^minecraft:/, '').replace(/_/g, ' ');
+	return name.charAt(0).toUpperCase() + name.slice(1);
+};
+
+const BiomeLegendControl = L.Control.extend({
+	initialize: function (biomes) {
+		this.options.position = 'bottomright';
+		this.biomes = biomes;
+	},
+
+	onAdd: function (map) {
+		const container = L.DomUtil.create('div', 'leaflet-bar biome-legend');
+
+		for (const [id, color] of Object.entries(this.biomes)) {
+			const entry = L.DomUtil.create('div', 'biome-legend-entry', container);
+			const swatch = L.DomUtil.create('span', 'biome-legend-swatch', entry);
+			swatch.style.background = color;
+			entry.appendChild(document.createTextNode(biomeName(id)));
+			entry.title = id;
+		}
+
+		L.DomEvent.disableClickPropagation(container);
+		L.DomEvent.disableScrollPropagation(container);
+
+		return container;
+	},
+});
+
+
+const DimensionControl = L.Control.extend({
+	initialize: function (dimensions, current, onChange) {
+		this.options.position = 'topleft';
+		this.dimensions = dimensions;
+		this.current = current;
+		this.onChange = onChange;
+	},
+
+	onAdd: function (map) {
+		const container = L.DomUtil.create('div', 'leaflet-bar dimension-control');
+		const select = L.DomUtil.create('select', '', container);
+
+		for (const dimension of this.dimensions) {
+			const option = L.DomUtil.create('option', '', select);
+			option.value = dimension.id;
+			option.textContent = dimensionNames[dimension.id] ?? dimension.id;
+			if (dimension.id === this.current)
+				option.selected = true;
+		}
+
+		L.DomEvent.disableClickPropagation(container);
+		L.DomEvent.on(select, 'change', () => this.onChange(select.value));
+
+		return container;
+	},
+});
+
+
 const parseHash = function () {
 	const args = {};
 
@@ -284,8 +591,8 @@ function createSign(sign, back) {
 	return wrapper;
 }
 
-async function loadSigns(signLayer) {
-	const response = await fetch('data/entities.json', {cache: 'no-store'});
+async function loadSigns(dataPath, signLayer) {
+	const response = await fetch(dataPath+'entities.json', {cache: 'no-store'});
 	const res = await response.json();
 
 	const groups = {};
@@ -320,6 +627,7 @@ async function loadSigns(signLayer) {
 		material ??= 'oak';
 
 		const [x, z] = key.split(',').map((i) => +i);
+		const y = group[0].y;
 
 		const popup = L.popup().setContent(el);
 
@@ -332,9 +640,10 @@ async function loadSigns(signLayer) {
 			updateHash();
 		});
 
-		const marker = L.marker([-z-0.5, x+0.5], {
+		const marker = L.marker(projection.toLatLng(x+0.5, z+0.5, y+0.5), {
 			icon: signIcon(material, kind),
 		}).addTo(signLayer).bindPopup(popup);
+		marker.worldCoords = [x+0.5, z+0.5, y+0.5];
 
 		markers[coordKey([x, z])] = marker;
 
@@ -345,18 +654,43 @@ async function loadSigns(signLayer) {
 
 window.createMap = function () {
 	(async function () {
-		const response = await fetch('data/info.json', {cache: 'no-store'});
+		const indexResponse = await fetch('data/info.json', {cache: 'no-store'});
+		const {dimensions} = await indexResponse.json();
+
+		const findDimension = (id) => dimensions.find((dim) => dim.id === decodeURIComponent(id));
+		const dimension = findDimension(parseHash()['dim'] ?? '') ?? dimensions[0];
+		const dataPath = 'data/'+dimension.path+'/';
+
+		const response = await fetch(dataPath+'info.json', {cache: 'no-store'});
 		const res = await response.json();
 		const {mipmaps, spawn} = res;
 		const features = res.features || {};
+		const layers = res.layers ?? [{id: 'map'}];
+		if (features.heightmap)
+			layers.push({id: 'height', name: 'Elevation'});
+		if (features.biomes)
+			layers.push({id: 'biome', name: 'Biomes'});
+		if (features.night)
+			layers.push({id: 'night', name: 'Night'});
+		if (features.vanilla_map)
+			layers.push({id: 'vanilla', name: 'Vanilla map'});
+		const isometric = res.isometric;
+		if (isometric)
+			layers.push({id: 'isometric', name: 'Isometric view'});
+
+		const findLayer = (id) => layers.find((layer) => layer.id === id);
 
 		const updateParams = function () {
 			const args = parseHash();
 
+			params.dim = (findDimension(args['dim'] ?? '') ?? dimensions[0]).id;
+			params.layer = (findLayer(args['layer'] ?? '') ?? layers[0]).id;
 			params.zoom = parseInt(args['zoom']);
 			params.x = parseFloat(args['x']);
 			params.z = parseFloat(args['z']);
 			params.light = parseInt(args['light']);
+			params.shade = parseInt(args['shade'] ?? '1');
+			params.contours = parseInt(args['contours'] ?? '1');
 			params.signs = parseInt(args['signs'] ?? '1');
 			params.marker = (args['marker'] ?? '').split(',').map((i) => +i);
 
@@ -372,55 +706,154 @@ window.createMap = function () {
 
 		updateParams();
 
+		// The isometric map layer uses its own map coordinates and tile
+		// grid; its level 0 tiles have multiple pixels per map unit
+		const isometricZoom = isometric ? Math.log2(isometric.scale) : 0;
+		const isometricProj = isometric ? isometricProjection(isometric) : null;
+		const layerProjection = function (id) {
+			return (id === 'isometric') ? isometricProj : topDownProjection;
+		};
+		projection = layerProjection(params.layer);
+
+		const tileBounds = function (bounds, size) {
+			return L.latLngBounds(
+				[-size*(bounds.maxZ+1), size*bounds.minX],
+				[-size*bounds.minZ, size*(bounds.maxX+1)],
+			);
+		};
+		const maxBounds = tileBounds(mipmaps[0].bounds, 512);
+		if (isometric)
+			maxBounds.extend(tileBounds(isometric.mipmaps[0].bounds, 512/isometric.scale));
+
 		const map = L.map('map', {
-			center: [-params.z, params.x],
+			center: projection.toLatLng(params.x, params.z),
 			zoom: params.zoom,
-			minZoom: -(mipmaps.length-1),
+			minZoom: Math.min(
+				-(mipmaps.length-1),
+				isometric ? isometricZoom-(isometric.mipmaps.length-1) : 0,
+			),
 			maxZoom: 5,
 			crs: L.CRS.Simple,
-			maxBounds: [
-				[-512*(mipmaps[0].bounds.maxZ+1), 512*mipmaps[0].bounds.minX],
-				[-512*mipmaps[0].bounds.minZ, 512*(mipmaps[0].bounds.maxX+1)],
-			],
+			maxBounds,
 		});
 
+		// Switches between the coordinates of the top-down and isometric
+		// map layers
+		const updateProjection = function () {
+			projection = layerProjection(params.layer);
+
+			for (const marker of Object.values(markers))
+				marker.setLatLng(projection.toLatLng(...marker.worldCoords));
+		};
+
+		const baseMaps = {};
 		const overlayMaps = {};
 
-		const mapLayer = new MinedMapLayer(mipmaps, 'map');
-		mapLayer.addTo(map);
+		const mapLayers = {};
+		layers.forEach((layer, index) => {
+			let mapLayer = (layer.id === 'isometric') ?
+				new MinedMapLayer(dataPath, isometric.mipmaps, layer.id, isometricZoom) :
+				new MinedMapLayer(dataPath, mipmaps, layer.id);
+			if (layer.id === 'map' && features.textures)
+				mapLayer = L.layerGroup([mapLayer, new TextureLayer(dataPath, mipmaps[0])]);
+			mapLayers[layer.id] = mapLayer;
+			baseMaps[layerName(layer, index)] = mapLayer;
+		});
+		mapLayers[params.layer].addTo(map);
 
-		const lightLayer = new MinedMapLayer(mipmaps, 'light');
+		const lightLayer = new MinedMapLayer(dataPath, mipmaps, 'light');
 		overlayMaps['Illumination'] = lightLayer;
 		if (params.light)
 			map.addLayer(lightLayer);
 
+		let shadeLayer;
+		if (features.hillshade) {
+			shadeLayer = new MinedMapLayer(dataPath, mipmaps, 'shade');
+			overlayMaps['Relief shading'] = shadeLayer;
+			if (params.shade)
+				map.addLayer(shadeLayer);
+		}
+
+		let contourLayer;
+		if (features.contours) {
+			contourLayer = new MinedMapLayer(dataPath, mipmaps, 'contour');
+			overlayMaps['Contour lines'] = contourLayer;
+			if (params.contours)
+				map.addLayer(contourLayer);
+		}
+
 		let signLayer;
 		if (features.signs) {
 			signLayer = L.layerGroup();
-			loadSigns(signLayer);
+			loadSigns(dataPath, signLayer);
 			if (params.signs)
 				map.addLayer(signLayer);
 
 			overlayMaps['Signs'] = signLayer;
 		}
 
-		L.control.layers({}, overlayMaps).addTo(map);
+		L.control.layers(layers.length > 1 ? baseMaps : {}, overlayMaps).addTo(map);
+
+		if (dimensions.length > 1) {
+			const dimensionControl = new DimensionControl(dimensions, dimension.id, (id) => {
+				window.location.hash = '#dim='+encodeURIComponent(id);
+			});
+			dimensionControl.addTo(map);
+		}
 
 		const coordControl = new CoordControl();
 		coordControl.addTo(map);
 
+		const biomeLegend = features.biomes ? new BiomeLegendControl(res.biomes ?? {}) : null;
+		const updateBiomeLegend = function () {
+			if (!biomeLegend)
+				return;
+
+			if (params.layer === 'biome')
+				biomeLegend.addTo(map);
+			else
+				biomeLegend.remove();
+		};
+		updateBiomeLegend();
+
+		const columnInfo = new ColumnInfo(dataPath, mipmaps[0], features);
+		let mousePos = null;
+
 		map.on('mousemove', function(e) {
-			coordControl.update(Math.round(e.latlng.lng), Math.round(-e.latlng.lat));
+			const [posX, posZ] = projection.fromLatLng(e.latlng);
+			const x = Math.round(posX), z = Math.round(posZ);
+			coordControl.update(x, z);
+
+			// Column data is only available for the top-down map layers
+			if (!columnInfo.at || projection !== topDownProjection)
+				return;
+
+			const blockX = Math.floor(posX), blockZ = Math.floor(posZ);
+			const pos = mousePos = coordKey([blockX, blockZ]);
+			columnInfo.at(blockX, blockZ).then((info) => {
+				if (pos === mousePos)
+					coordControl.update(x, z, info);
+			});
 		});
 
 		const makeHash = function () {
 			let ret = '#x='+params.x+'&z='+params.z;
 
+			if (params.dim !== dimensions[0].id)
+				ret += '&dim='+encodeURIComponent(params.dim);
+
+			if (params.layer !== layers[0].id)
+				ret += '&layer='+params.layer;
+
 			if (params.zoom != 0)
 				ret += '&zoom='+params.zoom;
 
 			if (map.hasLayer(lightLayer))
 				ret += '&light=1';
+			if (features.hillshade && !map.hasLayer(shadeLayer))
+				ret += '&shade=0';
+			if (features.contours && !map.hasLayer(contourLayer))
+				ret += '&contours=0';
 			if (features.signs && !map.hasLayer(signLayer))
 				ret += '&signs=0';
 			if (params.marker) {
@@ -436,15 +869,16 @@ window.createMap = function () {
 
 		const refreshHash = function (ev) {
 			if (ev.type === 'layeradd' || ev.type === 'layerremove') {
-				if (ev.layer !== lightLayer && ev.layer !== signLayer)
+				if (ev.layer !== lightLayer && ev.layer !== shadeLayer &&
+				    ev.layer !== contourLayer && ev.layer !== signLayer)
 					return;
 			}
 
-			const center = map.getCenter();
+			const [x, z] = projection.fromLatLng(map.getCenter());
 
 			params.zoom = map.getZoom();
-			params.x = Math.round(center.lng);
-			params.z = Math.round(-center.lat);
+			params.x = Math.round(x);
+			params.z = Math.round(z);
 
 			updateHash();
 		}
@@ -455,6 +889,15 @@ window.createMap = function () {
 		map.on('zoomend', refreshHash);
 		map.on('layeradd', refreshHash);
 		map.on('layerremove', refreshHash);
+		map.on('baselayerchange', (ev) => {
+			const prevProjection = projection;
+			params.layer = Object.keys(mapLayers).find((id) => mapLayers[id] === ev.layer);
+			updateProjection();
+			if (projection !== prevProjection)
+				map.setView(projection.toLatLng(params.x, params.z), map.getZoom(), {animate: false});
+			updateBiomeLegend();
+			updateHash();
+		});
 
 		window.onhashchange = function () {
 			if (window.location.hash === makeHash())
@@ -464,11 +907,40 @@ window.createMap = function () {
 
 			updateParams();
 
+			if (params.dim !== dimension.id) {
+				// Switching dimensions requires loading different metadata
+				window.location.reload();
+				return;
+			}
+
+			for (const [id, mapLayer] of Object.entries(mapLayers)) {
+				if (id === params.layer)
+					map.addLayer(mapLayer);
+				else
+					map.removeLayer(mapLayer);
+			}
+			updateProjection();
+			updateBiomeLegend();
+
 			if (params.light)
 				map.addLayer(lightLayer);
 			else
 				map.removeLayer(lightLayer);
 
+			if (features.hillshade) {
+				if (params.shade)
+					map.addLayer(shadeLayer);
+				else
+					map.removeLayer(shadeLayer);
+			}
+
+			if (features.contours) {
+				if (params.contours)
+					map.addLayer(contourLayer);
+				else
+					map.removeLayer(contourLayer);
+			}
+
 			if (features.signs) {
 				if (params.signs)
 					map.addLayer(signLayer);
@@ -479,7 +951,7 @@ window.createMap = function () {
 					getMarker(params.marker)?.openPopup();
 			}
 
-			map.setView([-params.z, params.x], params.zoom);
+			map.setView(projection.toLatLng(params.x, params.z), params.zoom);
 
 			updateHash();
 		};